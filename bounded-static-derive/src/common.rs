@@ -5,29 +5,253 @@ use syn::{
     TypeParam, WhereClause, WherePredicate,
 };
 
-/// The method and trait bound for both traits we will generate.
+/// The method and trait bound for the traits we will generate.
 #[derive(Copy, Clone)]
+#[allow(clippy::enum_variant_names)]
 pub(super) enum TargetTrait {
     ToBoundedStatic,
     IntoBoundedStatic,
+    ToBounded,
+    ToStaticAs,
+    ToSendStatic,
+}
+
+/// The method of a `TargetTrait` to generate.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(super) enum Method {
+    /// i.e. `to_static`
+    Convert,
+    /// i.e. `to_static_with_budget`
+    ConvertWithBudget,
+    /// i.e. `to_static_with`
+    ConvertWithConverter,
+}
+
+impl Method {
+    /// Wrap the converted value as the method return value.
+    ///
+    /// i.e. `Foo { .. }` or `Ok(Foo { .. })`
+    pub fn wrap_body(self, body: &TokenStream) -> TokenStream {
+        if self == Self::ConvertWithBudget {
+            quote!(::core::result::Result::Ok(#body))
+        } else {
+            quote!(#body)
+        }
+    }
 }
 
 impl TargetTrait {
-    pub fn method(self) -> Ident {
+    pub fn method(self, method: Method) -> Ident {
+        match (self, method) {
+            (Self::ToBoundedStatic, Method::Convert) => format_ident!("to_static"),
+            (Self::ToBoundedStatic, Method::ConvertWithBudget) => {
+                format_ident!("to_static_with_budget")
+            }
+            (Self::IntoBoundedStatic, Method::Convert) => format_ident!("into_static"),
+            (Self::IntoBoundedStatic, Method::ConvertWithBudget) => {
+                format_ident!("into_static_with_budget")
+            }
+            (Self::ToBoundedStatic, Method::ConvertWithConverter) => {
+                format_ident!("to_static_with")
+            }
+            (Self::IntoBoundedStatic, Method::ConvertWithConverter) => {
+                unreachable!("IntoBoundedStatic has no conversion with a converter")
+            }
+            (Self::ToBounded, _) => format_ident!("to_bounded_in"),
+            (Self::ToStaticAs, _) => format_ident!("to_static_as"),
+            (Self::ToSendStatic, _) => format_ident!("to_send_static"),
+        }
+    }
+
+    /// The trait path, relative to the `bounded_static` crate.
+    ///
+    /// i.e. `ToBoundedStatic`, `ToBounded<'__b, __A>` or `ToStaticAs<__R>`
+    pub fn bound(self) -> TokenStream {
         match self {
-            Self::ToBoundedStatic => format_ident!("to_static"),
-            Self::IntoBoundedStatic => format_ident!("into_static"),
+            Self::ToBoundedStatic => quote!(ToBoundedStatic),
+            Self::IntoBoundedStatic => quote!(IntoBoundedStatic),
+            Self::ToBounded => quote!(ToBounded<'__b, __A>),
+            Self::ToStaticAs => quote!(ToStaticAs<__R>),
+            Self::ToSendStatic => quote!(ToSendStatic),
         }
     }
 
-    pub fn bound(self) -> Ident {
+    /// The name of the associated target type.
+    ///
+    /// i.e. `Static` or `Output`
+    pub fn target_type(self) -> Ident {
+        if matches!(self, Self::ToBounded) {
+            format_ident!("Output")
+        } else {
+            format_ident!("Static")
+        }
+    }
+
+    /// The lifetime which the lifetime parameters are replaced with in the target type.
+    ///
+    /// i.e. `'static` or `'__b`
+    fn target_lifetime(self) -> TokenStream {
+        if matches!(self, Self::ToBounded) {
+            quote!('__b)
+        } else {
+            quote!('static)
+        }
+    }
+
+    /// The methods to generate for this trait.
+    ///
+    /// `ToBoundedStatic` and `IntoBoundedStatic` also provide a budgeted conversion which must be generated so that
+    /// all fields are charged to the budget, and `ToBoundedStatic` provides a conversion with a converter which must
+    /// be generated so that the converter is passed to all fields.
+    pub const fn methods(self) -> &'static [Method] {
         match self {
-            Self::ToBoundedStatic => format_ident!("ToBoundedStatic"),
-            Self::IntoBoundedStatic => format_ident!("IntoBoundedStatic"),
+            Self::ToBoundedStatic => &[
+                Method::Convert,
+                Method::ConvertWithBudget,
+                Method::ConvertWithConverter,
+            ],
+            Self::IntoBoundedStatic => &[Method::Convert, Method::ConvertWithBudget],
+            Self::ToBounded | Self::ToStaticAs | Self::ToSendStatic => &[Method::Convert],
+        }
+    }
+
+    /// The method signature.
+    ///
+    /// i.e. `fn to_static(&self) -> Self::Static`
+    pub fn signature(self, method: Method) -> TokenStream {
+        let name = self.method(method);
+        let receiver = match self {
+            Self::ToBoundedStatic | Self::ToBounded | Self::ToStaticAs | Self::ToSendStatic => {
+                quote!(&self)
+            }
+            Self::IntoBoundedStatic => quote!(self),
+        };
+        match method {
+            Method::Convert if matches!(self, Self::ToBounded) => quote!(
+                #[allow(unused_variables)]
+                fn #name(#receiver, __alloc: &'__b __A) -> Self::Output
+            ),
+            Method::Convert if matches!(self, Self::ToStaticAs) => quote!(
+                #[allow(unused_variables)]
+                fn #name(#receiver, __repr: __R) -> Self::Static
+            ),
+            Method::Convert => quote!(fn #name(#receiver) -> Self::Static),
+            Method::ConvertWithConverter => quote!(
+                #[allow(unused_variables)]
+                fn #name<'__converter>(
+                    &'__converter self,
+                    __converter: &mut ::bounded_static::StaticConverter<'__converter>,
+                ) -> Self::Static
+            ),
+            Method::ConvertWithBudget => quote!(
+                #[allow(unused_variables)]
+                fn #name(
+                    #receiver,
+                    __budget: &mut ::bounded_static::Budget,
+                ) -> ::core::result::Result<Self::Static, ::bounded_static::TryStaticError>
+            ),
+        }
+    }
+
+    /// Convert the value of a field at the given path segment, optionally within an `enum` variant.
+    ///
+    /// i.e. `self.foo.to_static()`,
+    /// `self.foo.to_static_with_budget(__budget).map_err(|err| err.at(PathSegment::Field("foo")))?`,
+    /// or `self.foo.to_static_with(__converter)`
+    pub fn convert(
+        self,
+        method: Method,
+        expr: &TokenStream,
+        segment: &TokenStream,
+        variant: Option<&Ident>,
+    ) -> TokenStream {
+        let name = self.method(method);
+        let variant = variant.map(|variant| {
+            let variant = unraw(variant);
+            quote!(.at(::bounded_static::error::PathSegment::Variant(#variant)))
+        });
+        if method == Method::ConvertWithBudget {
+            quote!(#expr.#name(__budget).map_err(|err| {
+                err.at(::bounded_static::error::PathSegment::#segment)#variant
+            })?)
+        } else if method == Method::ConvertWithConverter {
+            quote!(#expr.#name(__converter))
+        } else if matches!(self, Self::ToBounded) {
+            quote!(#expr.#name(__alloc))
+        } else if matches!(self, Self::ToStaticAs) {
+            quote!(#expr.#name(__repr))
+        } else {
+            quote!(#expr.#name())
+        }
+    }
+
+    /// Record the conversion of a value of the named type with the `instrument` feature, for `ToBoundedStatic` and
+    /// `IntoBoundedStatic`.
+    ///
+    /// i.e. `let _guard = ::bounded_static::__private::enter("my_crate::Foo");`
+    pub fn instrument_guard(self, name: &Ident) -> TokenStream {
+        if matches!(
+            self,
+            Self::ToBounded | Self::ToStaticAs | Self::ToSendStatic
+        ) {
+            return quote!();
+        }
+        let name = unraw(name);
+        quote!(
+            let _guard = ::bounded_static::__private::enter(
+                ::core::concat!(::core::module_path!(), "::", #name)
+            );
+        )
+    }
+
+    /// The `add_static_cost` method with the given body, if this trait has one.
+    pub fn cost_method(self, body: &TokenStream) -> TokenStream {
+        match self {
+            Self::ToBoundedStatic => quote!(
+                #[allow(unused_variables)]
+                fn add_static_cost(&self, __report: &mut ::bounded_static::CostReport) {
+                    #body
+                }
+            ),
+            _ => quote!(),
+        }
+    }
+
+    /// The `to_static_into` method with the given body, if this trait has one.
+    pub fn into_method(self, body: &TokenStream) -> TokenStream {
+        match self {
+            Self::ToBoundedStatic => quote!(
+                #[allow(unused_variables)]
+                fn to_static_into(&self, dst: &mut Self::Static) {
+                    #body
+                }
+            ),
+            _ => quote!(),
         }
     }
 }
 
+/// Convert the value of a field by reference into an existing value.
+///
+/// i.e. `ToBoundedStatic::to_static_into(&self.foo, &mut dst.foo);`
+pub(super) fn convert_into(expr: &TokenStream, dst: &TokenStream) -> TokenStream {
+    quote!(::bounded_static::ToBoundedStatic::to_static_into(#expr, #dst);)
+}
+
+/// Add the cost of the value of a field by reference.
+///
+/// i.e. `ToBoundedStatic::add_static_cost(&self.foo, __report);`
+pub(super) fn add_cost(expr: &TokenStream) -> TokenStream {
+    quote!(::bounded_static::ToBoundedStatic::add_static_cost(#expr, __report);)
+}
+
+/// The name of an identifier without any raw prefix.
+pub(super) fn unraw(ident: &Ident) -> String {
+    let name = ident.to_string();
+    name.strip_prefix("r#")
+        .map_or_else(|| name.clone(), String::from)
+}
+
 /// Check for references which aren't `'static` and panic.
 ///
 /// # Examples
@@ -78,7 +302,7 @@ pub(super) fn check_field(field: &Field) {
     }
 }
 
-/// The generic parameters of the `Static` associated type for `TargetTrait`.
+/// The generic parameters of the `Static` (or `Output`) associated type for `TargetTrait`.
 ///
 /// i.e. `Static = Foo<'static, <T as ToBoundedStatic>::Static>`
 pub(super) fn make_target_generics(generics: &Generics, target: TargetTrait) -> Vec<TokenStream> {
@@ -88,9 +312,10 @@ pub(super) fn make_target_generics(generics: &Generics, target: TargetTrait) ->
         .map(|param| match param {
             GenericParam::Type(TypeParam { ident, .. }) => {
                 let target_bound = target.bound();
-                quote!(<#ident as #target_bound>::Static)
+                let target_type = target.target_type();
+                quote!(<#ident as ::bounded_static::#target_bound>::#target_type)
             }
-            GenericParam::Lifetime(_) => quote!('static),
+            GenericParam::Lifetime(_) => target.target_lifetime(),
             GenericParam::Const(ConstParam { ident, .. }) => quote!(#ident),
         })
         .collect()
@@ -134,10 +359,28 @@ pub(super) fn make_target_generics(generics: &Generics, target: TargetTrait) ->
 ///
 /// - Generic parameter `T` has the additional bound `::bounded_static::ToBoundedStatic`
 /// - Associated type `T::Static` has the bound of `T`, i.e. `Into<String> + 'a`
+///
+/// For `ToBounded` the lifetime `'__b` and allocator `__A` parameters of the trait are also added, along with the
+/// bounds of `T::Static` required by the `ToBoundedStatic` supertrait, and for `ToStaticAs` the representation `__R`
+/// parameter of the trait is added.
 pub(super) fn make_bounded_generics(generics: &Generics, target: TargetTrait) -> Generics {
-    let params = make_bounded_generic_params(generics, target);
+    let mut params = make_bounded_generic_params(generics, target);
+    if matches!(target, TargetTrait::ToBounded) {
+        params.insert(0, parse_quote!('__b));
+        params
+            .push(parse_quote!(__A: ?::core::marker::Sized + ::bounded_static::BoundedAlloc<'__b>));
+    }
+    if matches!(target, TargetTrait::ToStaticAs) {
+        params.push(parse_quote!(__R: ::bounded_static::StaticRepr));
+    }
     let predicates = make_bounded_generic_predicates(generics, target);
-    let static_predicates = make_static_generic_predicates(generics, target);
+    let mut static_predicates = make_static_generic_predicates(generics, target);
+    if matches!(target, TargetTrait::ToBounded) {
+        static_predicates.extend(make_static_generic_predicates(
+            generics,
+            TargetTrait::ToBoundedStatic,
+        ));
+    }
     let where_items: Vec<_> = predicates.into_iter().chain(static_predicates).collect();
     Generics {
         params: parse_quote!(#(#params),*),
@@ -204,15 +447,16 @@ fn make_static_generic_predicates(generics: &Generics, target: TargetTrait) -> V
                 let var = &param_ty.ident;
                 let param_ty_bounds = &param_ty.bounds;
                 let target_bound = target.bound();
+                let target_type = target.target_type();
                 match find_predicate(generics.where_clause.as_ref(), var) {
                     None if param_ty_bounds.is_empty() => None,
-                    None => Some(parse_quote!(<#var as #target_bound>::Static: #param_ty_bounds)),
+                    None => Some(parse_quote!(<#var as ::bounded_static::#target_bound>::#target_type: #param_ty_bounds)),
                     Some(predicate_ty) => {
                         let predicate_bounds = &predicate_ty.bounds;
                         if param_ty_bounds.is_empty() {
-                            Some(parse_quote!(<#var as #target_bound>::Static: #predicate_bounds))
+                            Some(parse_quote!(<#var as ::bounded_static::#target_bound>::#target_type: #predicate_bounds))
                         } else {
-                            Some(parse_quote!(<#var as #target_bound>::Static: #param_ty_bounds + #predicate_bounds))
+                            Some(parse_quote!(<#var as ::bounded_static::#target_bound>::#target_type: #param_ty_bounds + #predicate_bounds))
                         }
                     }
                 }
@@ -243,12 +487,12 @@ fn find_predicate<'a>(
 
 /// Clone and add a bound to a type.
 trait CloneWithBound {
-    fn clone_with_bound(&self, bound: &Ident) -> Self;
+    fn clone_with_bound(&self, bound: &TokenStream) -> Self;
 }
 
 /// Clone and add a bound to a `PredicateType` (in a `where` clause).
 impl CloneWithBound for PredicateType {
-    fn clone_with_bound(&self, bound: &Ident) -> Self {
+    fn clone_with_bound(&self, bound: &TokenStream) -> Self {
         let mut bounded = self.clone();
         bounded.bounds.push(parse_quote!(::bounded_static::#bound));
         bounded
@@ -257,7 +501,7 @@ impl CloneWithBound for PredicateType {
 
 /// Clone and add a bound to a `TypeParam`.
 impl CloneWithBound for TypeParam {
-    fn clone_with_bound(&self, bound: &Ident) -> Self {
+    fn clone_with_bound(&self, bound: &TokenStream) -> Self {
         let mut bounded = self.clone();
         bounded.bounds.push(parse_quote!(::bounded_static::#bound));
         bounded
@@ -1,55 +1,63 @@
 use crate::common;
-use crate::common::TargetTrait;
+use crate::common::{Method, TargetTrait};
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 use syn::{Fields, FieldsNamed, FieldsUnnamed, Generics, Variant};
 
-/// Generate `ToBoundedStatic` and `IntoBoundedStatic` impls for an `enum` deriving `ToStatic`.
+/// Generate the `TargetTrait` impls for an `enum`.
 pub(super) fn generate_enum(
     name: &Ident,
     generics: &Generics,
     variants: &[&Variant],
+    targets: &[TargetTrait],
 ) -> TokenStream {
     variants
         .iter()
         .for_each(|v| v.fields.iter().for_each(common::check_field));
-    let to = generate_enum_to(name, generics, variants);
-    let into = generate_enum_into(name, generics, variants);
-    quote!(#to #into)
+    let impls = targets
+        .iter()
+        .map(|&target| generate_enum_impl(name, generics, variants, target));
+    quote!(#(#impls)*)
 }
 
-/// Generate `ToBoundedStatic` for an enum.
-fn generate_enum_to(name: &Ident, generics: &Generics, variants: &[&Variant]) -> TokenStream {
-    let arms = generate_match_arms(name, variants, TargetTrait::ToBoundedStatic);
-    let gens = common::make_bounded_generics(generics, TargetTrait::ToBoundedStatic);
-    let (impl_gens, to_ty_gens, to_where) = gens.split_for_impl();
-    let static_gens = common::make_target_generics(generics, TargetTrait::ToBoundedStatic);
-    quote!(
-        impl #impl_gens ::bounded_static::ToBoundedStatic for #name #to_ty_gens #to_where {
-            type Static = #name<#(#static_gens),*>;
-            fn to_static(&self) -> Self::Static {
-                match self {
-                    #(#arms),*
-                }
-            }
+/// Generate `TargetTrait` for an enum.
+fn generate_enum_impl(
+    name: &Ident,
+    generics: &Generics,
+    variants: &[&Variant],
+    target: TargetTrait,
+) -> TokenStream {
+    let gens = common::make_bounded_generics(generics, target);
+    let (impl_gens, _, where_clause) = gens.split_for_impl();
+    let (_, ty_gens, _) = generics.split_for_impl();
+    let static_gens = common::make_target_generics(generics, target);
+    let bound = target.bound();
+    let target_type = target.target_type();
+    let methods = target.methods().iter().map(|&method| {
+        let arms = generate_match_arms(name, variants, target, method);
+        let signature = target.signature(method);
+        let guard = target.instrument_guard(name);
+        let body = method.wrap_body(&quote!(match self { #(#arms),* }));
+        quote!(#signature { #guard #body })
+    });
+    let cost_arms = generate_cost_match_arms(name, variants);
+    let cost_method = target.cost_method(&quote!(match self { #(#cost_arms),* }));
+    let into_arms = generate_into_match_arms(name, variants);
+    let guard = target.instrument_guard(name);
+    let into_method = target.into_method(&quote!(
+        #guard
+        match (self, dst) {
+            #(#into_arms,)*
+            #[allow(unreachable_patterns)]
+            (_, dst) => *dst = ::bounded_static::ToBoundedStatic::to_static(self),
         }
-    )
-}
-
-/// Generate `IntoBoundedStatic` for an enum.
-fn generate_enum_into(name: &Ident, generics: &Generics, variants: &[&Variant]) -> TokenStream {
-    let arms = generate_match_arms(name, variants, TargetTrait::IntoBoundedStatic);
-    let gens = common::make_bounded_generics(generics, TargetTrait::IntoBoundedStatic);
-    let (impl_gens, into_ty_gens, into_where) = gens.split_for_impl();
-    let static_gens = common::make_target_generics(generics, TargetTrait::IntoBoundedStatic);
+    ));
     quote!(
-        impl #impl_gens ::bounded_static::IntoBoundedStatic for #name #into_ty_gens #into_where {
-            type Static = #name<#(#static_gens),*>;
-            fn into_static(self) -> Self::Static {
-                match self {
-                    #(#arms),*
-                }
-            }
+        impl #impl_gens ::bounded_static::#bound for #name #ty_gens #where_clause {
+            type #target_type = #name<#(#static_gens),*>;
+            #(#methods)*
+            #cost_method
+            #into_method
         }
     )
 }
@@ -67,16 +75,89 @@ fn generate_match_arms(
     name: &Ident,
     variants: &[&Variant],
     target: TargetTrait,
+    method: Method,
 ) -> Vec<TokenStream> {
     variants
         .iter()
         .map(|variant| match &variant.fields {
             Fields::Unit => generate_variant_unit(name, &variant.ident),
             Fields::Named(fields_named) => {
-                generate_variant_named(name, &variant.ident, fields_named, target)
+                generate_variant_named(name, &variant.ident, fields_named, target, method)
             }
             Fields::Unnamed(fields_unnamed) => {
-                generate_variant_unnamed(name, &variant.ident, fields_unnamed, target)
+                generate_variant_unnamed(name, &variant.ident, fields_unnamed, target, method)
+            }
+        })
+        .collect()
+}
+
+/// Generate a collection of match arms which add the cost of each field.
+///
+/// i.e. `Foo::Bar { a, b } => { ToBoundedStatic::add_static_cost(a, __report); .. }`
+fn generate_cost_match_arms(name: &Ident, variants: &[&Variant]) -> Vec<TokenStream> {
+    variants
+        .iter()
+        .map(|variant| {
+            let variant_name = &variant.ident;
+            match &variant.fields {
+                Fields::Unit => quote!(#name::#variant_name => {}),
+                Fields::Named(fields_named) => {
+                    let fields = extract_named_fields(fields_named);
+                    let costs = fields.iter().map(|field| common::add_cost(&quote!(#field)));
+                    quote!(#name::#variant_name{ #(#fields),* } => { #(#costs)* })
+                }
+                Fields::Unnamed(fields_unnamed) => {
+                    let fields = extract_unnamed_fields(fields_unnamed);
+                    let costs = fields.iter().map(|field| common::add_cost(&quote!(#field)));
+                    quote!(#name::#variant_name( #(#fields),* ) => { #(#costs)* })
+                }
+            }
+        })
+        .collect()
+}
+
+/// Generate a collection of match arms which convert each field into the field of the same variant of `dst`.
+///
+/// If the variant of `dst` differs then the value is converted with `to_static` and assigned to `dst` instead.
+///
+/// i.e. `(Foo::Bar { a }, Foo::Bar { a: __dst_0 }) => { ToBoundedStatic::to_static_into(a, __dst_0); }`
+fn generate_into_match_arms(name: &Ident, variants: &[&Variant]) -> Vec<TokenStream> {
+    variants
+        .iter()
+        .map(|variant| {
+            let variant_name = &variant.ident;
+            match &variant.fields {
+                Fields::Unit => quote!((#name::#variant_name, #name::#variant_name) => {}),
+                Fields::Named(fields_named) => {
+                    let fields = extract_named_fields(fields_named);
+                    let dsts: Vec<_> = (0..fields.len())
+                        .map(|i| format_ident!("__dst_{}", i))
+                        .collect();
+                    let intos = fields
+                        .iter()
+                        .zip(&dsts)
+                        .map(|(field, dst)| common::convert_into(&quote!(#field), &quote!(#dst)));
+                    quote!(
+                        (#name::#variant_name{ #(#fields),* }, #name::#variant_name{ #(#fields: #dsts),* }) => {
+                            #(#intos)*
+                        }
+                    )
+                }
+                Fields::Unnamed(fields_unnamed) => {
+                    let fields = extract_unnamed_fields(fields_unnamed);
+                    let dsts: Vec<_> = (0..fields.len())
+                        .map(|i| format_ident!("__dst_{}", i))
+                        .collect();
+                    let intos = fields
+                        .iter()
+                        .zip(&dsts)
+                        .map(|(field, dst)| common::convert_into(&quote!(#field), &quote!(#dst)));
+                    quote!(
+                        (#name::#variant_name( #(#fields),* ), #name::#variant_name( #(#dsts),* )) => {
+                            #(#intos)*
+                        }
+                    )
+                }
             }
         })
         .collect()
@@ -97,9 +178,10 @@ fn generate_variant_named(
     variant: &Ident,
     fields_named: &FieldsNamed,
     target: TargetTrait,
+    method: Method,
 ) -> TokenStream {
     let fields = extract_named_fields(fields_named);
-    let fields_to_method = generate_named_field_init_method(fields_named, target);
+    let fields_to_method = generate_named_field_init_method(variant, fields_named, target, method);
     quote!(#name::#variant{ #(#fields),* } => #name::#variant{ #(#fields_to_method),* })
 }
 
@@ -111,41 +193,57 @@ fn generate_variant_unnamed(
     variant: &Ident,
     fields_unnamed: &FieldsUnnamed,
     target: TargetTrait,
+    method: Method,
 ) -> TokenStream {
     let fields = extract_unnamed_fields(fields_unnamed);
-    let fields_to_method = generate_unnamed_field_init_method(fields_unnamed, target);
+    let fields_to_method =
+        generate_unnamed_field_init_method(variant, fields_unnamed, target, method);
     quote!(#name::#variant( #(#fields),* ) => #name::#variant( #(#fields_to_method),* ))
 }
 
 /// i.e. `foo: foo.to_static()`
 fn generate_named_field_init_method(
+    variant: &Ident,
     fields_named: &FieldsNamed,
     target: TargetTrait,
+    method: Method,
 ) -> Vec<TokenStream> {
-    let method = target.method();
     fields_named
         .named
         .iter()
         .map(|f| {
             let field_name = f.ident.as_ref().expect("FieldsNamed must have an ident");
-            quote!(#field_name: #field_name.#method())
+            let field_str = common::unraw(field_name);
+            let value = target.convert(
+                method,
+                &quote!(#field_name),
+                &quote!(Field(#field_str)),
+                Some(variant),
+            );
+            quote!(#field_name: #value)
         })
         .collect()
 }
 
 /// i.e. `foo.to_static()`
 fn generate_unnamed_field_init_method(
+    variant: &Ident,
     fields_unnamed: &FieldsUnnamed,
     target: TargetTrait,
+    method: Method,
 ) -> Vec<TokenStream> {
-    let method = target.method();
     fields_unnamed
         .unnamed
         .iter()
         .enumerate()
         .map(|(i, _)| {
             let field_name = format_ident!("field_{}", i);
-            quote!(#field_name.#method())
+            target.convert(
+                method,
+                &quote!(#field_name),
+                &quote!(Index(#i)),
+                Some(variant),
+            )
         })
         .collect()
 }
@@ -1,148 +1,149 @@
 use crate::common;
-use crate::common::TargetTrait;
+use crate::common::{Method, TargetTrait};
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use syn::{Field, FieldsNamed, FieldsUnnamed, Generics};
 
-/// Generate `ToBoundedStatic` and `IntoBoundedStatic` impls for a `struct` with named fields deriving `ToStatic`.
+/// Generate the `TargetTrait` impls for a `struct` with named fields.
 pub(super) fn generate_struct_named(
     name: &Ident,
     generics: &Generics,
     fields_named: &FieldsNamed,
+    targets: &[TargetTrait],
 ) -> TokenStream {
     fields_named.named.iter().for_each(common::check_field);
-    let to = generate_struct_named_to(name, generics, fields_named);
-    let into = generate_struct_named_into(name, generics, fields_named);
-    quote!(#to #into)
+    let impls = targets
+        .iter()
+        .map(|&target| generate_struct_named_impl(name, generics, fields_named, target));
+    quote!(#(#impls)*)
 }
 
-/// Generate `ToBoundedStatic` and `IntoBoundedStatic` impls for a `struct` with unnamed fields deriving `ToStatic`.
+/// Generate the `TargetTrait` impls for a `struct` with unnamed fields.
 pub(super) fn generate_struct_unnamed(
     name: &Ident,
     generics: &Generics,
     fields_unnamed: &FieldsUnnamed,
+    targets: &[TargetTrait],
 ) -> TokenStream {
     fields_unnamed.unnamed.iter().for_each(common::check_field);
-    let to = generate_struct_unnamed_to(name, generics, fields_unnamed);
-    let into = generate_struct_unnamed_into(name, generics, fields_unnamed);
-    quote!(#to #into)
-}
-
-/// Generate `ToBoundedStatic` and `IntoBoundedStatic` impls for a unit `struct` deriving `ToStatic`.
-pub(super) fn generate_struct_unit(name: &Ident) -> TokenStream {
-    let to = generate_struct_unit_to(name);
-    let into = generate_struct_unit_into(name);
-    quote!(#to #into)
+    let impls = targets
+        .iter()
+        .map(|&target| generate_struct_unnamed_impl(name, generics, fields_unnamed, target));
+    quote!(#(#impls)*)
 }
 
-/// Generate `ToBoundedStatic` for a `struct` with with named fields.
-fn generate_struct_named_to(
-    name: &Ident,
-    generics: &Generics,
-    fields_named: &FieldsNamed,
-) -> TokenStream {
-    let fields = make_named_fields_init_methods(fields_named, TargetTrait::ToBoundedStatic);
-    let gens = common::make_bounded_generics(generics, TargetTrait::ToBoundedStatic);
-    let (impl_gens, ty_gens, where_clause) = gens.split_for_impl();
-    let static_gens = common::make_target_generics(generics, TargetTrait::ToBoundedStatic);
-    quote!(
-        impl #impl_gens ::bounded_static::ToBoundedStatic for #name #ty_gens #where_clause {
-            type Static = #name<#(#static_gens),*>;
-            fn to_static(&self) -> Self::Static {
-                #name {
-                    #(#fields),*
-                }
-            }
-        }
-    )
+/// Generate the `TargetTrait` impls for a unit `struct`.
+pub(super) fn generate_struct_unit(name: &Ident, targets: &[TargetTrait]) -> TokenStream {
+    let impls = targets
+        .iter()
+        .map(|&target| generate_struct_unit_impl(name, target));
+    quote!(#(#impls)*)
 }
 
-/// Generate `IntoBoundedStatic` for a `struct` with with named fields.
-fn generate_struct_named_into(
+/// Generate `TargetTrait` for a `struct` with with named fields.
+fn generate_struct_named_impl(
     name: &Ident,
     generics: &Generics,
     fields_named: &FieldsNamed,
+    target: TargetTrait,
 ) -> TokenStream {
-    let fields = make_named_fields_init_methods(fields_named, TargetTrait::IntoBoundedStatic);
-    let gens = common::make_bounded_generics(generics, TargetTrait::IntoBoundedStatic);
-    let (impl_gens, ty_gens, where_clause) = gens.split_for_impl();
-    let static_gens = common::make_target_generics(generics, TargetTrait::IntoBoundedStatic);
-    quote!(
-        impl #impl_gens ::bounded_static::IntoBoundedStatic for #name #ty_gens #where_clause {
-            type Static = #name<#(#static_gens),*>;
-            fn into_static(self) -> Self::Static {
-                #name {
-                    #(#fields),*
-                }
-            }
-        }
-    )
-}
-
-/// Generate `ToBoundedStatic` for a `struct` with unnamed fields.
-fn generate_struct_unnamed_to(
-    name: &Ident,
-    generics: &Generics,
-    fields_unnamed: &FieldsUnnamed,
-) -> TokenStream {
-    let fields = make_unnamed_fields(fields_unnamed, TargetTrait::ToBoundedStatic);
-    let gens = common::make_bounded_generics(generics, TargetTrait::ToBoundedStatic);
-    let (impl_gens, ty_gens, where_clause) = gens.split_for_impl();
-    let static_gens = common::make_target_generics(generics, TargetTrait::ToBoundedStatic);
+    let gens = common::make_bounded_generics(generics, target);
+    let (impl_gens, _, where_clause) = gens.split_for_impl();
+    let (_, ty_gens, _) = generics.split_for_impl();
+    let static_gens = common::make_target_generics(generics, target);
+    let bound = target.bound();
+    let target_type = target.target_type();
+    let methods = target.methods().iter().map(|&method| {
+        let fields = make_named_fields_init_methods(fields_named, target, method);
+        let signature = target.signature(method);
+        let guard = target.instrument_guard(name);
+        let body = method.wrap_body(&quote!(#name { #(#fields),* }));
+        quote!(#signature { #guard #body })
+    });
+    let costs = fields_named.named.iter().map(|field| {
+        let field_name = field
+            .ident
+            .as_ref()
+            .expect("FieldsNamed field must have an ident");
+        common::add_cost(&quote!(&self.#field_name))
+    });
+    let cost_method = target.cost_method(&quote!(#(#costs)*));
+    let intos = fields_named.named.iter().map(|field| {
+        let field_name = field
+            .ident
+            .as_ref()
+            .expect("FieldsNamed field must have an ident");
+        common::convert_into(&quote!(&self.#field_name), &quote!(&mut dst.#field_name))
+    });
+    let guard = target.instrument_guard(name);
+    let into_method = target.into_method(&quote!(#guard #(#intos)*));
     quote!(
-        impl #impl_gens ::bounded_static::ToBoundedStatic for #name #ty_gens #where_clause {
-            type Static = #name<#(#static_gens),*>;
-            fn to_static(&self) -> Self::Static {
-                #name (
-                    #(#fields),*
-                )
-            }
+        impl #impl_gens ::bounded_static::#bound for #name #ty_gens #where_clause {
+            type #target_type = #name<#(#static_gens),*>;
+            #(#methods)*
+            #cost_method
+            #into_method
         }
     )
 }
 
-/// Generate `IntoBoundedStatic` for a `struct` with unnamed fields.
-fn generate_struct_unnamed_into(
+/// Generate `TargetTrait` for a `struct` with unnamed fields.
+fn generate_struct_unnamed_impl(
     name: &Ident,
     generics: &Generics,
     fields_unnamed: &FieldsUnnamed,
+    target: TargetTrait,
 ) -> TokenStream {
-    let fields = make_unnamed_fields(fields_unnamed, TargetTrait::IntoBoundedStatic);
-    let gens = common::make_bounded_generics(generics, TargetTrait::IntoBoundedStatic);
-    let (impl_gens, ty_gens, where_clause) = gens.split_for_impl();
-    let static_gens = common::make_target_generics(generics, TargetTrait::IntoBoundedStatic);
+    let gens = common::make_bounded_generics(generics, target);
+    let (impl_gens, _, where_clause) = gens.split_for_impl();
+    let (_, ty_gens, _) = generics.split_for_impl();
+    let static_gens = common::make_target_generics(generics, target);
+    let bound = target.bound();
+    let target_type = target.target_type();
+    let methods = target.methods().iter().map(|&method| {
+        let fields = make_unnamed_fields(fields_unnamed, target, method);
+        let signature = target.signature(method);
+        let guard = target.instrument_guard(name);
+        let body = method.wrap_body(&quote!(#name ( #(#fields),* )));
+        quote!(#signature { #guard #body })
+    });
+    let costs = (0..fields_unnamed.unnamed.len()).map(|i| {
+        let index = syn::Index::from(i);
+        common::add_cost(&quote!(&self.#index))
+    });
+    let cost_method = target.cost_method(&quote!(#(#costs)*));
+    let intos = (0..fields_unnamed.unnamed.len()).map(|i| {
+        let index = syn::Index::from(i);
+        common::convert_into(&quote!(&self.#index), &quote!(&mut dst.#index))
+    });
+    let guard = target.instrument_guard(name);
+    let into_method = target.into_method(&quote!(#guard #(#intos)*));
     quote!(
-        impl #impl_gens ::bounded_static::IntoBoundedStatic for #name #ty_gens #where_clause {
-            type Static = #name<#(#static_gens),*>;
-            fn into_static(self) -> Self::Static {
-                #name (
-                    #(#fields),*
-                )
-            }
+        impl #impl_gens ::bounded_static::#bound for #name #ty_gens #where_clause {
+            type #target_type = #name<#(#static_gens),*>;
+            #(#methods)*
+            #cost_method
+            #into_method
         }
     )
 }
 
-/// Generate `ToBoundedStatic` for unit struct.
-fn generate_struct_unit_to(name: &Ident) -> TokenStream {
+/// Generate `TargetTrait` for unit struct.
+fn generate_struct_unit_impl(name: &Ident, target: TargetTrait) -> TokenStream {
+    let gens = common::make_bounded_generics(&Generics::default(), target);
+    let (impl_gens, _, where_clause) = gens.split_for_impl();
+    let bound = target.bound();
+    let target_type = target.target_type();
+    let methods = target.methods().iter().map(|&method| {
+        let signature = target.signature(method);
+        let guard = target.instrument_guard(name);
+        let body = method.wrap_body(&quote!(#name));
+        quote!(#signature { #guard #body })
+    });
     quote!(
-        impl ::bounded_static::ToBoundedStatic for #name {
-            type Static = #name;
-            fn to_static(&self) -> Self::Static {
-                #name
-            }
-        }
-    )
-}
-
-/// Generate `IntoBoundedStatic` for unit struct.
-fn generate_struct_unit_into(name: &Ident) -> TokenStream {
-    quote!(
-        impl ::bounded_static::IntoBoundedStatic for #name {
-            type Static = #name;
-            fn into_static(self) -> Self::Static {
-                #name
-            }
+        impl #impl_gens ::bounded_static::#bound for #name #where_clause {
+            type #target_type = #name;
+            #(#methods)*
         }
     )
 }
@@ -150,37 +151,47 @@ fn generate_struct_unit_into(name: &Ident) -> TokenStream {
 fn make_named_fields_init_methods(
     fields_named: &FieldsNamed,
     target: TargetTrait,
+    method: Method,
 ) -> Vec<TokenStream> {
     fields_named
         .named
         .iter()
-        .map(|field| make_named_field_init_method(field, target))
+        .map(|field| make_named_field_init_method(field, target, method))
         .collect()
 }
 
 /// i.e. `foo: self.foo.to_static()`
-fn make_named_field_init_method(field: &Field, target: TargetTrait) -> TokenStream {
+fn make_named_field_init_method(field: &Field, target: TargetTrait, method: Method) -> TokenStream {
     let field_name = field
         .ident
         .as_ref()
         .expect("FieldsNamed field must have an ident");
-    let method = target.method();
-    quote!(#field_name: self.#field_name.#method())
+    let field_str = common::unraw(field_name);
+    let value = target.convert(
+        method,
+        &quote!(self.#field_name),
+        &quote!(Field(#field_str)),
+        None,
+    );
+    quote!(#field_name: #value)
 }
 
-fn make_unnamed_fields(fields_unnamed: &FieldsUnnamed, target: TargetTrait) -> Vec<TokenStream> {
+fn make_unnamed_fields(
+    fields_unnamed: &FieldsUnnamed,
+    target: TargetTrait,
+    method: Method,
+) -> Vec<TokenStream> {
     let fields_to_static: Vec<_> = fields_unnamed
         .unnamed
         .iter()
         .enumerate()
-        .map(|(i, _)| make_unnamed_field(i, target))
+        .map(|(i, _)| make_unnamed_field(i, target, method))
         .collect();
     fields_to_static
 }
 
 /// i.e. `self.0.to_static()`
-fn make_unnamed_field(i: usize, target: TargetTrait) -> TokenStream {
-    let i = syn::Index::from(i);
-    let method = target.method();
-    quote!(self.#i.#method())
+fn make_unnamed_field(i: usize, target: TargetTrait, method: Method) -> TokenStream {
+    let index = syn::Index::from(i);
+    target.convert(method, &quote!(self.#index), &quote!(Index(#i)), None)
 }
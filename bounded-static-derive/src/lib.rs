@@ -1,16 +1,28 @@
 #![doc(html_root_url = "https://docs.rs/bounded-static-derive/0.8.0")]
-//! Provides the `ToStatic` derive macro.
+//! Provides the `ToStatic`, `ToBounded`, `ToStaticAs` and `ToSendStatic` derive macros.
 //!
 //! The [`ToStatic`] derive macro implements the [`ToBoundedStatic`](https://docs.rs/bounded-static/0.8.0/bounded_static/trait.ToBoundedStatic.html)
 //! and [`IntoBoundedStatic`](https://docs.rs/bounded-static/0.8.0/bounded_static/trait.IntoBoundedStatic.html) traits for any `struct`
 //! and `enum` that can be converted to a form that is bounded by `'static`.
 //!
-//! The [`ToStatic`] macro should be used via the [`bounded-static`](https://docs.rs/bounded-static/0.8.0) crate
+//! The [`ToBounded`] derive macro implements the [`ToBounded`](https://docs.rs/bounded-static/0.8.0/bounded_static/trait.ToBounded.html)
+//! trait for any `struct` and `enum` that can be converted to a form that is bounded by an arbitrary lifetime.
+//!
+//! The [`ToStaticAs`] derive macro implements the [`ToStaticAs`](https://docs.rs/bounded-static/0.8.0/bounded_static/trait.ToStaticAs.html)
+//! trait for any `struct` and `enum` that can be converted to a form that is bounded by `'static` with a chosen
+//! representation of strings and sequences.
+//!
+//! The [`ToSendStatic`] derive macro implements the [`ToSendStatic`](https://docs.rs/bounded-static/0.8.0/bounded_static/trait.ToSendStatic.html)
+//! trait for any `struct` and `enum` that can be converted to a form that is bounded by `'static` and is `Send` and
+//! `Sync`.
+//!
+//! The [`ToStatic`], [`ToBounded`], [`ToStaticAs`] and [`ToSendStatic`] macros should be used via the [`bounded-static`](https://docs.rs/bounded-static/0.8.0) crate
 //! rather than using this crate directly.
 #![warn(clippy::all, clippy::pedantic, clippy::nursery, rust_2018_idioms)]
 #![allow(clippy::redundant_pub_crate, clippy::needless_for_each)]
 #![forbid(unsafe_code)]
 
+use crate::common::TargetTrait;
 use proc_macro2::TokenStream;
 use syn::{Data, DataStruct, DeriveInput, Fields};
 
@@ -26,27 +38,73 @@ mod data_struct;
 #[proc_macro_derive(ToStatic)]
 pub fn to_static(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = syn::parse_macro_input!(input as syn::DeriveInput);
-    proc_macro::TokenStream::from(generate_traits(&input))
+    proc_macro::TokenStream::from(generate_traits(
+        &input,
+        &[TargetTrait::ToBoundedStatic, TargetTrait::IntoBoundedStatic],
+    ))
+}
+
+/// The `ToBounded` derive macro.
+///
+/// Generate a [`ToBounded`](https://docs.rs/bounded-static/0.8.0/bounded_static/trait.ToBounded.html) impl for the
+/// data item deriving `ToBounded`, which converts the item to the same item with every lifetime parameter replaced by
+/// the lifetime `'b` of the allocator.
+///
+/// `ToBoundedStatic` is a supertrait of `ToBounded`, and so the item must also derive `ToStatic`.
+#[proc_macro_derive(ToBounded)]
+pub fn to_bounded(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    proc_macro::TokenStream::from(generate_traits(&input, &[TargetTrait::ToBounded]))
 }
 
-fn generate_traits(input: &DeriveInput) -> TokenStream {
+/// The `ToStaticAs` derive macro.
+///
+/// Generate a [`ToStaticAs`](https://docs.rs/bounded-static/0.8.0/bounded_static/trait.ToStaticAs.html) impl for the
+/// data item deriving `ToStaticAs`, which converts the item with the string and sequence representation chosen by a
+/// `StaticRepr` policy.  Fields whose representation changes must have the type of a generic parameter.
+#[proc_macro_derive(ToStaticAs)]
+pub fn to_static_as(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    proc_macro::TokenStream::from(generate_traits(&input, &[TargetTrait::ToStaticAs]))
+}
+
+/// The `ToSendStatic` derive macro.
+///
+/// Generate a [`ToSendStatic`](https://docs.rs/bounded-static/0.8.0/bounded_static/trait.ToSendStatic.html) impl for
+/// the data item deriving `ToSendStatic`, which converts the item to a snapshot which is `Send + Sync + 'static`.
+/// Fields whose snapshot has a different type, such as `Rc<T>`, must have the type of a generic parameter.
+#[proc_macro_derive(ToSendStatic)]
+pub fn to_send_static(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    proc_macro::TokenStream::from(generate_traits(&input, &[TargetTrait::ToSendStatic]))
+}
+
+fn generate_traits(input: &DeriveInput, targets: &[TargetTrait]) -> TokenStream {
     match &input.data {
         Data::Struct(DataStruct {
             fields: Fields::Named(fields_named),
             ..
-        }) => data_struct::generate_struct_named(&input.ident, &input.generics, fields_named),
+        }) => {
+            data_struct::generate_struct_named(&input.ident, &input.generics, fields_named, targets)
+        }
         Data::Struct(DataStruct {
             fields: Fields::Unnamed(fields_unnamed),
             ..
-        }) => data_struct::generate_struct_unnamed(&input.ident, &input.generics, fields_unnamed),
+        }) => data_struct::generate_struct_unnamed(
+            &input.ident,
+            &input.generics,
+            fields_unnamed,
+            targets,
+        ),
         Data::Struct(DataStruct {
             fields: Fields::Unit,
             ..
-        }) => data_struct::generate_struct_unit(&input.ident),
+        }) => data_struct::generate_struct_unit(&input.ident, targets),
         Data::Enum(data_enum) => data_enum::generate_enum(
             &input.ident,
             &input.generics,
             data_enum.variants.iter().collect::<Vec<_>>().as_slice(),
+            targets,
         ),
         Data::Union(_) => unimplemented!("union is not yet supported"),
     }
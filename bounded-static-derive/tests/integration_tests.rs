@@ -1,5 +1,10 @@
-use bounded_static::{IntoBoundedStatic, ToBoundedStatic, ToStatic};
+use bounded_static::error::{ErrorKind, PathSegment};
+use bounded_static::{
+    Budget, Heap, IntoBoundedStatic, StaticConverter, StaticCost, ToBounded, ToBoundedStatic,
+    ToSendStatic, ToStatic, ToStaticAs, TryIntoBoundedStatic, TryStaticError, TryToBoundedStatic,
+};
 use std::borrow::Cow;
+use std::rc::Rc;
 
 #[test]
 fn test_struct_named_fields_1() {
@@ -419,6 +424,590 @@ fn test_generic_fully_qualified_enum() {
     ensure_static(owned);
 }
 
+#[test]
+fn test_try_struct_named_fields() {
+    #[derive(Debug, PartialEq, ToStatic)]
+    struct Foo<'a> {
+        value: Cow<'a, str>,
+        name: String,
+        bar: Vec<Bar>,
+    }
+    let value = String::from("value");
+    let data = Foo {
+        value: Cow::from(&value),
+        name: String::from("name"),
+        bar: vec![Bar(true)],
+    };
+    let owned = data.try_to_static().unwrap();
+    assert_eq!(data, owned);
+    ensure_static(owned);
+    ensure_static(data.try_into_static().unwrap());
+}
+
+#[test]
+fn test_try_struct_named_fields_error() {
+    #[derive(ToStatic)]
+    struct Foo<'a> {
+        value: Cow<'a, str>,
+        bar: Vec<Bar>,
+    }
+    let value = String::from("value");
+    let data = Foo {
+        value: Cow::from(&value),
+        bar: vec![Bar(true), Bar(false)],
+    };
+    let Err(err) = data.try_to_static() else {
+        panic!("expected error")
+    };
+    assert_eq!(&ErrorKind::Custom("invalid"), err.kind());
+    assert_eq!(
+        vec![&PathSegment::Field("bar"), &PathSegment::Index(1)],
+        err.path().collect::<Vec<_>>()
+    );
+    assert_eq!("invalid at .bar[1]", err.to_string());
+    assert!(data.try_into_static().is_err());
+}
+
+#[test]
+fn test_try_struct_unnamed_fields_error() {
+    #[derive(ToStatic)]
+    struct Foo<'a>(Cow<'a, str>, Bar);
+    let value = String::from("value");
+    let data = Foo(Cow::from(&value), Bar(false));
+    let Err(err) = data.try_to_static() else {
+        panic!("expected error")
+    };
+    assert_eq!("invalid at [1]", err.to_string());
+    assert!(data.try_into_static().is_err());
+}
+
+#[test]
+fn test_try_unit_struct() {
+    #[derive(ToStatic)]
+    struct Foo;
+    ensure_static(Foo.try_to_static().unwrap());
+    ensure_static(Foo.try_into_static().unwrap());
+}
+
+#[test]
+fn test_try_enum_error() {
+    #[derive(ToStatic)]
+    enum Foo<'a> {
+        First(Cow<'a, str>, Bar),
+        Second { r#fst: Bar },
+        Third,
+    }
+    let value = String::from("value");
+    let Err(err) = Foo::First(Cow::from(&value), Bar(false)).try_to_static() else {
+        panic!("expected error")
+    };
+    assert_eq!("invalid at ::First[1]", err.to_string());
+    let Err(err) = Foo::Second { fst: Bar(false) }.try_into_static() else {
+        panic!("expected error")
+    };
+    assert_eq!("invalid at ::Second.fst", err.to_string());
+    ensure_static(Foo::Third.try_to_static().unwrap());
+    ensure_static(
+        Foo::First(Cow::from(&value), Bar(true))
+            .try_into_static()
+            .unwrap(),
+    );
+}
+
+#[test]
+fn test_try_generic() {
+    #[derive(ToStatic)]
+    struct Foo<T: Into<String>> {
+        value: T,
+    }
+    let value = Foo { value: "test" };
+    ensure_static(value.try_to_static().unwrap());
+    ensure_static(value.try_into_static().unwrap());
+}
+
+#[test]
+fn test_struct_with_limit() {
+    #[derive(Debug, PartialEq, ToStatic)]
+    struct Foo<'a> {
+        value: Cow<'a, str>,
+        names: Vec<String>,
+    }
+    let value = String::from("value");
+    let data = Foo {
+        value: Cow::from(&value),
+        names: vec![String::from("name")],
+    };
+    let limit = 5 + std::mem::size_of::<String>() + 4;
+    assert_eq!(Ok(data.to_static()), data.to_static_with_limit(limit));
+    let Err(err) = data.to_static_with_limit(limit - 1) else {
+        panic!("expected error")
+    };
+    assert_eq!(&ErrorKind::LimitExceeded, err.kind());
+    assert_eq!("memory budget exceeded at .names[0]", err.to_string());
+    ensure_static(data.into_static_with_limit(limit).unwrap());
+}
+
+#[test]
+fn test_enum_with_limit() {
+    #[derive(ToStatic)]
+    enum Foo<'a> {
+        First(Cow<'a, str>),
+        Second { r#fst: String },
+        Third,
+    }
+    let value = String::from("value");
+    let Err(err) = Foo::First(Cow::from(&value)).to_static_with_limit(4) else {
+        panic!("expected error")
+    };
+    assert_eq!("memory budget exceeded at ::First[0]", err.to_string());
+    let data = Foo::Second {
+        fst: String::from("fst"),
+    };
+    let Err(err) = data.to_static_with_limit(2) else {
+        panic!("expected error")
+    };
+    assert_eq!("memory budget exceeded at ::Second.fst", err.to_string());
+    ensure_static(data.into_static_with_limit(0).unwrap());
+    ensure_static(Foo::Third.to_static_with_limit(0).unwrap());
+}
+
+#[test]
+fn test_enum_field_named_budget() {
+    #[derive(ToStatic)]
+    enum Foo<'a> {
+        First { budget: Cow<'a, str> },
+    }
+    let value = String::from("value");
+    let data = Foo::First {
+        budget: Cow::from(&value),
+    };
+    ensure_static(data.to_static());
+    ensure_static(data.to_static_with_limit(5).unwrap());
+    ensure_static(data.into_static_with_limit(5).unwrap());
+}
+
+#[test]
+fn test_static_cost() {
+    #[derive(ToStatic)]
+    struct Foo<'a> {
+        value: Cow<'a, str>,
+        bar: Bar<'a>,
+    }
+    #[derive(ToStatic)]
+    struct Bar<'a>(Vec<Cow<'a, str>>, Baz<'a>);
+    #[derive(ToStatic)]
+    enum Baz<'a> {
+        First(Cow<'a, str>),
+        Second { r#fst: String },
+    }
+    let value = String::from("value");
+    let data = Foo {
+        value: Cow::from(&value),
+        bar: Bar(
+            vec![Cow::from(&value[..2])],
+            Baz::Second {
+                fst: String::from("fst"),
+            },
+        ),
+    };
+    let report = data.static_cost();
+    assert_eq!(2, report.borrowed());
+    assert_eq!(7, report.borrowed_bytes());
+    assert_eq!(1, report.owned());
+    assert_eq!(3, report.owned_bytes());
+    let mut budget = Budget::new(usize::MAX);
+    ensure_static(data.to_static_with_budget(&mut budget).unwrap());
+    assert_eq!(budget.used(), report.bytes());
+    assert_eq!(1, Baz::First(Cow::from(&value)).static_cost().borrowed());
+}
+
+#[test]
+fn test_enum_field_named_report() {
+    #[derive(ToStatic)]
+    enum Foo<'a> {
+        First { report: Cow<'a, str> },
+    }
+    let value = String::from("value");
+    let data = Foo::First {
+        report: Cow::from(&value),
+    };
+    assert_eq!(5, data.static_cost().borrowed_bytes());
+    ensure_static(data.to_static());
+}
+
+#[test]
+fn test_enum_field_named_converter() {
+    #[derive(Debug, PartialEq, ToStatic)]
+    enum Foo<'a> {
+        First { converter: Cow<'a, str> },
+    }
+    let value = String::from("value");
+    let data = Foo::First {
+        converter: Cow::from(&value),
+    };
+    let to_static = StaticConverter::new().convert(&data);
+    assert_eq!(data, to_static);
+    ensure_static(to_static);
+}
+
+#[test]
+fn test_to_static_into() {
+    #[derive(Debug, PartialEq, ToStatic)]
+    struct Foo<'a> {
+        value: Cow<'a, str>,
+        bar: Bar<'a>,
+        baz: Vec<Baz<'a>>,
+    }
+    #[derive(Debug, PartialEq, ToStatic)]
+    struct Bar<'a>(Cow<'a, str>);
+    #[derive(Debug, PartialEq, ToStatic)]
+    enum Baz<'a> {
+        First(Cow<'a, str>),
+        Second { r#fst: String },
+        Third,
+    }
+    let value = String::from("value");
+    let mut dst = Foo {
+        value: Cow::Owned(String::with_capacity(16)),
+        bar: Bar(Cow::Owned(String::with_capacity(16))),
+        baz: vec![
+            Baz::First(Cow::Owned(String::with_capacity(16))),
+            Baz::First(Cow::Borrowed("static")),
+        ],
+    };
+    let value_ptr = dst.value.as_ptr();
+    let bar_ptr = dst.bar.0.as_ptr();
+    let baz_ptr = match &dst.baz[0] {
+        Baz::First(value) => value.as_ptr(),
+        _ => unreachable!(),
+    };
+    let data = Foo {
+        value: Cow::from(&value),
+        bar: Bar(Cow::from(&value)),
+        baz: vec![
+            Baz::First(Cow::from(&value)),
+            Baz::Second {
+                fst: String::from("fst"),
+            },
+            Baz::Third,
+        ],
+    };
+    data.to_static_into(&mut dst);
+    assert_eq!(data, dst);
+    assert_eq!(value_ptr, dst.value.as_ptr());
+    assert_eq!(bar_ptr, dst.bar.0.as_ptr());
+    assert!(matches!(&dst.baz[0], Baz::First(value) if value.as_ptr() == baz_ptr));
+}
+
+#[test]
+fn test_to_static_into_enum_field_named_dst() {
+    #[derive(Debug, PartialEq, ToStatic)]
+    enum Foo<'a> {
+        First { a: Cow<'a, str>, dst_a: u8 },
+    }
+    let value = String::from("value");
+    let mut dst = Foo::First {
+        a: Cow::Owned(String::with_capacity(16)),
+        dst_a: 0,
+    };
+    let data = Foo::First {
+        a: Cow::from(&value),
+        dst_a: 1,
+    };
+    data.to_static_into(&mut dst);
+    assert_eq!(data, dst);
+}
+
+#[test]
+fn test_to_static_with() {
+    #[derive(ToStatic)]
+    struct Graph<'a> {
+        nodes: Vec<Rc<Node<'a>>>,
+        root: Edge<'a>,
+    }
+    #[derive(ToStatic)]
+    struct Node<'a> {
+        name: Cow<'a, str>,
+        edges: Vec<Edge<'a>>,
+    }
+    #[derive(ToStatic)]
+    enum Edge<'a> {
+        To(Rc<Node<'a>>),
+        Named {
+            to: Rc<Node<'a>>,
+            r#type: Cow<'a, str>,
+        },
+        Leaf,
+    }
+    let name = String::from("name");
+    let leaf = Rc::new(Node {
+        name: Cow::from(&name),
+        edges: vec![Edge::Leaf],
+    });
+    let node = Rc::new(Node {
+        name: Cow::from(&name),
+        edges: vec![
+            Edge::To(leaf.clone()),
+            Edge::Named {
+                to: leaf.clone(),
+                r#type: Cow::from(&name),
+            },
+        ],
+    });
+    let graph = Graph {
+        nodes: vec![node.clone(), leaf],
+        root: Edge::To(node),
+    };
+    let to_static = StaticConverter::new().convert(&graph);
+    let Edge::To(root) = &to_static.root else {
+        unreachable!()
+    };
+    assert!(Rc::ptr_eq(root, &to_static.nodes[0]));
+    assert!(matches!(&root.edges[0], Edge::To(to) if Rc::ptr_eq(to, &to_static.nodes[1])));
+    assert!(
+        matches!(&root.edges[1], Edge::Named { to, .. } if Rc::ptr_eq(to, &to_static.nodes[1]))
+    );
+    let to_static = graph.to_static();
+    assert!(matches!(&to_static.root, Edge::To(root) if !Rc::ptr_eq(root, &to_static.nodes[0])));
+}
+
+#[test]
+fn test_instrument() {
+    #[derive(ToStatic)]
+    struct Foo<'a> {
+        value: Cow<'a, str>,
+        bar: Bar<'a>,
+    }
+    #[derive(ToStatic)]
+    struct Bar<'a>(Cow<'a, str>, String);
+    let value = String::from("value");
+    let data = Foo {
+        value: Cow::from(&value),
+        bar: Bar(Cow::Owned(String::from("owned")), String::from("string")),
+    };
+    let (owned, report) = bounded_static::instrument::collect(|| data.into_static());
+    ensure_static(owned);
+    assert_eq!(2, report.total().conversions());
+    assert_eq!(1, report.total().allocations());
+    assert_eq!(1, report.total().reused());
+    let foo = report.get(concat!(module_path!(), "::Foo")).unwrap();
+    assert_eq!(1, foo.conversions());
+    assert_eq!(1, foo.allocations());
+    assert_eq!(5, foo.bytes());
+    let bar = report.get(concat!(module_path!(), "::Bar")).unwrap();
+    assert_eq!(1, bar.conversions());
+    assert_eq!(0, bar.allocations());
+    assert_eq!(1, bar.reused());
+}
+
+#[test]
+fn test_to_bounded() {
+    #[derive(ToStatic, ToBounded, Debug, PartialEq)]
+    struct Foo<'a, T: Clone> {
+        value: Cow<'a, str>,
+        items: Vec<T>,
+        bar: Bar<'a>,
+        unit: Unit,
+    }
+    #[derive(ToStatic, ToBounded, Debug, PartialEq)]
+    enum Bar<'a> {
+        Named { value: Cow<'a, str> },
+        Unnamed(Option<Cow<'a, [u8]>>, u32),
+        Unit,
+    }
+    #[derive(ToStatic, ToBounded, Debug, PartialEq)]
+    struct Unit;
+    fn rebind<'b>(foo: &Foo<'_, String>, heap: &'b Heap) -> Foo<'b, String> {
+        foo.to_bounded_in(heap)
+    }
+    let heap = Heap;
+    let value = String::from("value");
+    let data = Foo {
+        value: Cow::from(&value),
+        items: vec![String::from("item")],
+        bar: Bar::Unnamed(Some(Cow::from(value.as_bytes())), 1),
+        unit: Unit,
+    };
+    let output = rebind(&data, &heap);
+    assert_eq!(data, output);
+    drop(data);
+    drop(value);
+    assert!(matches!(output.value, Cow::Owned(_)));
+    let bar = Bar::Named {
+        value: Cow::from("named"),
+    };
+    assert_eq!(bar, bar.to_bounded_in(&Heap));
+    assert_eq!(Bar::Unit, Bar::Unit.to_bounded_in(&Heap));
+}
+
+#[test]
+fn test_enum_field_named_alloc() {
+    #[derive(ToStatic, ToBounded, Debug, PartialEq)]
+    enum Foo<'a> {
+        First { alloc: Cow<'a, str> },
+    }
+    let value = String::from("value");
+    let data = Foo::First {
+        alloc: Cow::from(&value),
+    };
+    assert_eq!(data, data.to_bounded_in(&Heap));
+}
+
+#[test]
+fn test_to_static_as() {
+    use bounded_static::repr::{Compact, Owned};
+    #[derive(ToStaticAs, Debug, PartialEq)]
+    struct Foo<S, L> {
+        name: S,
+        aliases: L,
+        bar: Bar<S>,
+        unit: Unit,
+    }
+    #[derive(ToStaticAs, Debug, PartialEq)]
+    enum Bar<S> {
+        Named { value: S },
+        Unnamed(Option<S>, u32),
+        Unit,
+    }
+    #[derive(ToStaticAs, Debug, PartialEq)]
+    struct Unit;
+    let value = String::from("value");
+    let data = Foo {
+        name: Cow::from(&value),
+        aliases: vec![Cow::from(&value)],
+        bar: Bar::Unnamed(Some(Cow::from(&value)), 1),
+        unit: Unit,
+    };
+    let output: Foo<Box<str>, Box<[Box<str>]>> = data.to_static_as(Compact);
+    drop(data);
+    drop(value);
+    assert_eq!(Box::from("value"), output.name);
+    assert_eq!(1, output.aliases.len());
+    assert_eq!(Bar::Unnamed(Some(Box::from("value")), 1), output.bar);
+    let bar = Bar::Named {
+        value: Cow::from("named"),
+    };
+    assert_eq!(
+        Bar::Named {
+            value: String::from("named")
+        },
+        bar.to_static_as(Owned)
+    );
+    assert_eq!(
+        Bar::<String>::Unit,
+        Bar::<Cow<'_, str>>::Unit.to_static_as(Owned)
+    );
+    assert_eq!(Unit, Unit.to_static_as(Compact));
+}
+
+#[test]
+fn test_enum_field_named_repr() {
+    use bounded_static::repr::Compact;
+    #[derive(ToStaticAs, Debug, PartialEq)]
+    enum Foo<S> {
+        First { repr: S },
+    }
+    let value = String::from("value");
+    let data = Foo::First {
+        repr: Cow::from(&value),
+    };
+    let to_static: Foo<Box<str>> = data.to_static_as(Compact);
+    assert_eq!(
+        Foo::First {
+            repr: Box::from("value")
+        },
+        to_static
+    );
+}
+
+#[test]
+fn test_to_send_static() {
+    use std::cell::RefCell;
+    use std::sync::Arc;
+    #[derive(ToSendStatic, Debug)]
+    struct Foo<'a, P, C> {
+        value: Cow<'a, str>,
+        shared: P,
+        cell: C,
+        bar: Bar<'a>,
+        unit: Unit,
+    }
+    #[derive(ToSendStatic, Debug, PartialEq)]
+    enum Bar<'a> {
+        Named { value: Cow<'a, str> },
+        Unnamed(Option<Cow<'a, [u8]>>, u32),
+        Unit,
+    }
+    #[derive(ToSendStatic, Debug, PartialEq)]
+    struct Unit;
+    fn ensure_send<T: Send + Sync + 'static>(_: &T) {}
+    let value = String::from("value");
+    let data = Foo {
+        value: Cow::from(&value),
+        shared: Rc::new(vec![Cow::from(&value)]),
+        cell: RefCell::new(1_u32),
+        bar: Bar::Unnamed(Some(Cow::from(value.as_bytes())), 1),
+        unit: Unit,
+    };
+    let output: Foo<'static, Arc<Vec<Cow<'static, str>>>, std::sync::Mutex<u32>> =
+        data.to_send_static();
+    drop(data);
+    drop(value);
+    ensure_send(&output);
+    std::thread::spawn(move || {
+        assert_eq!("value", output.value);
+        assert_eq!(["value"], output.shared.as_slice());
+        assert_eq!(1, *output.cell.lock().unwrap());
+        assert_eq!(Bar::Unnamed(Some(Cow::from(&b"value"[..])), 1), output.bar);
+    })
+    .join()
+    .unwrap();
+    let bar = Bar::Named {
+        value: Cow::from("named"),
+    };
+    assert_eq!(bar, bar.to_send_static());
+    assert_eq!(Bar::Unit, Bar::Unit.to_send_static());
+    assert_eq!(Unit, Unit.to_send_static());
+}
+
+/// A type whose budgeted conversion fails if it is not valid.
+#[derive(Debug, Clone, PartialEq)]
+struct Bar(bool);
+
+impl Bar {
+    fn check(&self) -> Result<Self, TryStaticError> {
+        if self.0 {
+            Ok(self.clone())
+        } else {
+            Err(TryStaticError::new(ErrorKind::Custom("invalid")))
+        }
+    }
+}
+
+impl ToBoundedStatic for Bar {
+    type Static = Self;
+
+    fn to_static(&self) -> Self::Static {
+        self.check().expect("valid")
+    }
+
+    fn to_static_with_budget(&self, _: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        self.check()
+    }
+}
+
+impl IntoBoundedStatic for Bar {
+    type Static = Self;
+
+    fn into_static(self) -> Self::Static {
+        self.to_static()
+    }
+
+    fn into_static_with_budget(self, _: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        self.check()
+    }
+}
+
 fn ensure_static<S: 'static>(s: S) {
     drop(s);
 }
@@ -0,0 +1,136 @@
+//! Benchmarks for the collection fast paths.
+//!
+//! Each conversion is timed for a small and a large collection.  The time taken by a fast path which reuses the
+//! allocation does not grow with the length of the collection, a fast path which copies or clones the collection grows
+//! with a single `memcpy` or table clone, and the general path converts every element in turn.
+//!
+//! Run with `cargo bench -p bounded-static --bench fast_path`.
+
+use bounded_static::{IntoBoundedStatic, ToBoundedStatic};
+use std::borrow::Cow;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// The number of times each conversion is timed, the median of which is reported.
+const SAMPLES: usize = 21;
+
+/// The lengths of the collections which are converted.
+const LENGTHS: [usize; 2] = [1_000, 1_000_000];
+
+/// Time `run` on the output of `setup` and report the median.
+///
+/// Neither `setup` nor dropping the output of `run` is timed.
+fn bench<I, O>(name: &str, len: usize, mut setup: impl FnMut() -> I, mut run: impl FnMut(I) -> O) {
+    let mut samples = Vec::with_capacity(SAMPLES);
+    for _ in 0..SAMPLES {
+        let input = setup();
+        let start = Instant::now();
+        let output = black_box(run(black_box(input)));
+        samples.push(start.elapsed());
+        drop(output);
+    }
+    samples.sort_unstable();
+    let median = samples[SAMPLES / 2];
+    println!(
+        "{name:<46} {len:>9} {:>14} {:>12.3}",
+        format_duration(median),
+        per_element(median, len)
+    );
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3}us", duration.as_secs_f64() * 1_000_000.0)
+}
+
+fn per_element(duration: Duration, len: usize) -> f64 {
+    duration.as_secs_f64() * 1_000_000_000.0 / len as f64
+}
+
+fn strings(len: usize) -> Vec<String> {
+    (0..len).map(|i| i.to_string()).collect()
+}
+
+fn main() {
+    println!(
+        "{:<46} {:>9} {:>14} {:>12}",
+        "conversion", "length", "median", "ns/element"
+    );
+    for len in LENGTHS {
+        let numbers: Vec<u64> = (0..len as u64).collect();
+        let strings = strings(len);
+        let optional: Vec<Option<u64>> = numbers.iter().copied().map(Some).collect();
+        let cows: Vec<Cow<'_, str>> = strings.iter().map(|s| Cow::from(s.as_str())).collect();
+        let map: HashMap<String, u64> = strings
+            .iter()
+            .cloned()
+            .zip(numbers.iter().copied())
+            .collect();
+        let cow_map: HashMap<Cow<'_, str>, u64> =
+            cows.iter().cloned().zip(numbers.iter().copied()).collect();
+
+        bench(
+            "Vec<u64>::into_static",
+            len,
+            || numbers.clone(),
+            IntoBoundedStatic::into_static,
+        );
+        bench(
+            "Vec<String>::into_static",
+            len,
+            || strings.clone(),
+            IntoBoundedStatic::into_static,
+        );
+        bench(
+            "VecDeque<u64>::into_static",
+            len,
+            || VecDeque::from(numbers.clone()),
+            IntoBoundedStatic::into_static,
+        );
+        bench(
+            "BinaryHeap<u64>::into_static",
+            len,
+            || BinaryHeap::from(numbers.clone()),
+            IntoBoundedStatic::into_static,
+        );
+        bench(
+            "Vec<Cow<str>>::into_static (general)",
+            len,
+            || cows.clone(),
+            IntoBoundedStatic::into_static,
+        );
+
+        bench("Vec<u64>::to_static", len, || &numbers, |v| v.to_static());
+        bench(
+            "Vec<Option<u64>>::to_static (general)",
+            len,
+            || &optional,
+            |v| v.to_static(),
+        );
+
+        bench(
+            "HashMap<String, u64>::to_static",
+            len,
+            || &map,
+            |m| m.to_static(),
+        );
+        bench(
+            "HashMap<Cow<str>, u64>::to_static",
+            len,
+            || &cow_map,
+            |m| m.to_static(),
+        );
+        #[cfg(feature = "hashbrown")]
+        {
+            let cow_map: hashbrown::HashMap<_, _, std::collections::hash_map::RandomState> =
+                cow_map.clone().into_iter().collect();
+            bench(
+                "hashbrown::HashMap<Cow<str>, u64>::to_static",
+                len,
+                || &cow_map,
+                |m| m.to_static(),
+            );
+        }
+        println!();
+    }
+}
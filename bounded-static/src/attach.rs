@@ -0,0 +1,217 @@
+//! A `'static` handle to a borrowed view which is kept alongside the owner it borrows from.
+//!
+//! Converting a value with [`into_static`](crate::IntoBoundedStatic::into_static) copies all of its borrowed data,
+//! which may be too expensive for a large document which contains many borrowed slices.  The [`Attached`] type
+//! instead keeps the owner of the data, such as a `Vec<u8>`, an `Arc<[u8]>` or a memory map, alive alongside a view
+//! which borrows from it, and is itself bounded by `'static`.  It is a safe wrapper around a
+//! [`Yoke`](https://docs.rs/yoke/0.7.5/yoke/struct.Yoke.html) and requires the `yoke` feature.
+//!
+//! The view type is given in its `'static` form, such as `Cow<'static, str>`, and must implement
+//! [`Yokeable`](https://docs.rs/yoke/0.7.5/yoke/trait.Yokeable.html), which may be derived with
+//! `#[derive(yoke::Yokeable)]`.  The owner must implement
+//! [`StableDeref`](https://docs.rs/stable_deref_trait/1.2.0/stable_deref_trait/trait.StableDeref.html), such that the
+//! data it dereferences to does not move when the owner is moved.
+//!
+//! The view type cannot be inferred from the return type of the function which borrows it and so is named when
+//! attaching, as in the example below.
+//!
+//! The choice between attaching and copying is made per call site: a view may be attached to its owner with
+//! [`Attached::attach`] or copied with `into_static()` as usual, and an attached view may later be copied and detached
+//! from its owner with [`Attached::detach`].
+//!
+//! An `Attached` value may always be moved with `into_static()`, but `to_static()` clones the owner and so is only
+//! provided for owners whose clone shares the data, such as an `Rc` or an `Arc`, and not for a `Vec<u8>`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use std::sync::Arc;
+//! # use bounded_static::Attached;
+//! fn first_word(text: &str) -> Cow<'_, str> {
+//!     Cow::Borrowed(text.split(' ').next().unwrap_or_default())
+//! }
+//!
+//! let owner: Arc<str> = Arc::from("attached view");
+//! let attached = Attached::<Arc<str>, Cow<'static, str>>::attach(owner, first_word);
+//! std::thread::spawn(move || {
+//!     assert_eq!("attached", attached.get());
+//!     assert!(matches!(attached.get(), Cow::Borrowed(_)));
+//! })
+//! .join()
+//! .unwrap();
+//! ```
+
+use crate::{IntoBoundedStatic, ToBoundedStatic};
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use stable_deref_trait::StableDeref;
+use yoke::{CloneableCart, Yoke, Yokeable};
+
+/// A view `V` which borrows from an owner `O` and is kept alongside it, such that the pair is bounded by `'static`.
+///
+/// See the `attach` module for details.
+pub struct Attached<O, V>
+where
+    V: for<'a> Yokeable<'a>,
+{
+    yoke: Yoke<V, O>,
+}
+
+impl<O, V> Attached<O, V>
+where
+    O: StableDeref,
+    O::Target: 'static,
+    V: for<'a> Yokeable<'a>,
+{
+    /// Attach the view returned by `f`, which may only borrow from the data of `owner`, to `owner`.
+    pub fn attach<F>(owner: O, f: F) -> Self
+    where
+        F: for<'a> FnOnce(&'a O::Target) -> <V as Yokeable<'a>>::Output,
+    {
+        Self {
+            yoke: Yoke::attach_to_cart(owner, f),
+        }
+    }
+
+    /// Attach the view returned by `f` to `owner`, or return the error returned by `f`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error returned by `f`.
+    pub fn try_attach<E, F>(owner: O, f: F) -> Result<Self, E>
+    where
+        F: for<'a> FnOnce(&'a O::Target) -> Result<<V as Yokeable<'a>>::Output, E>,
+    {
+        Yoke::try_attach_to_cart(owner, f).map(|yoke| Self { yoke })
+    }
+}
+
+impl<O, V> Attached<O, V>
+where
+    V: for<'a> Yokeable<'a>,
+{
+    /// The view, borrowed for the lifetime of `self`.
+    pub fn get(&self) -> &<V as Yokeable<'_>>::Output {
+        self.yoke.get()
+    }
+
+    /// The owner which the view borrows from.
+    pub fn owner(&self) -> &O {
+        self.yoke.backing_cart()
+    }
+
+    /// Drop the view and return the owner.
+    pub fn into_owner(self) -> O {
+        self.yoke.into_backing_cart()
+    }
+
+    /// Map the view to another view which borrows from the same owner.
+    pub fn map<P, F>(self, f: F) -> Attached<O, P>
+    where
+        P: for<'a> Yokeable<'a>,
+        F: for<'a> FnOnce(
+            <V as Yokeable<'a>>::Output,
+            PhantomData<&'a ()>,
+        ) -> <P as Yokeable<'a>>::Output,
+    {
+        Attached {
+            yoke: self.yoke.map_project(f),
+        }
+    }
+
+    /// Project a part of the view to a new view which shares the owner.
+    ///
+    /// The owner must be cheap to clone, such as an `Rc` or an `Arc`.
+    pub fn project<'this, P, F>(&'this self, f: F) -> Attached<O, P>
+    where
+        O: CloneableCart,
+        P: for<'a> Yokeable<'a>,
+        F: for<'a> FnOnce(
+            &'this <V as Yokeable<'a>>::Output,
+            PhantomData<&'a ()>,
+        ) -> <P as Yokeable<'a>>::Output,
+    {
+        Attached {
+            yoke: self.yoke.map_project_cloned(f),
+        }
+    }
+
+    /// Copy the view with [`to_static`](ToBoundedStatic::to_static), such that it no longer borrows from the owner.
+    #[must_use]
+    pub fn detach(&self) -> V
+    where
+        for<'a> <V as Yokeable<'a>>::Output: ToBoundedStatic<Static = V>,
+    {
+        self.yoke.get().to_static()
+    }
+}
+
+impl<O, V> Clone for Attached<O, V>
+where
+    V: for<'a> Yokeable<'a>,
+    Yoke<V, O>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            yoke: self.yoke.clone(),
+        }
+    }
+}
+
+impl<O, V> Debug for Attached<O, V>
+where
+    O: Debug,
+    V: for<'a> Yokeable<'a>,
+    for<'a> <V as Yokeable<'a>>::Output: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Attached")
+            .field("owner", self.owner())
+            .field("view", self.get())
+            .finish()
+    }
+}
+
+/// [`ToBoundedStatic`] impl for `Attached<O, V>`, which clones the owner and the view.
+///
+/// This requires that the owner implements [`CloneableCart`], such as an `Rc` or an `Arc`, whose clone shares the data
+/// which the view borrows from.  An owner such as a `Vec<u8>` would be deep-copied by a clone, leaving the view
+/// borrowing from the original, and so `Attached<Vec<u8>, V>` does not implement `ToBoundedStatic`.  Such a value may
+/// instead be moved with [`into_static`](IntoBoundedStatic::into_static) or copied and detached from its owner with
+/// [`Attached::detach`].
+impl<O, V> ToBoundedStatic for Attached<O, V>
+where
+    O: 'static,
+    V: for<'a> Yokeable<'a>,
+    Yoke<V, O>: Clone,
+{
+    type Static = Self;
+
+    fn to_static(&self) -> Self::Static {
+        self.clone()
+    }
+}
+
+/// No-op [`IntoBoundedStatic`] impl for `Attached<O, V>`.
+impl<O, V> IntoBoundedStatic for Attached<O, V>
+where
+    O: 'static,
+    V: for<'a> Yokeable<'a>,
+{
+    type Static = Self;
+
+    fn into_static(self) -> Self::Static {
+        self
+    }
+}
+
+crate::__to_bounded!(
+    clone impl[O, V] Attached<O, V> where O: 'static, V: for<'a> Yokeable<'a>, Yoke<V, O>: Clone
+);
+crate::__to_static_as!(
+    clone impl[O, V] Attached<O, V> where O: 'static, V: for<'a> Yokeable<'a>, Yoke<V, O>: Clone
+);
+crate::__to_send_static!(
+    [send] clone impl[O, V] Attached<O, V> where O: 'static, V: for<'a> Yokeable<'a>, Yoke<V, O>: Clone
+);
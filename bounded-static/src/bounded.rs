@@ -0,0 +1,96 @@
+//! Conversions to a form that is bounded by an arbitrary lifetime `'b`.
+//!
+//! The [`ToBoundedStatic`](crate::ToBoundedStatic) trait converts a value to a form that is bounded by `'static`,
+//! which requires that all borrowed data is copied to the heap.  The [`ToBounded`] trait instead converts a value to a
+//! form that is bounded by a lifetime `'b` chosen by the caller, such as the lifetime of an arena or of a request,
+//! and copies borrowed data with a [`BoundedAlloc`] which lives for `'b`.
+//!
+//! The [`Heap`] allocator copies borrowed data to the heap, such that converting with `&Heap` for the `'static`
+//! lifetime is equivalent to [`to_static`](crate::ToBoundedStatic::to_static).  When the `bumpalo` feature is enabled
+//! [`BoundedAlloc`] is implemented for `bumpalo::Bump`, which copies borrowed strings and slices into the arena and
+//! keeps them borrowed from it.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use bounded_static::{Heap, ToBounded};
+//! fn longer_lived<'b>(value: &[Cow<'_, str>], alloc: &'b Heap) -> Vec<Cow<'b, str>> {
+//!     value.to_vec().to_bounded_in(alloc)
+//! }
+//!
+//! let input = String::from("value");
+//! let output = longer_lived(&[Cow::from(input.as_str())], &Heap);
+//! drop(input);
+//! assert_eq!(vec!["value"], output);
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, borrow::ToOwned};
+
+/// A trait for converting `&T` to an owned `T` such that `T: 'b`, copying borrowed data with an allocator `A`.
+///
+/// This is a generalisation of [`ToBoundedStatic`](crate::ToBoundedStatic) to lifetimes other than `'static`, see
+/// the `bounded` module for details.  It is implemented for the types supported by
+/// [`ToBoundedStatic`](crate::ToBoundedStatic) which hold borrowed data or other values, and for the types given to
+/// the [`impl_copy`](crate::impl_copy), [`impl_clone`](crate::impl_clone) and
+/// [`impl_into_owned`](crate::impl_into_owned) macros.
+///
+/// [`ToBoundedStatic`](crate::ToBoundedStatic) is a supertrait, such that every type which can be converted to `'b`
+/// can also be converted to `'static`.
+///
+/// It may be derived with the `ToBounded` derive macro when the `derive` feature is enabled, together with the
+/// `ToStatic` derive macro.
+pub trait ToBounded<'b, A: ?Sized = Heap>: crate::ToBoundedStatic {
+    /// The target type is bounded by the `'b` lifetime.
+    type Output: 'b;
+
+    /// Convert an `&T` to an owned `T` such that `T: 'b`, copying any borrowed data with `alloc`.
+    #[must_use]
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output;
+}
+
+/// An allocator which borrowed data may be copied into for the lifetime `'b`.
+///
+/// The provided methods copy the data to the heap and so an allocator only needs to override the methods for the
+/// data it can store.
+pub trait BoundedAlloc<'b> {
+    /// Copy a string such that it lives for `'b`.
+    #[cfg(feature = "alloc")]
+    fn alloc_str(&'b self, value: &str) -> Cow<'b, str> {
+        Cow::Owned(value.to_owned())
+    }
+
+    /// Copy a slice of `Copy` values such that it lives for `'b`.
+    #[cfg(feature = "alloc")]
+    fn alloc_slice<T>(&'b self, value: &[T]) -> Cow<'b, [T]>
+    where
+        T: Copy + 'b,
+    {
+        Cow::Owned(value.to_owned())
+    }
+}
+
+/// A [`BoundedAlloc`] which copies borrowed data to the heap.
+///
+/// Converting a value with [`to_bounded_in`](ToBounded::to_bounded_in) and `&Heap` produces the same value as
+/// [`to_static`](crate::ToBoundedStatic::to_static), but bounded by the chosen lifetime.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Heap;
+
+impl BoundedAlloc<'_> for Heap {}
+
+/// [`BoundedAlloc`] impl which copies borrowed data into a `bumpalo::Bump` arena.
+#[cfg(all(feature = "alloc", feature = "bumpalo"))]
+impl<'b> BoundedAlloc<'b> for bumpalo::Bump {
+    fn alloc_str(&'b self, value: &str) -> Cow<'b, str> {
+        Cow::Borrowed(Self::alloc_str(self, value))
+    }
+
+    fn alloc_slice<T>(&'b self, value: &[T]) -> Cow<'b, [T]>
+    where
+        T: Copy + 'b,
+    {
+        Cow::Borrowed(self.alloc_slice_copy(value))
+    }
+}
@@ -0,0 +1,88 @@
+//! A memory budget for converting untrusted input.
+
+use crate::error::{ErrorKind, TryStaticError};
+
+/// A limit on the number of heap bytes which may be allocated during a conversion.
+///
+/// A `Budget` is threaded through [`to_static_with_budget`](crate::ToBoundedStatic::to_static_with_budget) and
+/// [`into_static_with_budget`](crate::IntoBoundedStatic::into_static_with_budget) and each impl which allocates
+/// [`charge`](Budget::charge)s the number of bytes it will allocate before allocating them.  The conversion stops with
+/// an error of kind [`ErrorKind::LimitExceeded`] once the limit is exceeded.
+///
+/// # Examples
+///
+/// ```rust
+/// # use bounded_static::{Budget, ToBoundedStatic};
+/// let value = vec![String::from("aaaa"), String::from("bbbb")];
+/// let mut budget = Budget::new(1024);
+/// let to_static = value.to_static_with_budget(&mut budget).unwrap();
+/// assert_eq!(2 * std::mem::size_of::<String>() + 8, budget.used());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Budget {
+    limit: usize,
+    used: usize,
+}
+
+impl Budget {
+    /// Create a new `Budget` which allows up to `limit` heap bytes to be allocated.
+    #[must_use]
+    pub const fn new(limit: usize) -> Self {
+        Self { limit, used: 0 }
+    }
+
+    /// Create a new `Budget` with no limit, which only counts the heap bytes charged against it.
+    #[must_use]
+    pub const fn unlimited() -> Self {
+        Self::new(usize::MAX)
+    }
+
+    /// Charge `bytes` against this budget.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`ErrorKind::LimitExceeded`] if charging `bytes` would exceed the limit, in which case
+    /// nothing is charged.
+    pub fn charge(&mut self, bytes: usize) -> Result<(), TryStaticError> {
+        let used = self.used.saturating_add(bytes);
+        if used > self.limit {
+            return Err(TryStaticError::new(ErrorKind::LimitExceeded));
+        }
+        self.used = used;
+        Ok(())
+    }
+
+    /// Charge the size of `len` values of type `T` against this budget.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`ErrorKind::LimitExceeded`] if charging would exceed the limit, in which case nothing
+    /// is charged.
+    pub fn charge_for<T>(&mut self, len: usize) -> Result<(), TryStaticError> {
+        self.charge(core::mem::size_of::<T>().saturating_mul(len))
+    }
+
+    /// The maximum number of bytes which may be charged.
+    #[must_use]
+    pub const fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// The number of bytes charged so far.
+    #[must_use]
+    pub const fn used(&self) -> usize {
+        self.used
+    }
+
+    /// Does this budget have no limit?
+    #[must_use]
+    pub const fn is_unlimited(&self) -> bool {
+        self.limit == usize::MAX
+    }
+
+    /// The number of bytes which may still be charged.
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        self.limit - self.used
+    }
+}
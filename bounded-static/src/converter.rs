@@ -0,0 +1,156 @@
+//! Conversions which preserve the sharing of `Rc` and `Arc` pointers.
+
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, collections::BTreeMap, collections::BTreeSet, rc::Rc};
+#[cfg(feature = "alloc")]
+use core::any::{Any, TypeId};
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+
+/// A conversion session which preserves the sharing of `Rc` and `Arc` pointers.
+///
+/// Converting a value with [`to_static`](crate::ToBoundedStatic::to_static) converts the value behind every `Rc` and
+/// `Arc` pointer it holds separately, such that a value which is shared by several pointers is duplicated once per
+/// pointer.  Converting a value with [`to_static_with`](crate::ToBoundedStatic::to_static_with) instead records the
+/// address of each pointer which is converted in the `StaticConverter`, such that every pointer to the same value is
+/// converted to a pointer to the same converted value.
+///
+/// A `StaticConverter` may be used to convert several values, such as the nodes of a graph, in which case the
+/// pointers shared between the values are also preserved.  The values must outlive the `StaticConverter`.
+///
+/// If enabled with [`dedup_strings`](StaticConverter::dedup_strings), equal `Rc<str>` and `Arc<str>` values are also
+/// converted to pointers to the same string, even if they did not share the same allocation.
+///
+/// Note that the values held by a `RefCell`, `Mutex` or `RwLock` may change during the conversion and so are converted
+/// with `to_static` and do not share pointers with the rest of the value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use std::rc::Rc;
+/// # use bounded_static::{StaticConverter, ToBoundedStatic};
+/// let input = String::from("shared");
+/// let shared = Rc::new(Cow::from(input.as_str()));
+/// let value = vec![shared.clone(), shared];
+/// let to_static = StaticConverter::new().convert(&value);
+/// assert!(Rc::ptr_eq(&to_static[0], &to_static[1]));
+/// ```
+#[derive(Default)]
+pub struct StaticConverter<'a> {
+    #[cfg(feature = "alloc")]
+    pointers: BTreeMap<(usize, TypeId), Box<dyn Any>>,
+    #[cfg(feature = "alloc")]
+    rc_strings: Option<BTreeSet<Rc<str>>>,
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    arc_strings: Option<BTreeSet<Arc<str>>>,
+    _values: PhantomData<&'a ()>,
+}
+
+impl<'a> StaticConverter<'a> {
+    /// Create a new `StaticConverter`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also convert equal `Rc<str>` and `Arc<str>` values to pointers to the same string.
+    #[must_use]
+    pub fn dedup_strings(self) -> Self {
+        #[cfg_attr(not(feature = "alloc"), allow(unused_mut))]
+        let mut this = self;
+        #[cfg(feature = "alloc")]
+        {
+            this.rc_strings = Some(BTreeSet::new());
+        }
+        #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+        {
+            this.arc_strings = Some(BTreeSet::new());
+        }
+        this
+    }
+
+    /// Convert a value with [`to_static_with`](crate::ToBoundedStatic::to_static_with).
+    pub fn convert<T>(&mut self, value: &'a T) -> T::Static
+    where
+        T: crate::ToBoundedStatic + ?Sized,
+    {
+        value.to_static_with(self)
+    }
+
+    /// Convert the shared value at the given address with `convert`, or return a clone of the pointer `P` it was
+    /// previously converted to.
+    ///
+    /// This is used by the impls for `Rc` and `Arc` and may be used to implement
+    /// [`to_static_with`](crate::ToBoundedStatic::to_static_with) for other shared pointers.
+    #[cfg(feature = "alloc")]
+    pub fn shared<T, P>(&mut self, address: *const T, convert: impl FnOnce(&mut Self) -> P) -> P
+    where
+        T: ?Sized,
+        P: Clone + 'static,
+    {
+        let key = (address.cast::<()>() as usize, TypeId::of::<P>());
+        if let Some(shared) = self.pointers.get(&key).and_then(|p| p.downcast_ref::<P>()) {
+            return shared.clone();
+        }
+        let shared = convert(self);
+        self.pointers.insert(key, Box::new(shared.clone()));
+        shared
+    }
+
+    /// The number of shared values which have been converted.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        #[cfg(feature = "alloc")]
+        return self.pointers.len();
+        #[cfg(not(feature = "alloc"))]
+        return 0;
+    }
+
+    /// Returns true if no shared values have been converted.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Deduplicate the given `Rc<str>`, if enabled.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn rc_str(&mut self, value: &Rc<str>) -> Rc<str> {
+        self.rc_strings
+            .as_mut()
+            .map_or_else(|| value.clone(), |strings| dedup(strings, value))
+    }
+
+    /// Deduplicate the given `Arc<str>`, if enabled.
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    pub(crate) fn arc_str(&mut self, value: &Arc<str>) -> Arc<str> {
+        self.arc_strings
+            .as_mut()
+            .map_or_else(|| value.clone(), |strings| dedup(strings, value))
+    }
+}
+
+/// Return the value in `strings` which is equal to `value`, inserting `value` if there is none.
+#[cfg(feature = "alloc")]
+fn dedup<P>(strings: &mut BTreeSet<P>, value: &P) -> P
+where
+    P: Ord + Clone + core::borrow::Borrow<str>,
+{
+    let key: &str = value.borrow();
+    if let Some(existing) = strings.get(key) {
+        return existing.clone();
+    }
+    strings.insert(value.clone());
+    value.clone()
+}
+
+impl core::fmt::Debug for StaticConverter<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StaticConverter")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
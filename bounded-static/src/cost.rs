@@ -0,0 +1,133 @@
+//! Estimate the cost of converting a value to a form that is bounded by `'static`.
+
+use crate::ToBoundedStatic;
+
+/// A report of the cost of converting a value with [`to_static`](ToBoundedStatic::to_static).
+///
+/// The report counts the _leaves_ of a value which hold heap data, such as a `String` or a `Cow`, split by whether
+/// they borrow data (a `Cow::Borrowed`) or own it, together with the number of bytes held by those leaves.  The total
+/// [`bytes`](CostReport::bytes) also includes the buffers of any containers and is the number of heap bytes which
+/// `to_static()` would allocate, as charged to a [`Budget`](crate::Budget) by
+/// [`to_static_with_budget`](ToBoundedStatic::to_static_with_budget).
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use bounded_static::StaticCost;
+/// let input = String::from("borrowed");
+/// let value = (Cow::from(&input), String::from("owned"));
+/// let report = value.static_cost();
+/// assert_eq!(1, report.borrowed());
+/// assert_eq!(8, report.borrowed_bytes());
+/// assert_eq!(1, report.owned());
+/// assert_eq!(5, report.owned_bytes());
+/// assert_eq!(13, report.bytes());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CostReport {
+    borrowed: usize,
+    borrowed_bytes: usize,
+    owned: usize,
+    owned_bytes: usize,
+    bytes: usize,
+}
+
+impl CostReport {
+    /// Create a new, empty, `CostReport`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            borrowed: 0,
+            borrowed_bytes: 0,
+            owned: 0,
+            owned_bytes: 0,
+            bytes: 0,
+        }
+    }
+
+    /// Add a leaf which borrows `bytes` of data.
+    pub fn add_borrowed(&mut self, bytes: usize) {
+        self.borrowed += 1;
+        self.borrowed_bytes = self.borrowed_bytes.saturating_add(bytes);
+        self.add_bytes(bytes);
+    }
+
+    /// Add a leaf which owns `bytes` of heap data.
+    pub fn add_owned(&mut self, bytes: usize) {
+        self.owned += 1;
+        self.owned_bytes = self.owned_bytes.saturating_add(bytes);
+        self.add_bytes(bytes);
+    }
+
+    /// Add `bytes` of heap data which is not held by a leaf, such as the buffer of a container.
+    pub fn add_bytes(&mut self, bytes: usize) {
+        self.bytes = self.bytes.saturating_add(bytes);
+    }
+
+    /// Add the size of `len` values of type `T` which are not held by a leaf, such as the buffer of a container.
+    pub fn add_bytes_for<T>(&mut self, len: usize) {
+        self.add_bytes(core::mem::size_of::<T>().saturating_mul(len));
+    }
+
+    /// The number of leaves which borrow data.
+    #[must_use]
+    pub const fn borrowed(&self) -> usize {
+        self.borrowed
+    }
+
+    /// The number of bytes borrowed by all leaves which borrow data.
+    ///
+    /// This is the number of bytes which [`into_static`](crate::IntoBoundedStatic::into_static) would copy.
+    #[must_use]
+    pub const fn borrowed_bytes(&self) -> usize {
+        self.borrowed_bytes
+    }
+
+    /// The number of leaves which own heap data.
+    #[must_use]
+    pub const fn owned(&self) -> usize {
+        self.owned
+    }
+
+    /// The number of bytes owned by all leaves which own heap data.
+    #[must_use]
+    pub const fn owned_bytes(&self) -> usize {
+        self.owned_bytes
+    }
+
+    /// The total number of heap bytes which [`to_static`](ToBoundedStatic::to_static) would allocate.
+    #[must_use]
+    pub const fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Does the value borrow any data?
+    ///
+    /// If not then [`into_static`](crate::IntoBoundedStatic::into_static) does not need to copy any data.
+    #[must_use]
+    pub const fn is_borrowed(&self) -> bool {
+        self.borrowed > 0
+    }
+}
+
+/// Estimate the cost of converting a value to a form that is bounded by `'static`.
+///
+/// This trait is implemented for every type which implements [`ToBoundedStatic`] and reports the cost as computed by
+/// [`add_static_cost`](ToBoundedStatic::add_static_cost).
+pub trait StaticCost {
+    /// Estimate the cost of converting this value with [`to_static`](ToBoundedStatic::to_static) without converting it.
+    #[must_use]
+    fn static_cost(&self) -> CostReport;
+}
+
+impl<T> StaticCost for T
+where
+    T: ToBoundedStatic + ?Sized,
+{
+    fn static_cost(&self) -> CostReport {
+        let mut report = CostReport::new();
+        self.add_static_cost(&mut report);
+        report
+    }
+}
@@ -0,0 +1,339 @@
+//! Error types for the fallible [`TryToBoundedStatic`](crate::TryToBoundedStatic) and
+//! [`TryIntoBoundedStatic`](crate::TryIntoBoundedStatic) traits.
+//!
+//! The `StaticError` type (which requires the `std` feature) is an owned copy of an error, and of its `source()` chain,
+//! which may be converted from an error which borrows data.
+
+use core::convert::Infallible;
+use core::fmt::{Display, Formatter};
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::TryReserveError, vec::Vec};
+
+#[cfg(feature = "std")]
+use alloc::{boxed::Box, format, string::String};
+
+/// The error returned when a value cannot be converted to a form that is bounded by `'static`.
+///
+/// A `TryStaticError` records the [`ErrorKind`] of the failure and, when the `alloc` feature is enabled, the path of
+/// [`PathSegment`] from the outermost value being converted to the value which failed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use bounded_static::error::{ErrorKind, PathSegment};
+/// # use bounded_static::TryStaticError;
+/// let err = TryStaticError::new(ErrorKind::Custom("invalid"))
+///     .at(PathSegment::Field("name"))
+///     .at(PathSegment::Index(2));
+/// assert_eq!(&ErrorKind::Custom("invalid"), err.kind());
+/// assert_eq!("invalid at [2].name", err.to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryStaticError {
+    kind: ErrorKind,
+    #[cfg(feature = "alloc")]
+    path: Vec<PathSegment>,
+}
+
+/// The kind of a [`TryStaticError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A lock was poisoned.
+    Poisoned,
+    /// A value was mutably borrowed and could not be read.
+    Borrowed,
+    /// A custom error.
+    Custom(&'static str),
+    /// The memory [`Budget`](crate::Budget) of a conversion was exceeded.
+    LimitExceeded,
+    /// The heap data of a value could not be measured to be charged to a limited [`Budget`](crate::Budget).
+    Unmeasurable,
+    /// A memory allocation failed.
+    #[cfg(feature = "alloc")]
+    Alloc(TryReserveError),
+}
+
+/// A segment of the path to the value which failed to convert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PathSegment {
+    /// The element at the given position of a sequence, set, array or tuple.
+    Index(usize),
+    /// The key of the entry at the given position of a map.
+    Key(usize),
+    /// The value of the entry at the given position of a map.
+    Value(usize),
+    /// A named field of a `struct` or `enum` variant.
+    Field(&'static str),
+    /// An `enum` variant.
+    Variant(&'static str),
+}
+
+impl TryStaticError {
+    /// Create a new `TryStaticError` of the given [`ErrorKind`] with an empty path.
+    #[must_use]
+    pub const fn new(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            #[cfg(feature = "alloc")]
+            path: Vec::new(),
+        }
+    }
+
+    /// Convert an error into a `TryStaticError` and record that it occurred within the given [`PathSegment`].
+    #[must_use]
+    pub fn wrap<E: Into<Self>>(err: E, segment: PathSegment) -> Self {
+        err.into().at(segment)
+    }
+
+    /// Record that this error occurred within the given [`PathSegment`].
+    ///
+    /// Segments are recorded from the innermost to the outermost value.  The path is not recorded if the `alloc`
+    /// feature is not enabled, and the segment is skipped if the path cannot be grown, such as when reporting an
+    /// allocation failure.
+    #[must_use]
+    #[allow(unused_mut, unused_variables)]
+    pub fn at(mut self, segment: PathSegment) -> Self {
+        #[cfg(feature = "alloc")]
+        if self.path.try_reserve(1).is_ok() {
+            self.path.push(segment);
+        }
+        self
+    }
+
+    /// The [`ErrorKind`] of this error.
+    #[must_use]
+    pub const fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    /// The path from the outermost value to the value which failed to convert.
+    #[cfg(feature = "alloc")]
+    pub fn path(&self) -> impl Iterator<Item = &PathSegment> + '_ {
+        self.path.iter().rev()
+    }
+}
+
+impl From<ErrorKind> for TryStaticError {
+    fn from(kind: ErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<TryReserveError> for TryStaticError {
+    fn from(err: TryReserveError) -> Self {
+        Self::new(ErrorKind::Alloc(err))
+    }
+}
+
+impl From<Infallible> for TryStaticError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl Display for TryStaticError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.kind)?;
+        #[cfg(feature = "alloc")]
+        if !self.path.is_empty() {
+            f.write_str(" at ")?;
+            for segment in self.path() {
+                write!(f, "{segment}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Poisoned => f.write_str("lock poisoned"),
+            Self::Borrowed => f.write_str("value mutably borrowed"),
+            Self::Custom(msg) => f.write_str(msg),
+            Self::LimitExceeded => f.write_str("memory budget exceeded"),
+            Self::Unmeasurable => f.write_str("heap size cannot be measured"),
+            #[cfg(feature = "alloc")]
+            Self::Alloc(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Display for PathSegment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Index(i) => write!(f, "[{i}]"),
+            Self::Key(i) => write!(f, "[key {i}]"),
+            Self::Value(i) => write!(f, "[value {i}]"),
+            Self::Field(name) => write!(f, ".{name}"),
+            Self::Variant(name) => write!(f, "::{name}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryStaticError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ErrorKind::Alloc(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// An owned error which captures the `Display` and `Debug` output and the `source()` chain of any error.
+///
+/// Errors which borrow their context, such as a `ParseError<'a>` which holds slices of its input, cannot be returned
+/// from a spawned thread or from `main`.  A `StaticError` is bounded by `'static` and is `Send + Sync`, and reproduces
+/// the messages of the error it was created from, and of each of its sources, when formatted.
+///
+/// The `Box<dyn Error + 'a>` impls of [`ToBoundedStatic`](crate::ToBoundedStatic) convert the boxed error to a
+/// `StaticError`, and the `anyhow` and `eyre` features provide impls for `anyhow::Error` and `eyre::Report`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use bounded_static::error::StaticError;
+/// #[derive(Debug)]
+/// struct ParseError<'a> {
+///     input: &'a str,
+/// }
+///
+/// impl std::fmt::Display for ParseError<'_> {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "invalid input: {}", self.input)
+///     }
+/// }
+///
+/// impl Error for ParseError<'_> {}
+///
+/// let input = String::from("[1, 2");
+/// let err = StaticError::new(&ParseError { input: &input });
+/// drop(input);
+/// assert_eq!("invalid input: [1, 2", err.to_string());
+/// assert_eq!(r#"ParseError { input: "[1, 2" }"#, format!("{err:?}"));
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct StaticError {
+    display: String,
+    debug: String,
+    source: Option<Box<Self>>,
+}
+
+#[cfg(feature = "std")]
+impl StaticError {
+    /// Capture the `Display` and `Debug` output and the `source()` chain of an error.
+    #[must_use]
+    pub fn new<E>(err: &E) -> Self
+    where
+        E: std::error::Error + ?Sized,
+    {
+        Self {
+            display: format!("{err}"),
+            debug: format!("{err:?}"),
+            source: err.source().map(|source| Box::new(Self::new(source))),
+        }
+    }
+
+    /// The captured `Display` output of the error.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.display
+    }
+
+    /// The captured `Debug` output of the error.
+    #[must_use]
+    pub fn debug_message(&self) -> &str {
+        &self.debug
+    }
+
+    /// The captured sources of the error, starting with this error and ending with the innermost source.
+    pub fn chain(&self) -> impl Iterator<Item = &Self> + '_ {
+        core::iter::successors(Some(self), |err| err.source.as_deref())
+    }
+
+    /// The number of heap bytes held by the captured output of the error and of each of its sources.
+    pub(crate) fn heap_bytes(&self) -> usize {
+        let bytes = self.display.len().saturating_add(self.debug.len());
+        self.source.as_ref().map_or(bytes, |source| {
+            bytes
+                .saturating_add(core::mem::size_of::<Self>())
+                .saturating_add(source.heap_bytes())
+        })
+    }
+
+    /// The number of heap bytes needed to capture an error in a box, which is a boxed `StaticError` and the length of
+    /// the `Display` and `Debug` output for the error and for each of its sources.
+    ///
+    /// The output is counted without allocating, and so the error is formatted twice if it is then captured.
+    pub(crate) fn captured_bytes<E>(err: &E) -> usize
+    where
+        E: std::error::Error + ?Sized,
+    {
+        let bytes = core::mem::size_of::<Self>()
+            .saturating_add(formatted_len(format_args!("{err}")))
+            .saturating_add(formatted_len(format_args!("{err:?}")));
+        err.source().map_or(bytes, |source| {
+            bytes.saturating_add(Self::captured_bytes(source))
+        })
+    }
+}
+
+/// The length of formatted output, which is counted without allocating.
+#[cfg(feature = "std")]
+fn formatted_len(args: core::fmt::Arguments<'_>) -> usize {
+    struct Counter(usize);
+
+    impl core::fmt::Write for Counter {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            self.0 = self.0.saturating_add(s.len());
+            Ok(())
+        }
+    }
+
+    let mut counter = Counter(0);
+    let _ = core::fmt::write(&mut counter, args);
+    counter.0
+}
+
+#[cfg(feature = "std")]
+impl Display for StaticError {
+    /// Writes the captured `Display` output of the error, or with the alternate flag (`{:#}`) the output of the error
+    /// and of each of its sources separated by `": "`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            for (i, err) in self.chain().enumerate() {
+                if i > 0 {
+                    f.write_str(": ")?;
+                }
+                f.write_str(&err.display)?;
+            }
+            Ok(())
+        } else {
+            f.write_str(&self.display)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Debug for StaticError {
+    /// Writes the captured `Debug` output of the error.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.debug)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StaticError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn std::error::Error + 'static))
+    }
+}
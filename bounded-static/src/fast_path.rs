@@ -0,0 +1,842 @@
+//! Fast paths for converting collections of values whose conversion is a copy, a clone or a no-op.
+//!
+//! Without specialization the impl for a collection such as `Vec<T>` cannot know that converting a `T` is a no-op,
+//! even when `T::Static` is `T`, and so would have to convert every element and collect them into a new allocation.
+//!
+//! Instead the collection passes a [`ToStaticFastPath`] or [`IntoStaticFastPath`] to the hidden
+//! `to_static_fast_path` and `into_static_fast_path` methods of the element type.  By default these methods take the
+//! general path but the impls for types whose conversion is a copy, a clone or a no-op override them to take the
+//! matching fast path, in which `T::Static` is known to be `T` and so the collection may be copied, cloned or returned
+//! unchanged.  Collections with several type parameters, such as `HashMap<K, V, S>`, dispatch on each in turn.
+//!
+//! The hidden methods only hand the conversion back to the matching method of these traits, and all the work of each
+//! path is done here.  They cannot be moved off the public traits, as a collection impl only knows its element type
+//! through the bounds of the impl, and without specialization it cannot otherwise find out how the element converts.
+//! The paths are measured by the `fast_path` benchmark of this crate.
+//!
+//! This is not part of the public API.
+
+use crate::{IntoBoundedStatic, ToBoundedStatic};
+
+#[cfg(feature = "alloc")]
+use crate::record_alloc_for;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
+#[cfg(feature = "collections")]
+use alloc::collections::{BinaryHeap, VecDeque};
+
+/// A conversion to `'static` which may take a fast path depending on how values of type `T` are converted.
+pub trait ToStaticFastPath<T: ToBoundedStatic + ?Sized> {
+    /// The result of the conversion.
+    type Output;
+
+    /// Perform the conversion for any `T`.
+    fn convert(self) -> Self::Output;
+
+    /// Perform the conversion where `T::Static` is `T` and `to_static()` is a clone.
+    fn convert_clone(self) -> Self::Output
+    where
+        Self: Sized,
+        T: ToBoundedStatic<Static = T> + Clone,
+    {
+        self.convert()
+    }
+
+    /// Perform the conversion where `T::Static` is `T` and `to_static()` is a copy.
+    fn convert_copy(self) -> Self::Output
+    where
+        Self: Sized,
+        T: ToBoundedStatic<Static = T> + Copy,
+    {
+        self.convert_clone()
+    }
+
+    /// Perform the conversion where `T::Static` is `T` with its lifetimes replaced by `'static`.
+    #[cfg(feature = "hashbrown")]
+    fn convert_rebind(self) -> Self::Output
+    where
+        Self: Sized,
+        T: RebindStatic,
+    {
+        self.convert()
+    }
+}
+
+/// A conversion into `'static` which may take a fast path depending on how values of type `T` are converted.
+pub trait IntoStaticFastPath<T: IntoBoundedStatic + ?Sized> {
+    /// The result of the conversion.
+    type Output;
+
+    /// Perform the conversion for any `T`.
+    fn convert(self) -> Self::Output;
+
+    /// Perform the conversion where `T::Static` is `T` and `into_static()` is a no-op.
+    fn convert_identity(self) -> Self::Output
+    where
+        Self: Sized,
+        T: IntoBoundedStatic<Static = T>;
+
+    /// Perform the conversion where `T::Static` is `T` with its lifetimes replaced by `'static`.
+    #[cfg(feature = "hashbrown")]
+    fn convert_rebind(self) -> Self::Output
+    where
+        Self: Sized,
+        T: RebindStatic,
+    {
+        self.convert()
+    }
+}
+
+/// A type whose conversion to `'static` only replaces its lifetimes with `'static`.
+///
+/// The hashbrown map and set fast paths use this to convert the keys of a table in their buckets, without rehashing.
+///
+/// # Safety
+///
+/// `Self::Static` must be `Self` with its lifetimes replaced by `'static`, so that both have the same layout and drop
+/// glue, and a value and its conversion must hash and compare equal.
+#[cfg(feature = "hashbrown")]
+#[allow(unsafe_code)]
+pub unsafe trait RebindStatic:
+    Clone + ToBoundedStatic + IntoBoundedStatic<Static = <Self as ToBoundedStatic>::Static>
+{
+    /// Convert a clone of a value to `'static` as `to_static()` converts the value.
+    fn clone_to_static(self) -> <Self as ToBoundedStatic>::Static;
+}
+
+/// Convert a slice of `T` to a `Vec`.
+#[cfg(feature = "alloc")]
+pub struct SliceToStatic<'a, T>(pub &'a [T]);
+
+#[cfg(feature = "alloc")]
+impl<T> ToStaticFastPath<T> for SliceToStatic<'_, T>
+where
+    T: ToBoundedStatic,
+{
+    type Output = Vec<T::Static>;
+
+    fn convert(self) -> Self::Output {
+        record_alloc_for::<T::Static>(self.0.len());
+        self.0.iter().map(ToBoundedStatic::to_static).collect()
+    }
+
+    fn convert_copy(self) -> Self::Output
+    where
+        T: ToBoundedStatic<Static = T> + Copy,
+    {
+        record_alloc_for::<T>(self.0.len());
+        self.0.to_vec()
+    }
+}
+
+/// Convert a `Vec<T>`, reusing its allocation if `into_static()` is a no-op for `T`.
+#[cfg(feature = "alloc")]
+pub struct VecIntoStatic<T>(pub Vec<T>);
+
+#[cfg(feature = "alloc")]
+impl<T> IntoStaticFastPath<T> for VecIntoStatic<T>
+where
+    T: IntoBoundedStatic,
+{
+    type Output = Vec<T::Static>;
+
+    fn convert(self) -> Self::Output {
+        record_alloc_for::<T::Static>(self.0.len());
+        self.0
+            .into_iter()
+            .map(IntoBoundedStatic::into_static)
+            .collect()
+    }
+
+    fn convert_identity(self) -> Self::Output
+    where
+        T: IntoBoundedStatic<Static = T>,
+    {
+        self.0
+    }
+}
+
+/// Convert a `Box<T>`, reusing its allocation if `into_static()` is a no-op for `T`.
+#[cfg(feature = "alloc")]
+pub struct BoxIntoStatic<T>(pub Box<T>);
+
+#[cfg(feature = "alloc")]
+impl<T> IntoStaticFastPath<T> for BoxIntoStatic<T>
+where
+    T: IntoBoundedStatic,
+{
+    type Output = Box<T::Static>;
+
+    fn convert(self) -> Self::Output {
+        record_alloc_for::<T::Static>(1);
+        Box::new((*self.0).into_static())
+    }
+
+    fn convert_identity(self) -> Self::Output
+    where
+        T: IntoBoundedStatic<Static = T>,
+    {
+        self.0
+    }
+}
+
+/// Convert a `VecDeque<T>`, reusing its allocation if `into_static()` is a no-op for `T`.
+#[cfg(feature = "collections")]
+pub struct VecDequeIntoStatic<T>(pub VecDeque<T>);
+
+#[cfg(feature = "collections")]
+impl<T> IntoStaticFastPath<T> for VecDequeIntoStatic<T>
+where
+    T: IntoBoundedStatic,
+{
+    type Output = VecDeque<T::Static>;
+
+    fn convert(self) -> Self::Output {
+        record_alloc_for::<T::Static>(self.0.len());
+        self.0
+            .into_iter()
+            .map(IntoBoundedStatic::into_static)
+            .collect()
+    }
+
+    fn convert_identity(self) -> Self::Output
+    where
+        T: IntoBoundedStatic<Static = T>,
+    {
+        self.0
+    }
+}
+
+/// Convert a `BinaryHeap<T>`, reusing its allocation if `into_static()` is a no-op for `T`.
+#[cfg(feature = "collections")]
+pub struct BinaryHeapIntoStatic<T>(pub BinaryHeap<T>);
+
+#[cfg(feature = "collections")]
+impl<T> IntoStaticFastPath<T> for BinaryHeapIntoStatic<T>
+where
+    T: IntoBoundedStatic,
+    T::Static: Ord,
+{
+    type Output = BinaryHeap<T::Static>;
+
+    fn convert(self) -> Self::Output {
+        record_alloc_for::<T::Static>(self.0.len());
+        self.0
+            .into_iter()
+            .map(IntoBoundedStatic::into_static)
+            .collect()
+    }
+
+    fn convert_identity(self) -> Self::Output
+    where
+        T: IntoBoundedStatic<Static = T>,
+    {
+        self.0
+    }
+}
+
+/// Record the heap data copied by cloning a value whose `to_static()` is a clone with the `instrument` collector, if
+/// enabled.
+///
+/// The value is not converted and so its allocation, if any, is recorded from its
+/// [`static_cost`](crate::StaticCost::static_cost).
+#[cfg(feature = "std")]
+fn record_clone<T: ToBoundedStatic + ?Sized>(value: &T) {
+    #[cfg(feature = "instrument")]
+    crate::record_alloc(crate::StaticCost::static_cost(value).bytes());
+    let _ = value;
+}
+
+/// Generate the fast paths for a hash map and hash set type.
+///
+/// The map `to_static()` fast path clones the map, which copies the table without rehashing, if `to_static()` is a
+/// copy or a clone for the keys, values and hasher.  The map `into_static()` fast path returns the map
+/// unchanged if `into_static()` is a no-op for the keys and values and `to_static()` is a clone for the hasher.  The
+/// same applies to the set fast paths.
+///
+/// With `rebind` the keys of a map and the values of a set may also be [`RebindStatic`], for which the paths are
+/// implemented alongside the macro, as the map must provide the raw table API to convert them in their buckets.
+#[cfg(feature = "std")]
+macro_rules! hash_fast_path {
+    (@rebind $key:ident, $param:ident::$method:ident($path:ident)) => {
+        fn convert_rebind(self) -> Self::Output
+        where
+            $key: super::RebindStatic,
+        {
+            $param::$method($path(self.0))
+        }
+    };
+    ($map:ident, $set:ident $(, $rebind:ident)?) => {
+        use super::{record_clone, IntoStaticFastPath, ToStaticFastPath};
+        use crate::{record_alloc_for, IntoBoundedStatic, ToBoundedStatic};
+        use std::hash::{BuildHasher, Hash};
+
+        /// Convert a map to `'static` by converting and rehashing every entry.
+        fn map_to_static<K, V, S>(map: &$map<K, V, S>) -> $map<K::Static, V::Static, S::Static>
+        where
+            K: ToBoundedStatic,
+            K::Static: Eq + Hash,
+            V: ToBoundedStatic,
+            S: ToBoundedStatic,
+            S::Static: BuildHasher,
+        {
+            record_alloc_for::<(K::Static, V::Static)>(map.len());
+            let mut static_map =
+                $map::with_capacity_and_hasher(map.len(), map.hasher().to_static());
+            static_map.extend(map.iter().map(|(k, v)| (k.to_static(), v.to_static())));
+            static_map
+        }
+
+        /// Convert a map into `'static` by converting and rehashing every entry.
+        fn map_into_static<K, V, S>(map: $map<K, V, S>) -> $map<K::Static, V::Static, S::Static>
+        where
+            K: IntoBoundedStatic,
+            K::Static: Eq + Hash,
+            V: IntoBoundedStatic,
+            S: ToBoundedStatic,
+            S::Static: BuildHasher,
+        {
+            record_alloc_for::<(K::Static, V::Static)>(map.len());
+            let mut static_map =
+                $map::with_capacity_and_hasher(map.len(), map.hasher().to_static());
+            static_map.extend(
+                map.into_iter()
+                    .map(|(k, v)| (k.into_static(), v.into_static())),
+            );
+            static_map
+        }
+
+        /// Convert a set to `'static` by converting and rehashing every value.
+        fn set_to_static<T, S>(set: &$set<T, S>) -> $set<T::Static, S::Static>
+        where
+            T: ToBoundedStatic,
+            T::Static: Eq + Hash,
+            S: ToBoundedStatic,
+            S::Static: BuildHasher,
+        {
+            record_alloc_for::<T::Static>(set.len());
+            let mut static_set =
+                $set::with_capacity_and_hasher(set.len(), set.hasher().to_static());
+            static_set.extend(set.iter().map(ToBoundedStatic::to_static));
+            static_set
+        }
+
+        /// Convert a set into `'static` by converting and rehashing every value.
+        fn set_into_static<T, S>(set: $set<T, S>) -> $set<T::Static, S::Static>
+        where
+            T: IntoBoundedStatic,
+            T::Static: Eq + Hash,
+            S: ToBoundedStatic,
+            S::Static: BuildHasher,
+        {
+            record_alloc_for::<T::Static>(set.len());
+            let mut static_set =
+                $set::with_capacity_and_hasher(set.len(), set.hasher().to_static());
+            static_set.extend(set.into_iter().map(IntoBoundedStatic::into_static));
+            static_set
+        }
+
+        /// Convert a map to `'static`, dispatching on the key type.
+        pub struct MapToStatic<'a, K, V, S>(pub &'a $map<K, V, S>);
+
+        impl<K, V, S> ToStaticFastPath<K> for MapToStatic<'_, K, V, S>
+        where
+            K: ToBoundedStatic,
+            K::Static: Eq + Hash,
+            V: ToBoundedStatic,
+            S: ToBoundedStatic,
+            S::Static: BuildHasher,
+        {
+            type Output = $map<K::Static, V::Static, S::Static>;
+
+            fn convert(self) -> Self::Output {
+                map_to_static(self.0)
+            }
+
+            fn convert_clone(self) -> Self::Output
+            where
+                K: ToBoundedStatic<Static = K> + Clone,
+            {
+                V::to_static_fast_path(MapValuesToStatic(self.0))
+            }
+
+            $(hash_fast_path!(@$rebind K, V::to_static_fast_path(MapRebindValuesToStatic));)?
+        }
+
+        /// Convert a map whose keys are cloned to `'static`, dispatching on the value type.
+        struct MapValuesToStatic<'a, K, V, S>(&'a $map<K, V, S>);
+
+        impl<K, V, S> ToStaticFastPath<V> for MapValuesToStatic<'_, K, V, S>
+        where
+            K: ToBoundedStatic<Static = K> + Clone + Eq + Hash,
+            V: ToBoundedStatic,
+            S: ToBoundedStatic,
+            S::Static: BuildHasher,
+        {
+            type Output = $map<K, V::Static, S::Static>;
+
+            fn convert(self) -> Self::Output {
+                map_to_static(self.0)
+            }
+
+            fn convert_clone(self) -> Self::Output
+            where
+                V: ToBoundedStatic<Static = V> + Clone,
+            {
+                S::to_static_fast_path(MapHasherToStatic(self.0))
+            }
+        }
+
+        /// Convert a map whose keys and values are cloned to `'static`, dispatching on the hasher type.
+        struct MapHasherToStatic<'a, K, V, S>(&'a $map<K, V, S>);
+
+        impl<K, V, S> ToStaticFastPath<S> for MapHasherToStatic<'_, K, V, S>
+        where
+            K: ToBoundedStatic<Static = K> + Clone + Eq + Hash,
+            V: ToBoundedStatic<Static = V> + Clone,
+            S: ToBoundedStatic,
+            S::Static: BuildHasher,
+        {
+            type Output = $map<K, V, S::Static>;
+
+            fn convert(self) -> Self::Output {
+                map_to_static(self.0)
+            }
+
+            fn convert_clone(self) -> Self::Output
+            where
+                S: ToBoundedStatic<Static = S> + Clone,
+            {
+                record_alloc_for::<(K, V)>(self.0.len());
+                for (k, v) in self.0 {
+                    record_clone(k);
+                    record_clone(v);
+                }
+                self.0.clone()
+            }
+        }
+
+        /// Convert a map into `'static`, dispatching on the key type.
+        pub struct MapIntoStatic<K, V, S>(pub $map<K, V, S>);
+
+        impl<K, V, S> IntoStaticFastPath<K> for MapIntoStatic<K, V, S>
+        where
+            K: IntoBoundedStatic,
+            K::Static: Eq + Hash,
+            V: IntoBoundedStatic,
+            S: ToBoundedStatic,
+            S::Static: BuildHasher,
+        {
+            type Output = $map<K::Static, V::Static, S::Static>;
+
+            fn convert(self) -> Self::Output {
+                map_into_static(self.0)
+            }
+
+            fn convert_identity(self) -> Self::Output
+            where
+                K: IntoBoundedStatic<Static = K>,
+            {
+                V::into_static_fast_path(MapValuesIntoStatic(self.0))
+            }
+
+            $(hash_fast_path!(@$rebind K, V::into_static_fast_path(MapRebindValuesIntoStatic));)?
+        }
+
+        /// Convert a map whose keys are unchanged into `'static`, dispatching on the value type.
+        struct MapValuesIntoStatic<K, V, S>($map<K, V, S>);
+
+        impl<K, V, S> IntoStaticFastPath<V> for MapValuesIntoStatic<K, V, S>
+        where
+            K: IntoBoundedStatic<Static = K> + Eq + Hash,
+            V: IntoBoundedStatic,
+            S: ToBoundedStatic,
+            S::Static: BuildHasher,
+        {
+            type Output = $map<K, V::Static, S::Static>;
+
+            fn convert(self) -> Self::Output {
+                map_into_static(self.0)
+            }
+
+            fn convert_identity(self) -> Self::Output
+            where
+                V: IntoBoundedStatic<Static = V>,
+            {
+                S::to_static_fast_path(MapHasherIntoStatic(self.0))
+            }
+        }
+
+        /// Convert a map whose keys and values are unchanged into `'static`, dispatching on the hasher type.
+        struct MapHasherIntoStatic<K, V, S>($map<K, V, S>);
+
+        impl<K, V, S> ToStaticFastPath<S> for MapHasherIntoStatic<K, V, S>
+        where
+            K: IntoBoundedStatic<Static = K> + Eq + Hash,
+            V: IntoBoundedStatic<Static = V>,
+            S: ToBoundedStatic,
+            S::Static: BuildHasher,
+        {
+            type Output = $map<K, V, S::Static>;
+
+            fn convert(self) -> Self::Output {
+                map_into_static(self.0)
+            }
+
+            fn convert_clone(self) -> Self::Output
+            where
+                S: ToBoundedStatic<Static = S> + Clone,
+            {
+                self.0
+            }
+        }
+
+        /// Convert a set to `'static`, dispatching on the value type.
+        pub struct SetToStatic<'a, T, S>(pub &'a $set<T, S>);
+
+        impl<T, S> ToStaticFastPath<T> for SetToStatic<'_, T, S>
+        where
+            T: ToBoundedStatic,
+            T::Static: Eq + Hash,
+            S: ToBoundedStatic,
+            S::Static: BuildHasher,
+        {
+            type Output = $set<T::Static, S::Static>;
+
+            fn convert(self) -> Self::Output {
+                set_to_static(self.0)
+            }
+
+            fn convert_clone(self) -> Self::Output
+            where
+                T: ToBoundedStatic<Static = T> + Clone,
+            {
+                S::to_static_fast_path(SetHasherToStatic(self.0))
+            }
+
+            $(hash_fast_path!(@$rebind T, S::to_static_fast_path(SetRebindHasherToStatic));)?
+        }
+
+        /// Convert a set whose values are cloned to `'static`, dispatching on the hasher type.
+        struct SetHasherToStatic<'a, T, S>(&'a $set<T, S>);
+
+        impl<T, S> ToStaticFastPath<S> for SetHasherToStatic<'_, T, S>
+        where
+            T: ToBoundedStatic<Static = T> + Clone + Eq + Hash,
+            S: ToBoundedStatic,
+            S::Static: BuildHasher,
+        {
+            type Output = $set<T, S::Static>;
+
+            fn convert(self) -> Self::Output {
+                set_to_static(self.0)
+            }
+
+            fn convert_clone(self) -> Self::Output
+            where
+                S: ToBoundedStatic<Static = S> + Clone,
+            {
+                record_alloc_for::<T>(self.0.len());
+                self.0.iter().for_each(record_clone);
+                self.0.clone()
+            }
+        }
+
+        /// Convert a set into `'static`, dispatching on the value type.
+        pub struct SetIntoStatic<T, S>(pub $set<T, S>);
+
+        impl<T, S> IntoStaticFastPath<T> for SetIntoStatic<T, S>
+        where
+            T: IntoBoundedStatic,
+            T::Static: Eq + Hash,
+            S: ToBoundedStatic,
+            S::Static: BuildHasher,
+        {
+            type Output = $set<T::Static, S::Static>;
+
+            fn convert(self) -> Self::Output {
+                set_into_static(self.0)
+            }
+
+            fn convert_identity(self) -> Self::Output
+            where
+                T: IntoBoundedStatic<Static = T>,
+            {
+                S::to_static_fast_path(SetHasherIntoStatic(self.0))
+            }
+
+            $(hash_fast_path!(@$rebind T, S::to_static_fast_path(SetRebindHasherIntoStatic));)?
+        }
+
+        /// Convert a set whose values are unchanged into `'static`, dispatching on the hasher type.
+        struct SetHasherIntoStatic<T, S>($set<T, S>);
+
+        impl<T, S> ToStaticFastPath<S> for SetHasherIntoStatic<T, S>
+        where
+            T: IntoBoundedStatic<Static = T> + Eq + Hash,
+            S: ToBoundedStatic,
+            S::Static: BuildHasher,
+        {
+            type Output = $set<T, S::Static>;
+
+            fn convert(self) -> Self::Output {
+                set_into_static(self.0)
+            }
+
+            fn convert_clone(self) -> Self::Output
+            where
+                S: ToBoundedStatic<Static = S> + Clone,
+            {
+                self.0
+            }
+        }
+    };
+}
+
+/// Fast paths for `std::collections::HashMap` and `std::collections::HashSet`.
+#[cfg(feature = "std")]
+pub mod std_hash {
+    use std::collections::{HashMap, HashSet};
+
+    hash_fast_path!(HashMap, HashSet);
+}
+
+/// Fast paths for `ahash::AHashMap` and `ahash::AHashSet`.
+#[cfg(all(feature = "ahash", feature = "std"))]
+pub mod ahash_hash {
+    use ahash::{AHashMap, AHashSet};
+
+    hash_fast_path!(AHashMap, AHashSet);
+}
+
+/// Fast paths for `hashbrown::HashMap` and `hashbrown::HashSet`.
+///
+/// Besides the fast paths of the other maps and sets, keys and values which are [`RebindStatic`], such as `Cow<'a,
+/// str>`, are converted without rehashing if the conversion of the values and the hasher is a clone.  The table is
+/// cloned, or reused when converted into `'static`, and each key is then converted in its bucket, which keeps the
+/// control byte and so the position of the key in the table.  As the key and its conversion hash the same way the
+/// table then holds a valid map of the converted keys.
+#[cfg(feature = "hashbrown")]
+#[allow(unsafe_code)]
+pub mod hashbrown_hash {
+    use super::RebindStatic;
+    use core::mem::ManuallyDrop;
+    use hashbrown::raw::RawTable;
+    use hashbrown::{HashMap, HashSet};
+
+    hash_fast_path!(HashMap, HashSet, rebind);
+
+    /// Reinterpret a value of type `T` as a value of type `U`.
+    ///
+    /// # Safety
+    ///
+    /// `U` must be `T` with its lifetimes replaced.
+    unsafe fn rebind_unchecked<T, U>(value: T) -> U {
+        let value = ManuallyDrop::new(value);
+        // SAFETY: `T` and `U` differ only in their lifetimes and so have the same layout, and `value` is not dropped.
+        unsafe { core::ptr::read(core::ptr::addr_of!(*value).cast::<U>()) }
+    }
+
+    /// Convert the key of every entry of a table in its bucket.
+    ///
+    /// The converted keys are stored as `K`, which the caller must reinterpret as `K::Static` before they are used.
+    fn rebind_table<K, V>(
+        table: &mut RawTable<(K, V)>,
+        mut convert: impl FnMut(K) -> <K as ToBoundedStatic>::Static,
+    ) where
+        K: RebindStatic,
+    {
+        for index in 0..table.buckets() {
+            // SAFETY: `index` is less than the number of buckets, and the bucket is only replaced if it is full.  A key
+            // which hashes and compares equal is put back in the same bucket, and `K::Static` is `K` with its lifetimes
+            // replaced.  If `convert` panics the entry is dropped and the table remains valid.
+            unsafe {
+                if table.is_bucket_full(index) {
+                    let bucket = table.bucket(index);
+                    table.replace_bucket_with(bucket, |(key, value)| {
+                        Some((rebind_unchecked(convert(key)), value))
+                    });
+                }
+            }
+        }
+    }
+
+    /// Convert the keys of a map, which has already been cloned or is owned, without rehashing.
+    fn rebind_map<K, V, S>(
+        mut map: HashMap<K, V, S>,
+        convert: impl FnMut(K) -> <K as ToBoundedStatic>::Static,
+    ) -> HashMap<<K as ToBoundedStatic>::Static, V, S>
+    where
+        K: RebindStatic,
+    {
+        rebind_table(map.raw_table_mut(), convert);
+        // SAFETY: every key has been converted to `K::Static`, which is `K` with its lifetimes replaced.
+        unsafe { rebind_unchecked(map) }
+    }
+
+    /// Convert the values of a set, which has already been cloned or is owned, without rehashing.
+    fn rebind_set<T, S>(
+        mut set: HashSet<T, S>,
+        convert: impl FnMut(T) -> <T as ToBoundedStatic>::Static,
+    ) -> HashSet<<T as ToBoundedStatic>::Static, S>
+    where
+        T: RebindStatic,
+    {
+        rebind_table(set.raw_table_mut(), convert);
+        // SAFETY: every value has been converted to `T::Static`, which is `T` with its lifetimes replaced.
+        unsafe { rebind_unchecked(set) }
+    }
+
+    /// Convert a map whose keys are rebound to `'static`, dispatching on the value type.
+    struct MapRebindValuesToStatic<'a, K, V, S>(&'a HashMap<K, V, S>);
+
+    impl<K, V, S> ToStaticFastPath<V> for MapRebindValuesToStatic<'_, K, V, S>
+    where
+        K: RebindStatic,
+        <K as ToBoundedStatic>::Static: Eq + Hash,
+        V: ToBoundedStatic,
+        S: ToBoundedStatic,
+        S::Static: BuildHasher,
+    {
+        type Output = HashMap<<K as ToBoundedStatic>::Static, V::Static, S::Static>;
+
+        fn convert(self) -> Self::Output {
+            map_to_static(self.0)
+        }
+
+        fn convert_clone(self) -> Self::Output
+        where
+            V: ToBoundedStatic<Static = V> + Clone,
+        {
+            S::to_static_fast_path(MapRebindHasherToStatic(self.0))
+        }
+    }
+
+    /// Convert a map whose keys are rebound and values are cloned to `'static`, dispatching on the hasher type.
+    struct MapRebindHasherToStatic<'a, K, V, S>(&'a HashMap<K, V, S>);
+
+    impl<K, V, S> ToStaticFastPath<S> for MapRebindHasherToStatic<'_, K, V, S>
+    where
+        K: RebindStatic,
+        <K as ToBoundedStatic>::Static: Eq + Hash,
+        V: ToBoundedStatic<Static = V> + Clone,
+        S: ToBoundedStatic,
+        S::Static: BuildHasher,
+    {
+        type Output = HashMap<<K as ToBoundedStatic>::Static, V, S::Static>;
+
+        fn convert(self) -> Self::Output {
+            map_to_static(self.0)
+        }
+
+        fn convert_clone(self) -> Self::Output
+        where
+            S: ToBoundedStatic<Static = S> + Clone,
+        {
+            record_alloc_for::<(<K as ToBoundedStatic>::Static, V)>(self.0.len());
+            self.0.values().for_each(record_clone);
+            rebind_map(self.0.clone(), RebindStatic::clone_to_static)
+        }
+    }
+
+    /// Convert a map whose keys are rebound into `'static`, dispatching on the value type.
+    struct MapRebindValuesIntoStatic<K, V, S>(HashMap<K, V, S>);
+
+    impl<K, V, S> IntoStaticFastPath<V> for MapRebindValuesIntoStatic<K, V, S>
+    where
+        K: RebindStatic,
+        <K as ToBoundedStatic>::Static: Eq + Hash,
+        V: IntoBoundedStatic,
+        S: ToBoundedStatic,
+        S::Static: BuildHasher,
+    {
+        type Output = HashMap<<K as ToBoundedStatic>::Static, V::Static, S::Static>;
+
+        fn convert(self) -> Self::Output {
+            map_into_static(self.0)
+        }
+
+        fn convert_identity(self) -> Self::Output
+        where
+            V: IntoBoundedStatic<Static = V>,
+        {
+            S::to_static_fast_path(MapRebindHasherIntoStatic(self.0))
+        }
+    }
+
+    /// Convert a map whose keys are rebound and values are unchanged into `'static`, dispatching on the hasher type.
+    struct MapRebindHasherIntoStatic<K, V, S>(HashMap<K, V, S>);
+
+    impl<K, V, S> ToStaticFastPath<S> for MapRebindHasherIntoStatic<K, V, S>
+    where
+        K: RebindStatic,
+        <K as ToBoundedStatic>::Static: Eq + Hash,
+        V: IntoBoundedStatic<Static = V>,
+        S: ToBoundedStatic,
+        S::Static: BuildHasher,
+    {
+        type Output = HashMap<<K as ToBoundedStatic>::Static, V, S::Static>;
+
+        fn convert(self) -> Self::Output {
+            map_into_static(self.0)
+        }
+
+        fn convert_clone(self) -> Self::Output
+        where
+            S: ToBoundedStatic<Static = S> + Clone,
+        {
+            rebind_map(self.0, IntoBoundedStatic::into_static)
+        }
+    }
+
+    /// Convert a set whose values are rebound to `'static`, dispatching on the hasher type.
+    struct SetRebindHasherToStatic<'a, T, S>(&'a HashSet<T, S>);
+
+    impl<T, S> ToStaticFastPath<S> for SetRebindHasherToStatic<'_, T, S>
+    where
+        T: RebindStatic,
+        <T as ToBoundedStatic>::Static: Eq + Hash,
+        S: ToBoundedStatic,
+        S::Static: BuildHasher,
+    {
+        type Output = HashSet<<T as ToBoundedStatic>::Static, S::Static>;
+
+        fn convert(self) -> Self::Output {
+            set_to_static(self.0)
+        }
+
+        fn convert_clone(self) -> Self::Output
+        where
+            S: ToBoundedStatic<Static = S> + Clone,
+        {
+            record_alloc_for::<<T as ToBoundedStatic>::Static>(self.0.len());
+            rebind_set(self.0.clone(), RebindStatic::clone_to_static)
+        }
+    }
+
+    /// Convert a set whose values are rebound into `'static`, dispatching on the hasher type.
+    struct SetRebindHasherIntoStatic<T, S>(HashSet<T, S>);
+
+    impl<T, S> ToStaticFastPath<S> for SetRebindHasherIntoStatic<T, S>
+    where
+        T: RebindStatic,
+        <T as ToBoundedStatic>::Static: Eq + Hash,
+        S: ToBoundedStatic,
+        S::Static: BuildHasher,
+    {
+        type Output = HashSet<<T as ToBoundedStatic>::Static, S::Static>;
+
+        fn convert(self) -> Self::Output {
+            set_into_static(self.0)
+        }
+
+        fn convert_clone(self) -> Self::Output
+        where
+            S: ToBoundedStatic<Static = S> + Clone,
+        {
+            rebind_set(self.0, IntoBoundedStatic::into_static)
+        }
+    }
+}
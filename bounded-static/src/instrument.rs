@@ -0,0 +1,210 @@
+//! Runtime instrumentation of conversions.
+//!
+//! When the `instrument` feature is enabled the conversions provided by this crate record the number of allocations
+//! they perform, the number of bytes they copy and the number of `Cow::Owned` values they reuse in a thread-local
+//! collector.  Conversions of types which derive [`ToStatic`](crate::ToStatic) are also recorded against the name of
+//! the type, such that the allocations performed whilst converting the fields of a derived type are attributed to the
+//! innermost derived type being converted.
+//!
+//! The thread-local [`Report`] may be read with [`snapshot`] or [`take`], or the conversions performed by a closure
+//! may be collected with [`collect`].
+//!
+//! Note that only the infallible and budgeted conversions are instrumented.  The number of allocations performed by
+//! node based collections, such as `BTreeMap` and `LinkedList`, is approximated as a single allocation.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use bounded_static::{instrument, IntoBoundedStatic};
+//! let input = String::from("borrowed");
+//! let value = vec![Cow::from(&input), Cow::Owned(String::from("owned"))];
+//! let (_, report) = instrument::collect(|| value.into_static());
+//! assert_eq!(2, report.total().allocations());
+//! assert_eq!(1, report.total().reused());
+//! ```
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::vec::Vec;
+
+/// The counters recorded for a set of conversions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Counters {
+    conversions: u64,
+    allocations: u64,
+    bytes: u64,
+    reused: u64,
+}
+
+impl Counters {
+    /// The number of conversions of derived types.
+    #[must_use]
+    pub const fn conversions(&self) -> u64 {
+        self.conversions
+    }
+
+    /// The number of heap allocations.
+    #[must_use]
+    pub const fn allocations(&self) -> u64 {
+        self.allocations
+    }
+
+    /// The number of bytes copied into heap allocations.
+    #[must_use]
+    pub const fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// The number of `Cow::Owned` values which were reused rather than copied.
+    #[must_use]
+    pub const fn reused(&self) -> u64 {
+        self.reused
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.conversions += other.conversions;
+        self.allocations += other.allocations;
+        self.bytes += other.bytes;
+        self.reused += other.reused;
+    }
+}
+
+/// A report of the conversions recorded on a thread.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Report {
+    total: Counters,
+    types: BTreeMap<&'static str, Counters>,
+}
+
+impl Report {
+    /// The counters for all conversions.
+    #[must_use]
+    pub const fn total(&self) -> &Counters {
+        &self.total
+    }
+
+    /// The counters for conversions of the derived type with the given name.
+    ///
+    /// The name of a derived type is its module path followed by its name, such as `my_crate::model::Document`.
+    #[must_use]
+    pub fn get(&self, type_name: &str) -> Option<&Counters> {
+        self.types.get(type_name)
+    }
+
+    /// The counters for conversions of each derived type, ordered by type name.
+    pub fn types(&self) -> impl Iterator<Item = (&'static str, &Counters)> + '_ {
+        self.types.iter().map(|(name, counters)| (*name, counters))
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.total.merge(&other.total);
+        for (name, counters) in &other.types {
+            self.types.entry(name).or_default().merge(counters);
+        }
+    }
+
+    /// Apply `f` to the total counters and to the counters of the given type, if any.
+    fn record(&mut self, type_name: Option<&'static str>, f: impl Fn(&mut Counters)) {
+        f(&mut self.total);
+        if let Some(type_name) = type_name {
+            f(self.types.entry(type_name).or_default());
+        }
+    }
+}
+
+/// The thread-local collector.
+#[derive(Default)]
+struct Collector {
+    report: Report,
+    stack: Vec<&'static str>,
+}
+
+std::thread_local! {
+    static COLLECTOR: RefCell<Collector> = RefCell::new(Collector::default());
+}
+
+/// Take the report of all conversions recorded on the current thread, leaving an empty report in its place.
+#[must_use]
+pub fn take() -> Report {
+    with_collector(|collector| core::mem::take(&mut collector.report)).unwrap_or_default()
+}
+
+/// A copy of the report of all conversions recorded on the current thread.
+#[must_use]
+pub fn snapshot() -> Report {
+    with_collector(|collector| collector.report.clone()).unwrap_or_default()
+}
+
+/// Run `f` and return its result together with the report of the conversions it performed on the current thread.
+///
+/// The conversions performed by `f` are also recorded in the thread-local report.
+pub fn collect<R>(f: impl FnOnce() -> R) -> (R, Report) {
+    /// Merge the outer report back into the thread-local report, even if `f` panics.
+    struct Restore(Report);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let outer = core::mem::take(&mut self.0);
+            with_collector(|collector| {
+                let inner = core::mem::replace(&mut collector.report, outer);
+                collector.report.merge(&inner);
+            });
+        }
+    }
+
+    let restore = Restore(take());
+    let result = f();
+    let report = snapshot();
+    drop(restore);
+    (result, report)
+}
+
+/// A guard which records that a value of a derived type is being converted until it is dropped.
+#[must_use]
+pub(crate) struct TypeGuard(());
+
+impl Drop for TypeGuard {
+    fn drop(&mut self) {
+        with_collector(|collector| collector.stack.pop());
+    }
+}
+
+/// Record that a value of the given derived type is being converted.
+pub(crate) fn enter(type_name: &'static str) -> TypeGuard {
+    with_collector(|collector| {
+        collector.stack.push(type_name);
+        collector
+            .report
+            .record(Some(type_name), |counters| counters.conversions += 1);
+    });
+    TypeGuard(())
+}
+
+/// Record an allocation of `bytes`.
+pub(crate) fn record_alloc(bytes: usize) {
+    with_collector(|collector| {
+        let type_name = collector.stack.last().copied();
+        collector.report.record(type_name, |counters| {
+            counters.allocations += 1;
+            counters.bytes += bytes as u64;
+        });
+    });
+}
+
+/// Record that a `Cow::Owned` value was reused.
+pub(crate) fn record_reuse() {
+    with_collector(|collector| {
+        let type_name = collector.stack.last().copied();
+        collector
+            .report
+            .record(type_name, |counters| counters.reused += 1);
+    });
+}
+
+/// Access the thread-local collector, if it is available.
+fn with_collector<R>(f: impl FnOnce(&mut Collector) -> R) -> Option<R> {
+    COLLECTOR
+        .try_with(|collector| f(&mut collector.borrow_mut()))
+        .ok()
+}
@@ -0,0 +1,481 @@
+//! Interning of strings and byte slices.
+//!
+//! When the `intern` feature is enabled the [`ToInternedStatic`] trait converts strings and byte slices, and the
+//! `Cow`s, `Option`s and `Vec`s which hold them, to a form that is bounded by `'static` by interning them in a global,
+//! thread-safe, [`Interner`] rather than copying them.  Each distinct value is copied once and every conversion of an
+//! equal value returns the same `&'static str` or `&'static [u8]`, which makes it cheap to convert values which
+//! repeat often.
+//!
+//! Interned values are never freed and so interning is only suitable for values drawn from a bounded set, such as
+//! hostnames or method names, and should not be used for untrusted input.
+//!
+//! The global interner defaults to the built-in [`DefaultInterner`] and may be replaced, before it is first used, with
+//! [`set_interner`].  Interners backed by the `lasso` and `ustr` crates are available by enabling the features of the
+//! same name.  The memory used by the global interner is reported by [`stats`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use bounded_static::{intern, ToInternedStatic};
+//! let input = String::from("localhost");
+//! let first = Cow::from(input.as_str()).to_static_interned();
+//! let second = input.to_static_interned();
+//! assert!(matches!(first, Cow::Borrowed(_)));
+//! assert!(std::ptr::eq(first.as_ptr(), second.as_ptr()));
+//! assert!(intern::stats().hits() >= 1);
+//! ```
+
+use crate::StaticCow;
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::hash::Hash;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::collections::HashSet;
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+/// A thread-safe store of interned strings and byte slices.
+///
+/// An `Interner` must return the same `'static` reference for equal values and is expected to never free the values
+/// it has interned.
+pub trait Interner: Send + Sync {
+    /// Intern a string.
+    fn intern_str(&'static self, value: &str) -> &'static str;
+
+    /// Intern a byte slice.
+    fn intern_bytes(&'static self, value: &[u8]) -> &'static [u8];
+
+    /// The memory used by this interner and the number of lookups performed.
+    fn stats(&self) -> InternStats;
+}
+
+/// Memory statistics for an [`Interner`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct InternStats {
+    values: usize,
+    bytes: usize,
+    lookups: usize,
+    hits: usize,
+}
+
+impl InternStats {
+    /// Create new `InternStats`.
+    #[must_use]
+    pub const fn new(values: usize, bytes: usize, lookups: usize, hits: usize) -> Self {
+        Self {
+            values,
+            bytes,
+            lookups,
+            hits,
+        }
+    }
+
+    /// The number of distinct values interned.
+    #[must_use]
+    pub const fn values(&self) -> usize {
+        self.values
+    }
+
+    /// The number of heap bytes used by the interned values.
+    #[must_use]
+    pub const fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// The number of values looked up.
+    #[must_use]
+    pub const fn lookups(&self) -> usize {
+        self.lookups
+    }
+
+    /// The number of values looked up which were already interned.
+    #[must_use]
+    pub const fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// The number of values looked up which were not already interned and so were copied.
+    #[must_use]
+    pub const fn misses(&self) -> usize {
+        self.lookups.saturating_sub(self.hits)
+    }
+
+    const fn merge(self, other: Self) -> Self {
+        Self {
+            values: self.values + other.values,
+            bytes: self.bytes + other.bytes,
+            lookups: self.lookups + other.lookups,
+            hits: self.hits + other.hits,
+        }
+    }
+}
+
+/// The built-in [`Interner`], which stores each distinct value in its own leaked allocation.
+#[derive(Debug, Default)]
+pub struct DefaultInterner {
+    strings: Table<str>,
+    bytes: Table<[u8]>,
+}
+
+impl DefaultInterner {
+    /// Create a new, empty, `DefaultInterner`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Interner for DefaultInterner {
+    fn intern_str(&'static self, value: &str) -> &'static str {
+        self.strings.intern(value)
+    }
+
+    fn intern_bytes(&'static self, value: &[u8]) -> &'static [u8] {
+        self.bytes.intern(value)
+    }
+
+    fn stats(&self) -> InternStats {
+        self.strings.stats().merge(self.bytes.stats())
+    }
+}
+
+/// An [`Interner`] which interns strings in a [`lasso::ThreadedRodeo`].
+///
+/// Byte slices are interned as per the [`DefaultInterner`].
+#[cfg(feature = "lasso")]
+#[derive(Debug, Default)]
+pub struct LassoInterner {
+    rodeo: lasso::ThreadedRodeo,
+    lookups: Lookups,
+    bytes: Table<[u8]>,
+}
+
+#[cfg(feature = "lasso")]
+impl LassoInterner {
+    /// Create a new, empty, `LassoInterner`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The [`lasso::ThreadedRodeo`] which holds the interned strings.
+    #[must_use]
+    pub const fn rodeo(&self) -> &lasso::ThreadedRodeo {
+        &self.rodeo
+    }
+}
+
+#[cfg(feature = "lasso")]
+impl From<lasso::ThreadedRodeo> for LassoInterner {
+    fn from(rodeo: lasso::ThreadedRodeo) -> Self {
+        Self {
+            rodeo,
+            lookups: Lookups::default(),
+            bytes: Table::default(),
+        }
+    }
+}
+
+#[cfg(feature = "lasso")]
+impl Interner for LassoInterner {
+    fn intern_str(&'static self, value: &str) -> &'static str {
+        let key = self.rodeo.get(value);
+        self.lookups.record(key.is_some());
+        let key = key.unwrap_or_else(|| self.rodeo.get_or_intern(value));
+        self.rodeo.resolve(&key)
+    }
+
+    fn intern_bytes(&'static self, value: &[u8]) -> &'static [u8] {
+        self.bytes.intern(value)
+    }
+
+    fn stats(&self) -> InternStats {
+        let strings = InternStats::new(self.rodeo.len(), self.rodeo.current_memory_usage(), 0, 0);
+        strings
+            .merge(self.lookups.stats())
+            .merge(self.bytes.stats())
+    }
+}
+
+/// An [`Interner`] which interns strings in the global cache of the `ustr` crate.
+///
+/// The number of values and bytes reported by [`stats`](Interner::stats) include all strings in the `ustr` cache,
+/// including those interned with [`ustr::ustr`] directly.  Byte slices are interned as per the [`DefaultInterner`].
+#[cfg(feature = "ustr")]
+#[derive(Debug, Default)]
+pub struct UstrInterner {
+    lookups: Lookups,
+    bytes: Table<[u8]>,
+}
+
+#[cfg(feature = "ustr")]
+impl UstrInterner {
+    /// Create a new `UstrInterner`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(feature = "ustr")]
+impl Interner for UstrInterner {
+    fn intern_str(&'static self, value: &str) -> &'static str {
+        let existing = ustr::existing_ustr(value);
+        self.lookups.record(existing.is_some());
+        existing.unwrap_or_else(|| ustr::ustr(value)).as_str()
+    }
+
+    fn intern_bytes(&'static self, value: &[u8]) -> &'static [u8] {
+        self.bytes.intern(value)
+    }
+
+    fn stats(&self) -> InternStats {
+        let strings = InternStats::new(ustr::num_entries(), ustr::total_allocated(), 0, 0);
+        strings
+            .merge(self.lookups.stats())
+            .merge(self.bytes.stats())
+    }
+}
+
+static INTERNER: OnceLock<&'static dyn Interner> = OnceLock::new();
+
+/// Set the global [`Interner`].
+///
+/// # Errors
+///
+/// Returns the given interner if the global interner has already been set, or has already been used, in which case
+/// the global interner is unchanged.
+pub fn set_interner(interner: &'static dyn Interner) -> Result<(), &'static dyn Interner> {
+    INTERNER.set(interner)
+}
+
+/// The global [`Interner`], which is a [`DefaultInterner`] unless set with [`set_interner`].
+#[must_use]
+pub fn interner() -> &'static dyn Interner {
+    *INTERNER.get_or_init(|| Box::leak(Box::new(DefaultInterner::new())))
+}
+
+/// Intern a string in the global [`Interner`].
+#[must_use]
+pub fn intern_str(value: &str) -> &'static str {
+    interner().intern_str(value)
+}
+
+/// Intern a byte slice in the global [`Interner`].
+#[must_use]
+pub fn intern_bytes(value: &[u8]) -> &'static [u8] {
+    interner().intern_bytes(value)
+}
+
+/// The memory used by the global [`Interner`] and the number of lookups performed.
+#[must_use]
+pub fn stats() -> InternStats {
+    interner().stats()
+}
+
+/// A trait for converting `&T` to a form that is bounded by `'static` by interning the strings and byte slices it
+/// holds in the global [`Interner`].
+///
+/// See the module level documentation for details.
+pub trait ToInternedStatic {
+    /// The target type is bounded by the `'static` lifetime.
+    type Interned: 'static;
+
+    /// Convert an `&T` to a form that is bounded by `'static` by interning it.
+    #[must_use = "converting is often expensive and is not expected to have side effects"]
+    fn to_static_interned(&self) -> Self::Interned;
+}
+
+/// [`ToInternedStatic`] impl for converting `&str` to an interned `&'static str`.
+impl ToInternedStatic for str {
+    type Interned = &'static Self;
+
+    fn to_static_interned(&self) -> Self::Interned {
+        intern_str(self)
+    }
+}
+
+/// [`ToInternedStatic`] impl for converting `&[u8]` to an interned `&'static [u8]`.
+impl ToInternedStatic for [u8] {
+    type Interned = &'static Self;
+
+    fn to_static_interned(&self) -> Self::Interned {
+        intern_bytes(self)
+    }
+}
+
+/// [`ToInternedStatic`] impl for converting `String` to an interned `&'static str`.
+impl ToInternedStatic for String {
+    type Interned = &'static str;
+
+    fn to_static_interned(&self) -> Self::Interned {
+        intern_str(self)
+    }
+}
+
+/// Blanket [`ToInternedStatic`] impl for converting `&T` to an interned `T`.
+impl<T> ToInternedStatic for &T
+where
+    T: ToInternedStatic + ?Sized,
+{
+    type Interned = T::Interned;
+
+    fn to_static_interned(&self) -> Self::Interned {
+        (**self).to_static_interned()
+    }
+}
+
+/// [`ToInternedStatic`] impl for converting `Cow<'a, str>` to an interned `Cow::Borrowed(&'static str)`.
+impl ToInternedStatic for Cow<'_, str> {
+    type Interned = Cow<'static, str>;
+
+    fn to_static_interned(&self) -> Self::Interned {
+        Cow::Borrowed(intern_str(self))
+    }
+}
+
+/// [`ToInternedStatic`] impl for converting `Cow<'a, [u8]>` to an interned `Cow::Borrowed(&'static [u8])`.
+impl ToInternedStatic for Cow<'_, [u8]> {
+    type Interned = Cow<'static, [u8]>;
+
+    fn to_static_interned(&self) -> Self::Interned {
+        Cow::Borrowed(intern_bytes(self))
+    }
+}
+
+/// [`ToInternedStatic`] impl for converting `StaticCow<'a, str>` to an interned `StaticCow::Static(&'static str)`.
+///
+/// Data which is already borrowed for the `'static` lifetime is not interned.
+impl ToInternedStatic for StaticCow<'_, str> {
+    type Interned = StaticCow<'static, str>;
+
+    fn to_static_interned(&self) -> Self::Interned {
+        match self {
+            StaticCow::Static(value) => StaticCow::Static(value),
+            StaticCow::Borrowed(_) | StaticCow::Owned(_) => StaticCow::Static(intern_str(self)),
+        }
+    }
+}
+
+/// [`ToInternedStatic`] impl for converting `StaticCow<'a, [u8]>` to an interned `StaticCow::Static(&'static [u8])`.
+///
+/// Data which is already borrowed for the `'static` lifetime is not interned.
+impl ToInternedStatic for StaticCow<'_, [u8]> {
+    type Interned = StaticCow<'static, [u8]>;
+
+    fn to_static_interned(&self) -> Self::Interned {
+        match self {
+            StaticCow::Static(value) => StaticCow::Static(value),
+            StaticCow::Borrowed(_) | StaticCow::Owned(_) => StaticCow::Static(intern_bytes(self)),
+        }
+    }
+}
+
+/// Blanket [`ToInternedStatic`] impl for converting `Option<T>` to an interned `Option<T>`.
+impl<T> ToInternedStatic for Option<T>
+where
+    T: ToInternedStatic,
+{
+    type Interned = Option<T::Interned>;
+
+    fn to_static_interned(&self) -> Self::Interned {
+        self.as_ref().map(ToInternedStatic::to_static_interned)
+    }
+}
+
+/// Blanket [`ToInternedStatic`] impl for converting `Vec<T>` to an interned `Vec<T>`.
+impl<T> ToInternedStatic for Vec<T>
+where
+    T: ToInternedStatic,
+{
+    type Interned = Vec<T::Interned>;
+
+    fn to_static_interned(&self) -> Self::Interned {
+        self.iter()
+            .map(ToInternedStatic::to_static_interned)
+            .collect()
+    }
+}
+
+/// The number of lookups performed by an interner.
+#[derive(Debug, Default)]
+struct Lookups {
+    lookups: AtomicUsize,
+    hits: AtomicUsize,
+}
+
+impl Lookups {
+    fn record(&self, hit: bool) {
+        self.lookups.fetch_add(1, Ordering::Relaxed);
+        if hit {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn stats(&self) -> InternStats {
+        InternStats::new(
+            0,
+            0,
+            self.lookups.load(Ordering::Relaxed),
+            self.hits.load(Ordering::Relaxed),
+        )
+    }
+}
+
+/// A set of interned values of type `T`.
+#[derive(Debug)]
+struct Table<T: ?Sized + 'static> {
+    values: RwLock<HashSet<&'static T>>,
+    bytes: AtomicUsize,
+    lookups: Lookups,
+}
+
+impl<T: ?Sized> Default for Table<T> {
+    fn default() -> Self {
+        Self {
+            values: RwLock::new(HashSet::new()),
+            bytes: AtomicUsize::new(0),
+            lookups: Lookups::default(),
+        }
+    }
+}
+
+impl<T> Table<T>
+where
+    T: Eq + Hash + ?Sized,
+    for<'a> Box<T>: From<&'a T>,
+{
+    fn intern(&self, value: &T) -> &'static T {
+        let interned = self
+            .values
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(value)
+            .copied();
+        self.lookups.record(interned.is_some());
+        if let Some(interned) = interned {
+            return interned;
+        }
+        let mut values = self.values.write().unwrap_or_else(PoisonError::into_inner);
+        if let Some(interned) = values.get(value) {
+            return interned;
+        }
+        let interned: &'static T = Box::leak(Box::from(value));
+        self.bytes
+            .fetch_add(core::mem::size_of_val(value), Ordering::Relaxed);
+        values.insert(interned);
+        interned
+    }
+
+    fn stats(&self) -> InternStats {
+        let values = self
+            .values
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len();
+        InternStats::new(values, self.bytes.load(Ordering::Relaxed), 0, 0)
+            .merge(self.lookups.stats())
+    }
+}
@@ -0,0 +1,203 @@
+//! Lazy iterator adapters which convert the items of an iterator to a form that is bounded by `'static`.
+//!
+//! The [`StaticIterator`] trait is implemented for all iterators and provides the
+//! [`map_to_static`](StaticIterator::map_to_static) adapter, which converts the items of an iterator over `&T` with
+//! [`ToBoundedStatic`], and the [`map_into_static`](StaticIterator::map_into_static) adapter, which converts the
+//! items of an iterator over `T` with [`IntoBoundedStatic`].  The adapters preserve the `size_hint` of the iterator
+//! and implement `ExactSizeIterator` and `DoubleEndedIterator` when the iterator does.
+//!
+//! The [`collect_static`](StaticIterator::collect_static) and [`extend_static`](StaticIterator::extend_static)
+//! methods convert the items of an iterator into any collection which implements `FromIterator` or `Extend`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use std::collections::BTreeSet;
+//! # use bounded_static::iter::StaticIterator;
+//! let input = String::from("b\na\nb");
+//! let lines: Vec<Cow<'static, str>> = input.lines().map(Cow::from).map_into_static().collect();
+//! let unique: BTreeSet<Cow<'static, str>> = lines.iter().map_to_static().collect();
+//! let reversed: Vec<Cow<'static, str>> = input.lines().map(Cow::from).rev().collect_static();
+//! drop(input);
+//! assert_eq!(["b", "a", "b"], lines.as_slice());
+//! assert_eq!(2, unique.len());
+//! assert_eq!(["b", "a", "b"], reversed.as_slice());
+//! ```
+//!
+//! The adapters are not named `to_static` and `into_static` as iterators such as ranges also implement
+//! [`ToBoundedStatic`] and [`IntoBoundedStatic`], and so the calls would be ambiguous when both traits are in scope.
+
+use crate::{IntoBoundedStatic, ToBoundedStatic};
+use core::iter::FusedIterator;
+
+/// An extension trait for iterators which converts their items to a form that is bounded by `'static`.
+///
+/// See the `iter` module for details.
+pub trait StaticIterator: Iterator + Sized {
+    /// Convert each `&T` item of this iterator to an owned `T` such that `T: 'static`, as it is yielded.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    fn map_to_static<'a, T>(self) -> ToStaticIter<Self>
+    where
+        Self: Iterator<Item = &'a T>,
+        T: ToBoundedStatic + ?Sized + 'a,
+    {
+        ToStaticIter { iter: self }
+    }
+
+    /// Convert each item of this iterator into an owned `T` such that `T: 'static`, as it is yielded.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    fn map_into_static(self) -> IntoStaticIter<Self>
+    where
+        Self::Item: IntoBoundedStatic,
+    {
+        IntoStaticIter { iter: self }
+    }
+
+    /// Convert each item of this iterator into an owned `T` such that `T: 'static` and collect them into `C`.
+    #[must_use = "if you really need to exhaust the iterator, consider `.for_each(drop)` instead"]
+    fn collect_static<C>(self) -> C
+    where
+        Self::Item: IntoBoundedStatic,
+        C: FromIterator<<Self::Item as IntoBoundedStatic>::Static>,
+    {
+        self.map_into_static().collect()
+    }
+
+    /// Convert each item of this iterator into an owned `T` such that `T: 'static` and extend `dst` with them.
+    fn extend_static<C>(self, dst: &mut C)
+    where
+        Self::Item: IntoBoundedStatic,
+        C: Extend<<Self::Item as IntoBoundedStatic>::Static>,
+    {
+        dst.extend(self.map_into_static());
+    }
+}
+
+impl<I> StaticIterator for I where I: Iterator {}
+
+/// An iterator which converts each `&T` item of an iterator to an owned `T` such that `T: 'static`.
+///
+/// Created by [`StaticIterator::map_to_static`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct ToStaticIter<I> {
+    iter: I,
+}
+
+impl<'a, I, T> Iterator for ToStaticIter<I>
+where
+    I: Iterator<Item = &'a T>,
+    T: ToBoundedStatic + ?Sized + 'a,
+{
+    type Item = T::Static;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(ToBoundedStatic::to_static)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(ToBoundedStatic::to_static)
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.iter.fold(init, |acc, item| f(acc, item.to_static()))
+    }
+}
+
+impl<'a, I, T> DoubleEndedIterator for ToStaticIter<I>
+where
+    I: DoubleEndedIterator<Item = &'a T>,
+    T: ToBoundedStatic + ?Sized + 'a,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(ToBoundedStatic::to_static)
+    }
+}
+
+impl<'a, I, T> ExactSizeIterator for ToStaticIter<I>
+where
+    I: ExactSizeIterator<Item = &'a T>,
+    T: ToBoundedStatic + ?Sized + 'a,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, I, T> FusedIterator for ToStaticIter<I>
+where
+    I: FusedIterator<Item = &'a T>,
+    T: ToBoundedStatic + ?Sized + 'a,
+{
+}
+
+/// An iterator which converts each item of an iterator into an owned `T` such that `T: 'static`.
+///
+/// Created by [`StaticIterator::map_into_static`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct IntoStaticIter<I> {
+    iter: I,
+}
+
+impl<I> Iterator for IntoStaticIter<I>
+where
+    I: Iterator,
+    I::Item: IntoBoundedStatic,
+{
+    type Item = <I::Item as IntoBoundedStatic>::Static;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(IntoBoundedStatic::into_static)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(IntoBoundedStatic::into_static)
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.iter.fold(init, |acc, item| f(acc, item.into_static()))
+    }
+}
+
+impl<I> DoubleEndedIterator for IntoStaticIter<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: IntoBoundedStatic,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(IntoBoundedStatic::into_static)
+    }
+}
+
+impl<I> ExactSizeIterator for IntoStaticIter<I>
+where
+    I: ExactSizeIterator,
+    I::Item: IntoBoundedStatic,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I> FusedIterator for IntoStaticIter<I>
+where
+    I: FusedIterator,
+    I::Item: IntoBoundedStatic,
+{
+}
@@ -0,0 +1,354 @@
+//! Promotion of values to `'static` references.
+//!
+//! Data which is loaded once and then used for the rest of the program, such as configuration or a schema, can be
+//! converted to a form that is bounded by `'static` and leaked, such that it can be shared as a `&'static` reference
+//! without reference counting.
+//!
+//! The [`LeakStatic`] trait converts a value with [`into_static`](crate::IntoBoundedStatic::into_static) and leaks it
+//! in its own allocation.  When the `std` feature is enabled the [`StaticArena`] type converts values and leaks them
+//! in batches, tracks the number of bytes it has leaked, and can be disabled, for example in tests, such that it
+//! shares reference counted values instead.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use bounded_static::LeakStatic;
+//! let input = String::from("a b c");
+//! let words: Vec<Cow<'_, str>> = input.split(' ').map(Cow::from).collect();
+//! let words: &'static [Cow<'static, str>] = words.leak_static();
+//! std::thread::spawn(move || assert_eq!(words, ["a", "b", "c"])).join().unwrap();
+//! ```
+
+#[cfg(feature = "std")]
+use crate::{record_alloc, ToBoundedStatic};
+use crate::{record_alloc_for, IntoBoundedStatic};
+use alloc::boxed::Box;
+
+#[cfg(feature = "std")]
+use core::any::{Any, TypeId};
+#[cfg(feature = "std")]
+use core::fmt::{Debug, Display, Formatter};
+#[cfg(feature = "std")]
+use core::hash::{Hash, Hasher};
+#[cfg(feature = "std")]
+use core::ops::Deref;
+#[cfg(feature = "std")]
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+
+/// Convert a value to a form that is bounded by `'static` and leak it as a `&'static` reference.
+///
+/// This trait is implemented for every type which implements [`IntoBoundedStatic`].
+pub trait LeakStatic: IntoBoundedStatic + Sized {
+    /// Convert this value with [`into_static`](IntoBoundedStatic::into_static) and leak it.
+    ///
+    /// The converted value is never dropped and its memory is never freed.
+    #[must_use]
+    fn leak_static(self) -> &'static Self::Static;
+}
+
+impl<T> LeakStatic for T
+where
+    T: IntoBoundedStatic,
+{
+    fn leak_static(self) -> &'static Self::Static {
+        record_alloc_for::<T::Static>(1);
+        Box::leak(Box::new(self.into_static()))
+    }
+}
+
+/// The default number of values of each type which a [`StaticArena`] leaks in a single allocation.
+#[cfg(feature = "std")]
+pub const DEFAULT_CHUNK_LEN: usize = 64;
+
+/// An arena which converts values to a form that is bounded by `'static` and leaks them in batches.
+///
+/// Values of each type are stored in chunks of [`chunk_len`](StaticArena::chunk_len) values which are leaked in a
+/// single allocation, such that promoting many small values does not perform an allocation for each value.  The arena
+/// tracks the number of values it has promoted and the number of bytes it has leaked.
+///
+/// A `StaticArena` can be disabled with [`set_enabled`](StaticArena::set_enabled), for example in tests, in which case
+/// it does not leak values and instead returns reference counted values which are freed once they are no longer used.
+/// The [`StaticRef`] returned by [`promote`](StaticArena::promote) dereferences to the value in either case.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use bounded_static::StaticArena;
+/// static ARENA: StaticArena = StaticArena::new();
+///
+/// let input = String::from("value");
+/// let first = ARENA.promote(Cow::from(&input));
+/// let second = ARENA.promote(Cow::from(&input));
+/// assert_eq!(first, second);
+/// let value: &'static Cow<'static, str> = first.as_static().unwrap();
+/// assert_eq!(2, ARENA.promoted());
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct StaticArena {
+    enabled: AtomicBool,
+    chunk_len: usize,
+    chunks: Mutex<BTreeMap<TypeId, Box<dyn Any + Send>>>,
+    promoted: AtomicUsize,
+    leaked_bytes: AtomicUsize,
+}
+
+#[cfg(feature = "std")]
+impl StaticArena {
+    /// Create a new, enabled, `StaticArena` with chunks of [`DEFAULT_CHUNK_LEN`] values.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_chunk_len(DEFAULT_CHUNK_LEN)
+    }
+
+    /// Create a new, enabled, `StaticArena` with chunks of `chunk_len` values.
+    ///
+    /// A `chunk_len` of zero is treated as one.
+    #[must_use]
+    pub const fn with_chunk_len(chunk_len: usize) -> Self {
+        Self {
+            enabled: AtomicBool::new(true),
+            chunk_len: if chunk_len == 0 { 1 } else { chunk_len },
+            chunks: Mutex::new(BTreeMap::new()),
+            promoted: AtomicUsize::new(0),
+            leaked_bytes: AtomicUsize::new(0),
+        }
+    }
+
+    /// Convert a value with [`into_static`](IntoBoundedStatic::into_static) and promote it to a [`StaticRef`].
+    ///
+    /// If this arena is enabled the value is leaked in a chunk of this arena, otherwise it is reference counted.
+    pub fn promote<T>(&self, value: T) -> StaticRef<T::Static>
+    where
+        T: IntoBoundedStatic,
+        T::Static: Send + Sync,
+    {
+        self.store(value.into_static())
+    }
+
+    /// Convert a value with [`to_static`](ToBoundedStatic::to_static) and promote it to a [`StaticRef`].
+    pub fn promote_ref<T>(&self, value: &T) -> StaticRef<T::Static>
+    where
+        T: ToBoundedStatic + ?Sized,
+        T::Static: Send + Sync,
+    {
+        self.store(value.to_static())
+    }
+
+    /// Enable or disable this arena.
+    ///
+    /// Disabling an arena does not free the values it has already leaked.
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Returns true if this arena leaks the values it promotes.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// The number of values of each type which this arena leaks in a single allocation.
+    #[must_use]
+    pub const fn chunk_len(&self) -> usize {
+        self.chunk_len
+    }
+
+    /// The number of values which have been leaked by this arena.
+    #[must_use]
+    pub fn promoted(&self) -> usize {
+        self.promoted.load(Ordering::Relaxed)
+    }
+
+    /// The number of bytes which have been leaked by this arena, including any unused space in its chunks.
+    #[must_use]
+    pub fn leaked_bytes(&self) -> usize {
+        self.leaked_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Leak a converted value in this arena, or reference count it if this arena is disabled.
+    fn store<T>(&self, value: T) -> StaticRef<T>
+    where
+        T: Send + Sync + 'static,
+    {
+        if !self.is_enabled() {
+            return StaticRef(Repr::Shared(Arc::new(value)));
+        }
+        let slot = self.next_slot::<T>();
+        self.promoted.fetch_add(1, Ordering::Relaxed);
+        StaticRef(Repr::Leaked(slot.get_or_init(|| value)))
+    }
+
+    /// Take the next free slot for a value of type `T`, leaking a new chunk if required.
+    fn next_slot<T>(&self) -> &'static OnceLock<T>
+    where
+        T: Send + Sync + 'static,
+    {
+        let mut chunks = self.chunks.lock().unwrap_or_else(PoisonError::into_inner);
+        let chunk = chunks
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(Chunk::<T>::default()))
+            .downcast_mut::<Chunk<T>>()
+            .expect("chunk type matches its key");
+        if chunk.next == chunk.slots.len() {
+            let slots: Box<[OnceLock<T>]> = (0..self.chunk_len).map(|_| OnceLock::new()).collect();
+            let bytes = core::mem::size_of_val(&*slots);
+            record_alloc(bytes);
+            self.leaked_bytes.fetch_add(bytes, Ordering::Relaxed);
+            chunk.slots = Box::leak(slots);
+            chunk.next = 0;
+        }
+        let (slots, index) = (chunk.slots, chunk.next);
+        chunk.next += 1;
+        drop(chunks);
+        &slots[index]
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for StaticArena {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The leaked chunk of values of type `T` which a [`StaticArena`] is filling.
+#[cfg(feature = "std")]
+struct Chunk<T: 'static> {
+    slots: &'static [OnceLock<T>],
+    next: usize,
+}
+
+#[cfg(feature = "std")]
+impl<T> Default for Chunk<T> {
+    fn default() -> Self {
+        Self {
+            slots: Box::leak(Box::new([])),
+            next: 0,
+        }
+    }
+}
+
+/// A value promoted by a [`StaticArena`].
+///
+/// A `StaticRef` dereferences to a value which is either leaked, if the arena was enabled, or reference counted, if
+/// the arena was disabled.  Cloning a `StaticRef` never clones the value.
+#[cfg(feature = "std")]
+pub struct StaticRef<T: 'static>(Repr<T>);
+
+#[cfg(feature = "std")]
+enum Repr<T: 'static> {
+    Leaked(&'static T),
+    Shared(Arc<T>),
+}
+
+#[cfg(feature = "std")]
+impl<T> StaticRef<T> {
+    /// The leaked `&'static` reference to the value, if the value was promoted by an enabled arena.
+    #[must_use]
+    pub fn as_static(&self) -> Option<&'static T> {
+        match &self.0 {
+            Repr::Leaked(value) => Some(value),
+            Repr::Shared(_) => None,
+        }
+    }
+
+    /// Returns true if the value was leaked.
+    #[must_use]
+    pub const fn is_leaked(&self) -> bool {
+        matches!(self.0, Repr::Leaked(_))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Deref for StaticRef<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match &self.0 {
+            Repr::Leaked(value) => value,
+            Repr::Shared(value) => value,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> AsRef<T> for StaticRef<T> {
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Clone for StaticRef<T> {
+    fn clone(&self) -> Self {
+        match &self.0 {
+            Repr::Leaked(value) => Self(Repr::Leaked(value)),
+            Repr::Shared(value) => Self(Repr::Shared(value.clone())),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Debug> Debug for StaticRef<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Display> Display for StaticRef<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: PartialEq> PartialEq for StaticRef<T> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Eq> Eq for StaticRef<T> {}
+
+#[cfg(feature = "std")]
+impl<T: Hash> Hash for StaticRef<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&**self, state);
+    }
+}
+
+/// No-op [`ToBoundedStatic`] impl for `StaticRef<T>`.
+#[cfg(feature = "std")]
+impl<T> ToBoundedStatic for StaticRef<T> {
+    type Static = Self;
+
+    fn to_static(&self) -> Self::Static {
+        self.clone()
+    }
+}
+
+/// No-op [`IntoBoundedStatic`] impl for `StaticRef<T>`.
+#[cfg(feature = "std")]
+impl<T> IntoBoundedStatic for StaticRef<T> {
+    type Static = Self;
+
+    fn into_static(self) -> Self::Static {
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+crate::__to_bounded!(clone impl[T] StaticRef<T> where T: 'static);
+#[cfg(feature = "std")]
+crate::__to_static_as!(clone impl[T] StaticRef<T> where T: 'static);
+#[cfg(feature = "std")]
+crate::__to_send_static!([send] clone impl[T] StaticRef<T> where T: 'static);
@@ -30,6 +30,22 @@
 //! - [`tuple`](tuple)
 //! - [`Option`]
 //! - [`Result`]
+//! - [`Cell`](core::cell::Cell)
+//! - [`RefCell`](core::cell::RefCell)
+//! - [`OnceCell`](core::cell::OnceCell)
+//! - [`Range`](core::ops::Range) (and all other range types)
+//! - [`Bound`](core::ops::Bound)
+//! - [`ControlFlow`](core::ops::ControlFlow)
+//! - [`Reverse`](core::cmp::Reverse)
+//! - [`Ordering`](core::cmp::Ordering)
+//! - [`Wrapping`](core::num::Wrapping)
+//! - [`Saturating`](core::num::Saturating)
+//! - [`ManuallyDrop`](core::mem::ManuallyDrop)
+//! - [`Poll`](core::task::Poll)
+//! - [`PhantomData`](core::marker::PhantomData)
+//! - [`PhantomPinned`](core::marker::PhantomPinned)
+//! - [`Duration`](core::time::Duration)
+//! - [`BuildHasherDefault`](core::hash::BuildHasherDefault)
 //!
 //! Additional implementations are available by enabling the following features:
 //!
@@ -38,6 +54,10 @@
 //!   - [String](https://doc.rust-lang.org/alloc/string/struct.String.html)
 //!   - [Vec](https://doc.rust-lang.org/alloc/vec/struct.Vec.html)
 //!   - [Box](https://doc.rust-lang.org/alloc/boxed/struct.Box.html)
+//!   - [Pin<Box<T>>](https://doc.rust-lang.org/core/pin/struct.Pin.html)
+//!   - [Rc](https://doc.rust-lang.org/alloc/rc/struct.Rc.html) and
+//!     [Arc](https://doc.rust-lang.org/alloc/sync/struct.Arc.html), including `Rc<str>` and `Arc<str>`
+//!   - [CString](https://doc.rust-lang.org/alloc/ffi/struct.CString.html) and `Box<CStr>`
 //!
 //! - `collections` for all collection types in the `alloc` crate:
 //!   - [BinaryHeap](https://doc.rust-lang.org/alloc/collections/binary_heap/struct.BinaryHeap.html)
@@ -50,11 +70,32 @@
 //!   - [HashMap](https://doc.rust-lang.org/std/collections/struct.HashMap.html)
 //!   - [HashSet](https://doc.rust-lang.org/std/collections/struct.HashSet.html)
 //!   - [RandomState](https://doc.rust-lang.org/std/collections/hash_map/struct.RandomState.html)
+//!   - [Mutex](https://doc.rust-lang.org/std/sync/struct.Mutex.html)
+//!   - [RwLock](https://doc.rust-lang.org/std/sync/struct.RwLock.html)
+//!   - [OnceLock](https://doc.rust-lang.org/std/sync/struct.OnceLock.html)
+//!   - [PathBuf](https://doc.rust-lang.org/std/path/struct.PathBuf.html) and `Box<Path>`
+//!   - [OsString](https://doc.rust-lang.org/std/ffi/struct.OsString.html) and `Box<OsStr>`
+//!   - `Box<dyn Error + 'a>`, `Box<dyn Error + Send + 'a>` and `Box<dyn Error + Send + Sync + 'a>`
+//!   - [Instant](https://doc.rust-lang.org/std/time/struct.Instant.html)
+//!   - [SystemTime](https://doc.rust-lang.org/std/time/struct.SystemTime.html)
+//!   - [IpAddr](https://doc.rust-lang.org/std/net/enum.IpAddr.html),
+//!     [Ipv4Addr](https://doc.rust-lang.org/std/net/struct.Ipv4Addr.html) and
+//!     [Ipv6Addr](https://doc.rust-lang.org/std/net/struct.Ipv6Addr.html)
+//!   - [SocketAddr](https://doc.rust-lang.org/std/net/enum.SocketAddr.html),
+//!     [SocketAddrV4](https://doc.rust-lang.org/std/net/struct.SocketAddrV4.html) and
+//!     [SocketAddrV6](https://doc.rust-lang.org/std/net/struct.SocketAddrV6.html)
 //!
 //! Note that `collections`, `alloc` and `std` are enabled be default.
 //!
+//! Note that the network address types are only available from `core::net` from Rust 1.77 which is above the MSRV of
+//! this crate and so they are provided via `std::net` and require the `std` feature.
+//!
 //! Additional implementations for 3rd party types are available by enabling the following features:
 //!
+//! - `bumpalo` for a [`BoundedAlloc`] impl for [`Bump`](https://docs.rs/bumpalo/3.16.0/bumpalo/struct.Bump.html)
+//! - `yoke` for the `Attached` type, which is built on [`Yoke`](https://docs.rs/yoke/0.7.5/yoke/struct.Yoke.html)
+//! - `anyhow` for [`Error`](https://docs.rs/anyhow/1.0.86/anyhow/struct.Error.html) (requires `std`)
+//! - `eyre` for [`Report`](https://docs.rs/eyre/0.6.12/eyre/struct.Report.html) (requires `std`)
 //! - `smol_str` for [`SmolStr`](https://docs.rs/smol_str/0.2.2/smol_str/struct.SmolStr.html)
 //! - `smallvec` for [`SmallVec`](https://docs.rs/smallvec/1.13.2/smallvec/struct.SmallVec.html)
 //! - `smartstring` for [`SmartString`](https://docs.rs/smartstring/1.0.1/smartstring/index.html)
@@ -62,6 +103,9 @@
 //!     - [`RandomState`](https://docs.rs/ahash/0.8.6/ahash/random_state/struct.RandomState.html)
 //!     - [`AHashMap`](https://docs.rs/ahash/0.8.6/ahash/struct.AHashMap.html)
 //!     - [`AHashSet`](https://docs.rs/ahash/0.8.6/ahash/struct.AHashSet.html)
+//! - `hashbrown` for:
+//!     - [`HashMap`](https://docs.rs/hashbrown/0.14.5/hashbrown/struct.HashMap.html)
+//!     - [`HashSet`](https://docs.rs/hashbrown/0.14.5/hashbrown/struct.HashSet.html)
 //! - `chrono` for:
 //!     - [`DateTime`](https://docs.rs/chrono/0.4.38/chrono/struct.DateTime.html)
 //!     - [`FixedOffset`](https://docs.rs/chrono/0.4.38/chrono/struct.FixedOffset.html)
@@ -77,6 +121,18 @@
 //!     - [`NaiveTime`](https://docs.rs/chrono/0.4.38/chrono/naive/struct.NaiveTime.html)
 //! - `chrono-clock` for:
 //!    - [`Local`](https://docs.rs/chrono/0.4.38/chrono/struct.Local.html)
+//! - `rustc-hash` for:
+//!     - [`FxBuildHasher`](https://docs.rs/rustc-hash/2.1.1/rustc_hash/struct.FxBuildHasher.html)
+//! - `foldhash` for:
+//!     - [`fast::RandomState`](https://docs.rs/foldhash/0.1.5/foldhash/fast/struct.RandomState.html)
+//!     - [`fast::SeedableRandomState`](https://docs.rs/foldhash/0.1.5/foldhash/fast/struct.SeedableRandomState.html)
+//!     - [`fast::FixedState`](https://docs.rs/foldhash/0.1.5/foldhash/fast/struct.FixedState.html)
+//!     - [`quality::RandomState`](https://docs.rs/foldhash/0.1.5/foldhash/quality/struct.RandomState.html)
+//!     - [`quality::SeedableRandomState`](https://docs.rs/foldhash/0.1.5/foldhash/quality/struct.SeedableRandomState.html)
+//!     - [`quality::FixedState`](https://docs.rs/foldhash/0.1.5/foldhash/quality/struct.FixedState.html)
+//!
+//! Custom [`BuildHasher`](core::hash::BuildHasher) types which are `'static + Clone` can be used with the `HashMap`
+//! and `HashSet` impls by implementing the traits for them with the [`impl_build_hasher`] macro.
 //!
 //! # Examples
 //!
@@ -136,6 +192,348 @@
 //! }
 //! ```
 //!
+//! # Fallible Conversions
+//!
+//! Some values can only sometimes be converted to a form that is bounded by `'static`, such as a `Mutex` which may
+//! be poisoned or a `RefCell` which may be mutably borrowed.  The [`TryToBoundedStatic`] and [`TryIntoBoundedStatic`]
+//! traits are the fallible counterparts of [`ToBoundedStatic`] and [`IntoBoundedStatic`] and define an additional
+//! associated `Error` type:
+//!
+//! ```rust
+//! pub trait TryToBoundedStatic {
+//!     type Static: 'static;
+//!     type Error;
+//!
+//!     fn try_to_static(&self) -> Result<Self::Static, Self::Error>;
+//! }
+//! ```
+//!
+//! Both traits are blanket implemented for every type which implements the infallible traits, with an `Error` type of
+//! [`TryStaticError`], which records the [`ErrorKind`](error::ErrorKind) of the failure and the path to the value
+//! which failed.  The blanket impls convert with [`to_static_with_budget`](ToBoundedStatic::to_static_with_budget)
+//! and [`into_static_with_budget`](IntoBoundedStatic::into_static_with_budget) and an
+//! [`unlimited`](Budget::unlimited) budget, whose impls return an error rather than panic or ignore a failure:
+//!
+//! ```rust
+//! # use std::sync::Mutex;
+//! # use bounded_static::TryToBoundedStatic;
+//! let value = vec![Mutex::new(0), Mutex::new(1)];
+//! let _ = std::panic::catch_unwind(|| {
+//!     let _guard = value[1].lock().unwrap();
+//!     panic!("poison");
+//! });
+//! let err = value.try_to_static().unwrap_err();
+//! assert_eq!("lock poisoned at [1]", err.to_string());
+//! ```
+//!
+//! The budgeted impls for `String`, `Vec`, `VecDeque`, `BinaryHeap`, `HashMap`, `HashSet`, `AHashMap` and `AHashSet`,
+//! and for `StaticCow<str>` and `StaticCow<[T]>`, allocate with `try_reserve` and report an allocation failure as an
+//! error of kind [`ErrorKind::Alloc`](error::ErrorKind::Alloc) rather than aborting.  Each container is allocated once
+//! before any element is converted and any partially converted container is dropped if a later element fails.  The
+//! `StaticCow` impls copy a borrowed value with the [`ToOwnedStatic`] trait, which may be implemented for other borrowed
+//! types.
+//!
+//! Note that `Box`, `BTreeMap`, `BTreeSet`, `LinkedList`, `SmallVec`, `CString`, `PathBuf`, `OsString` and `Cow` do not
+//! provide a stable fallible allocation API, or in the case of `Cow` only require `ToOwned`, and so the impls for these
+//! types, and for `Pin<Box<T>>`, still allocate infallibly.
+//!
+//! Types which implement the infallible traits by hand, including with the [`impl_clone`] and [`impl_copy`] macros or
+//! the [`ToStatic`] derive, therefore implement the fallible traits too.  Types which cannot implement the infallible
+//! traits may implement the fallible traits directly.
+//!
+//! # Memory Limits
+//!
+//! Converting untrusted input to `'static` copies all borrowed data onto the heap.  The
+//! [`to_static_with_limit`](ToBoundedStatic::to_static_with_limit) and
+//! [`into_static_with_limit`](IntoBoundedStatic::into_static_with_limit) methods bound the number of heap bytes which
+//! may be allocated during a conversion and stop with an error of kind
+//! [`ErrorKind::LimitExceeded`](error::ErrorKind::LimitExceeded) once the limit is exceeded:
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use bounded_static::ToBoundedStatic;
+//! let input = String::from("untrusted");
+//! let value = vec![Cow::from(&input[..2]), Cow::from(&input[2..])];
+//! let limit = 2 * std::mem::size_of::<Cow<'_, str>>() + 4;
+//! let err = value.to_static_with_limit(limit).unwrap_err();
+//! assert_eq!("memory budget exceeded at [1]", err.to_string());
+//! ```
+//!
+//! The bytes are charged to a [`Budget`] which may also be shared across several conversions with
+//! [`to_static_with_budget`](ToBoundedStatic::to_static_with_budget).  Strings, `Cow`, `Box`, the path and ffi types
+//! and all collections charge the size of the data they allocate and all other impls provided by this crate, and the
+//! impls generated by the [`ToStatic`] derive macro, pass the budget to their fields.  Note that a `Cow` is cloned
+//! with `ToOwned`, which gives no way to measure the heap data of the elements of a `Cow<[T]>`, and so unless the
+//! value is known to own no heap data, such as a `Cow<str>` or `Cow<[u8]>`, a `Cow` fails to convert with an error of
+//! kind [`ErrorKind::Unmeasurable`](error::ErrorKind::Unmeasurable) for a limited budget.
+//!
+//! **Types which implement [`ToBoundedStatic`] or [`IntoBoundedStatic`] by hand charge nothing unless they override
+//! the budgeted methods.**  A hand-written impl which allocates, or which holds values that may allocate, passes any
+//! limit however much it allocates, and so must override
+//! [`to_static_with_budget`](ToBoundedStatic::to_static_with_budget) and
+//! [`into_static_with_budget`](IntoBoundedStatic::into_static_with_budget) for the limit to be enforced.  The
+//! [`impl_clone`] and [`impl_into_owned`] macros below do so for a type which declares the heap data it owns with a
+//! `heap(|value| len)` clause.
+//!
+//! # Cost Estimation
+//!
+//! The [`StaticCost`] trait, which is implemented for every type which implements [`ToBoundedStatic`], reports the
+//! cost of a conversion without performing it.  The [`CostReport`] counts the borrowed and owned leaves of a value
+//! and the number of bytes that [`to_static`](ToBoundedStatic::to_static) would copy, which may be used to choose
+//! between converting a value, keeping the data it borrows alive, or rejecting it:
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use bounded_static::StaticCost;
+//! let input = String::from("borrowed");
+//! let value = vec![Cow::from(&input[..4]), Cow::from(&input[4..]), Cow::Owned(String::from("owned"))];
+//! let report = value.static_cost();
+//! assert_eq!(2, report.borrowed());
+//! assert_eq!(8, report.borrowed_bytes());
+//! assert_eq!(1, report.owned());
+//! ```
+//!
+//! The cost is computed by [`add_static_cost`](ToBoundedStatic::add_static_cost) which, as for the budgeted
+//! conversions above, is provided by all impls in this crate and generated by the [`ToStatic`] derive macro.
+//!
+//! # Shared Pointers
+//!
+//! Converting a value with `to_static` converts the value behind each `Rc` or `Arc` it holds separately, such that a
+//! value which is shared by several pointers is duplicated.  The [`to_static_with`](ToBoundedStatic::to_static_with)
+//! method, which is also generated by the [`ToStatic`] derive macro, converts a value with a [`StaticConverter`] which
+//! records each pointer it converts, such that shared values remain shared:
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use std::rc::Rc;
+//! # use bounded_static::StaticConverter;
+//! let input = String::from("shared");
+//! let shared = Rc::new(Cow::from(input.as_str()));
+//! let value = (shared.clone(), vec![shared]);
+//! let to_static = StaticConverter::new().convert(&value);
+//! assert!(Rc::ptr_eq(&to_static.0, &to_static.1[0]));
+//! ```
+//!
+//! # Static Borrows
+//!
+//! Converting a `Cow::Borrowed` always copies the borrowed data, even if it is borrowed for `'static`.  The
+//! [`StaticCow`] type (which requires the `alloc` feature) is a `Cow` with a separate state for data borrowed for
+//! `'static`, which is kept without copying when converted:
+//!
+//! ```rust
+//! # use bounded_static::{IntoBoundedStatic, StaticCow};
+//! let input = String::from("borrowed");
+//! let values = vec![StaticCow::Static("static"), StaticCow::Borrowed(input.as_str())];
+//! let into_static = values.into_static();
+//! assert!(into_static[0].is_static());
+//! assert!(into_static[1].is_owned());
+//! ```
+//!
+//! Enabling the `serde` feature provides `Serialize` and `Deserialize` impls for [`StaticCow`].
+//!
+//! # Interning
+//!
+//! When the `intern` feature (which requires `std`) is enabled the [`ToInternedStatic`] trait converts strings and
+//! byte slices to a form that is bounded by `'static` by interning them in a global, thread-safe, interner, such that
+//! each distinct value is only copied once.  The interner may be backed by the `lasso` or `ustr` crates by enabling the
+//! features of the same name.  See the `intern` module for details.
+//!
+//! # Bounded Lifetimes
+//!
+//! The [`ToBounded`] trait converts a value to a form that is bounded by a lifetime `'b` chosen by the caller, such as
+//! the lifetime of an arena, rather than `'static`.  Borrowed strings and slices are copied with a [`BoundedAlloc`]
+//! which lives for `'b`, such as [`Heap`], which is equivalent to [`to_static`](ToBoundedStatic::to_static), or a
+//! `bumpalo::Bump` arena when the `bumpalo` feature is enabled:
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use bounded_static::{Heap, ToBounded};
+//! fn rebind<'b>(value: &(Cow<'_, str>, u32), alloc: &'b Heap) -> (Cow<'b, str>, u32) {
+//!     value.to_bounded_in(alloc)
+//! }
+//!
+//! let input = String::from("value");
+//! let output = rebind(&(Cow::from(input.as_str()), 1), &Heap);
+//! drop(input);
+//! assert_eq!(("value", 1), (output.0.as_ref(), output.1));
+//! ```
+//!
+//! [`ToBoundedStatic`] is a supertrait of [`ToBounded`], the `'static` special case of it which copies borrowed data
+//! to the heap.  The `ToBounded` derive macro implements [`ToBounded`] for a `struct` or `enum`, replacing each of its
+//! lifetime parameters with `'b`, and so is used together with the `ToStatic` derive macro.  See the `bounded` module
+//! for details.
+//!
+//! # Representations
+//!
+//! The [`ToStaticAs`] trait converts a value to `'static` with a [`StaticRepr`] policy which chooses how its strings
+//! and sequences are represented, such as `Box<str>` and `Box<[T]>` with `Compact`, or `Arc<str>` and `Arc<[T]>`
+//! with `Shared`:
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use bounded_static::{repr::Compact, ToStaticAs};
+//! let input = String::from("value");
+//! let output: Option<Box<str>> = Some(Cow::from(input.as_str())).to_static_as(Compact);
+//! drop(input);
+//! assert_eq!(Some("value"), output.as_deref());
+//! ```
+//!
+//! The `ToStaticAs` derive macro implements [`ToStaticAs`] for a `struct` or `enum` which is generic over the
+//! representation of its strings.  See the `repr` module for details.
+//!
+//! # Thread Safe Snapshots
+//!
+//! A type which is bounded by `'static` is not necessarily `Send`.  The [`ToSendStatic`] trait converts a value to a
+//! snapshot which is `Send + Sync + 'static`, replacing `Rc` with `Arc`, `RefCell` with `Mutex` and a `Cell` of a
+//! primitive with an atomic:
+//!
+//! ```rust
+//! # use std::cell::Cell;
+//! # use std::rc::Rc;
+//! # use bounded_static::ToSendStatic;
+//! let state = (Rc::<str>::from("state"), Cell::new(1_u32));
+//! let snapshot = state.to_send_static();
+//! std::thread::spawn(move || assert_eq!("state", &*snapshot.0)).join().unwrap();
+//! ```
+//!
+//! The `ToSendStatic` derive macro implements [`ToSendStatic`] for a `struct` or `enum`.  See the `send` module for
+//! details.
+//!
+//! # Leaking
+//!
+//! The [`leak_static`](LeakStatic::leak_static) method (which requires the `alloc` feature) converts a value with
+//! `into_static` and leaks it, such that data which is loaded once can be shared as a `&'static` reference:
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use bounded_static::LeakStatic;
+//! let input = String::from("config");
+//! let config: &'static Cow<'static, str> = Cow::from(input.as_str()).leak_static();
+//! assert_eq!("config", config);
+//! ```
+//!
+//! When the `std` feature is enabled the [`StaticArena`] type leaks values in batches, tracks the number of bytes it
+//! has leaked and can be disabled, for example in tests.  See the `leak` module for details.
+//!
+//! # Attaching
+//!
+//! When the `yoke` feature is enabled the `Attached` type keeps a borrowed view alongside the owner it borrows from,
+//! such as a `Vec<u8>` or an `Arc<[u8]>`, as an alternative to copying the view with `into_static`.  The pair is
+//! bounded by `'static` and the view may be mapped, projected or detached from the owner by copying it.  See the
+//! `attach` module for details.
+//!
+//! # Errors
+//!
+//! When the `std` feature is enabled a boxed error `Box<dyn Error + 'a>`, which may borrow the input it failed to
+//! parse, is converted to a `Box<dyn Error + 'static>` by capturing it as an owned
+//! [`StaticError`](error::StaticError), which reproduces the `Display` and `Debug` output of the error and of each of
+//! its sources:
+//!
+//! ```rust
+//! # use std::error::Error;
+//! # use bounded_static::IntoBoundedStatic;
+//! fn parse(input: &str) -> Result<u32, Box<dyn Error + Send + Sync + '_>> {
+//!     input.parse().map_err(|_| format!("invalid number: {input}").into())
+//! }
+//!
+//! let input = String::from("x");
+//! let err: Box<dyn Error + Send + Sync> = parse(&input).unwrap_err().into_static();
+//! drop(input);
+//! assert_eq!("invalid number: x", err.to_string());
+//! ```
+//!
+//! The `anyhow` and `eyre` features provide the same conversion for `anyhow::Error` and `eyre::Report`.
+//!
+//! # Reusing Allocations
+//!
+//! The [`to_static_into`](ToBoundedStatic::to_static_into) method is the `clone_from` counterpart of `to_static`, it
+//! converts a value into an existing `'static` value and reuses the capacity of any `String`, `Vec` or map it holds:
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use bounded_static::ToBoundedStatic;
+//! let mut buffer: Vec<Cow<'static, str>> = Vec::new();
+//! for line in ["a b", "c d e"] {
+//!     let words: Vec<Cow<'_, str>> = line.split(' ').map(Cow::from).collect();
+//!     words.to_static_into(&mut buffer);
+//! }
+//! assert_eq!(vec!["c", "d", "e"], buffer);
+//! ```
+//!
+//! The [`ToStatic`] derive macro generates `to_static_into` for structs and enums, which converts each field into
+//! the matching field of the existing value.
+//!
+//! # Iterators
+//!
+//! The [`StaticIterator`] extension trait provides lazy `map_to_static` and `map_into_static` adapters which convert
+//! the items of an iterator as they are yielded, and the `collect_static` and `extend_static` methods which convert
+//! the items of an iterator into a collection:
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use bounded_static::StaticIterator;
+//! let input = String::from("a\nb");
+//! let lines: Vec<Cow<'static, str>> = input.lines().map(Cow::from).collect_static();
+//! drop(input);
+//! assert_eq!(["a", "b"], lines.as_slice());
+//! ```
+//!
+//! See the `iter` module for details.
+//!
+//! # Threads
+//!
+//! When the `std` feature is enabled the [`thread::spawn_static`] function and the [`thread::Builder`] type convert
+//! borrowed data with `to_static` and pass it to the closure of a new thread, which must be `'static`:
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use bounded_static::thread::spawn_static;
+//! let input = String::from("request");
+//! let request = Cow::from(input.as_str());
+//! let handle = spawn_static(&request, |request| request.len());
+//! drop(request);
+//! drop(input);
+//! assert_eq!(7, handle.join().unwrap());
+//! ```
+//!
+//! # Instrumentation
+//!
+//! When the `instrument` feature (which requires `std`) is enabled the conversions provided by this crate record the
+//! number of allocations they perform, the number of bytes they copy and the number of `Cow::Owned` values they reuse
+//! in a thread-local collector, attributed to the innermost type being converted which derives [`ToStatic`].  See the
+//! `instrument` module for details.
+//!
+//! # Macros
+//!
+//! The [`impl_copy`], [`impl_clone`] and [`impl_into_owned`] macros can be used to implement [`ToBoundedStatic`] and
+//! [`IntoBoundedStatic`] for your own leaf types, such as `Copy` types, `'static + Clone` types and types which
+//! provide a `to_owned()` / `into_owned()` pair:
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! #[derive(Copy, Clone)]
+//! struct Id(u64);
+//!
+//! #[derive(Clone)]
+//! struct Name(String);
+//!
+//! struct Foo<'a>(Cow<'a, str>);
+//!
+//! impl Foo<'_> {
+//!     fn to_owned(&self) -> Foo<'static> {
+//!         Foo(Cow::Owned(self.0.to_string()))
+//!     }
+//!
+//!     fn into_owned(self) -> Foo<'static> {
+//!         Foo(Cow::Owned(self.0.into_owned()))
+//!     }
+//! }
+//!
+//! bounded_static::impl_copy!(Id);
+//! bounded_static::impl_clone!(heap(|name| name.0.len()) Name);
+//! bounded_static::impl_into_owned!(heap(|foo| foo.0.len()) Foo<'_> => Foo<'static>);
+//! ```
+//!
 //! # Derive
 //!
 //! These traits may be automatically derived for any `struct` or `enum` that can be converted to a form that is
@@ -179,7 +577,7 @@
 //! ```
 #![warn(clippy::all, clippy::pedantic, clippy::nursery, rust_2018_idioms)]
 #![allow(clippy::missing_const_for_fn)]
-#![forbid(unsafe_code)]
+#![deny(unsafe_code)]
 #![no_std]
 
 #[cfg(feature = "std")]
@@ -188,19 +586,65 @@ extern crate std;
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[macro_use]
+mod macros;
+
+#[cfg(feature = "yoke")]
+pub mod attach;
+pub mod bounded;
+pub mod budget;
+pub mod converter;
+pub mod cost;
+pub mod error;
+mod fast_path;
+#[cfg(feature = "instrument")]
+pub mod instrument;
+#[cfg(feature = "intern")]
+pub mod intern;
+pub mod iter;
+#[cfg(feature = "alloc")]
+pub mod leak;
+pub mod repr;
+pub mod send;
+#[cfg(feature = "alloc")]
+pub mod static_cow;
+#[cfg(feature = "std")]
+pub mod thread;
+
+use core::cell::{Cell, OnceCell, RefCell};
+use core::cmp::{Ordering, Reverse};
+use core::hash::BuildHasherDefault;
+use core::marker::{PhantomData, PhantomPinned};
+use core::mem::ManuallyDrop;
 use core::num::{
     NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
-    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
 };
+use core::ops::{
+    Bound, ControlFlow, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
+use core::task::Poll;
+use core::time::Duration;
+
+#[cfg(feature = "alloc")]
+use core::pin::Pin;
 
 #[cfg(feature = "alloc")]
 use alloc::{
     borrow::{Cow, ToOwned},
     boxed::Box,
+    ffi::CString,
+    rc::Rc,
     string::String,
     vec::Vec,
 };
 
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+
+#[cfg(feature = "alloc")]
+use core::ffi::CStr;
+
 #[cfg(feature = "collections")]
 use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
 
@@ -208,6 +652,73 @@ use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
 /// Re-export for the custom derive macro `ToStatic`.
 pub use bounded_static_derive::ToStatic;
 
+#[cfg(feature = "derive")]
+/// Re-export for the custom derive macro `ToBounded`.
+pub use bounded_static_derive::ToBounded;
+
+#[cfg(feature = "derive")]
+/// Re-export for the custom derive macro `ToStaticAs`.
+pub use bounded_static_derive::ToStaticAs;
+
+#[cfg(feature = "derive")]
+/// Re-export for the custom derive macro `ToSendStatic`.
+pub use bounded_static_derive::ToSendStatic;
+
+#[cfg(feature = "yoke")]
+pub use attach::Attached;
+pub use bounded::{BoundedAlloc, Heap, ToBounded};
+pub use budget::Budget;
+pub use converter::StaticConverter;
+pub use cost::{CostReport, StaticCost};
+pub use error::TryStaticError;
+#[cfg(feature = "intern")]
+pub use intern::ToInternedStatic;
+pub use iter::StaticIterator;
+#[cfg(feature = "alloc")]
+pub use leak::LeakStatic;
+#[cfg(feature = "std")]
+pub use leak::{StaticArena, StaticRef};
+pub use repr::{StaticRepr, ToStaticAs};
+pub use send::ToSendStatic;
+#[cfg(feature = "alloc")]
+pub use static_cow::StaticCow;
+
+use error::{ErrorKind, PathSegment};
+
+/// Support for the code generated by the derive macros and the macros of this crate, this is not part of the public
+/// API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::fast_path::{IntoStaticFastPath, ToStaticFastPath};
+
+    /// A guard which records that a value of a derived type is being converted until it is dropped.
+    #[must_use]
+    pub struct TypeGuard {
+        #[cfg(feature = "instrument")]
+        _guard: crate::instrument::TypeGuard,
+    }
+
+    /// Record that a value of the given derived type is being converted, if the `instrument` feature is enabled.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn enter(type_name: &'static str) -> TypeGuard {
+        let _ = type_name;
+        TypeGuard {
+            #[cfg(feature = "instrument")]
+            _guard: crate::instrument::enter(type_name),
+        }
+    }
+
+    /// Record an allocation of `bytes` with the `instrument` collector, if enabled.
+    #[inline]
+    pub fn record_alloc(bytes: usize) {
+        #[cfg(feature = "instrument")]
+        if bytes > 0 {
+            crate::instrument::record_alloc(bytes);
+        }
+        let _ = bytes;
+    }
+}
+
 /// A trait for converting `&T` to an owned `T` such that `T: 'static`.
 ///
 /// See the module level documentation for details.
@@ -218,6 +729,79 @@ pub trait ToBoundedStatic {
     /// Convert an `&T` to an owned `T` such that `T: 'static`.
     #[must_use = "converting is often expensive and is not expected to have side effects"]
     fn to_static(&self) -> Self::Static;
+
+    /// Convert an `&T` to an owned `T` such that `T: 'static`, charging all heap allocations to the given [`Budget`].
+    ///
+    /// The default implementation charges nothing and delegates to [`to_static`](ToBoundedStatic::to_static), which
+    /// is correct for types which do not allocate.
+    ///
+    /// **Types which allocate, or which contain values that may allocate, must override this method**, otherwise
+    /// they are not charged to the budget and pass [`to_static_with_limit`](ToBoundedStatic::to_static_with_limit)
+    /// however much they allocate.
+    ///
+    /// This method also backs the blanket [`TryToBoundedStatic`] impl, and so impls return an error rather than
+    /// panic if a value cannot be converted, such as a `RefCell` which is mutably borrowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`ErrorKind::LimitExceeded`] if the budget is exceeded, or of another kind if a value
+    /// cannot be converted.
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let _ = budget;
+        Ok(self.to_static())
+    }
+
+    /// Convert an `&T` to an owned `T` such that `T: 'static`, allocating at most `max_bytes` heap bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`ErrorKind::LimitExceeded`] if more than `max_bytes` would be allocated.
+    fn to_static_with_limit(&self, max_bytes: usize) -> Result<Self::Static, TryStaticError> {
+        self.to_static_with_budget(&mut Budget::new(max_bytes))
+    }
+
+    /// Add the cost of converting this value with [`to_static`](ToBoundedStatic::to_static) to the given
+    /// [`CostReport`].
+    ///
+    /// The default implementation adds nothing, which is correct for types which hold no heap data.  Types which hold
+    /// heap data, or which contain values that may, should override this method.  Use
+    /// [`static_cost`](StaticCost::static_cost) to create a report for a value.
+    fn add_static_cost(&self, report: &mut CostReport) {
+        let _ = report;
+    }
+
+    /// Convert an `&T` into an existing owned `T` such that `T: 'static`, reusing its allocations where possible.
+    ///
+    /// This is the [`clone_from`](Clone::clone_from) counterpart of [`to_static`](ToBoundedStatic::to_static).  The
+    /// default implementation assigns the result of `to_static` to `dst`.  The impls for `String`, `Cow`, `Vec`,
+    /// `VecDeque`, `BinaryHeap`, `HashMap` and `HashSet`, and for types which contain them, reuse the capacity of
+    /// `dst`.
+    fn to_static_into(&self, dst: &mut Self::Static) {
+        *dst = self.to_static();
+    }
+
+    /// Convert an `&T` to an owned `T` such that `T: 'static`, preserving the sharing of the `Rc` and `Arc` pointers
+    /// it holds with the given [`StaticConverter`].
+    ///
+    /// The default implementation delegates to [`to_static`](ToBoundedStatic::to_static), which is correct for types
+    /// which do not hold `Rc` or `Arc` pointers.  Types which hold them, or which contain values that may, should
+    /// override this method.
+    fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+        let _ = converter;
+        self.to_static()
+    }
+
+    /// Perform the given conversion, taking the fast path which matches how values of this type are converted.
+    ///
+    /// This is used by the collection impls and is not part of the public API.  Types for which `to_static` is a
+    /// copy or a clone override it to take the matching fast path.
+    #[doc(hidden)]
+    fn to_static_fast_path<F>(f: F) -> F::Output
+    where
+        F: __private::ToStaticFastPath<Self>,
+    {
+        f.convert()
+    }
 }
 
 /// A trait for converting an owned `T` into an owned `T` such that `T: 'static`.
@@ -230,8 +814,188 @@ pub trait IntoBoundedStatic {
     /// Convert an owned `T` into an owned `T` such that `T: 'static`.
     #[must_use = "converting is often expensive and is not expected to have side effects"]
     fn into_static(self) -> Self::Static;
+
+    /// Convert an owned `T` into an owned `T` such that `T: 'static`, charging all heap allocations to the given
+    /// [`Budget`].
+    ///
+    /// The default implementation charges nothing and delegates to [`into_static`](IntoBoundedStatic::into_static),
+    /// which is correct for types which do not allocate.
+    ///
+    /// **Types which allocate, or which contain values that may allocate, must override this method**, otherwise
+    /// they are not charged to the budget and pass
+    /// [`into_static_with_limit`](IntoBoundedStatic::into_static_with_limit) however much they allocate.
+    ///
+    /// This method also backs the blanket [`TryIntoBoundedStatic`] impl, and so impls return an error rather than
+    /// panic if a value cannot be converted, such as a `Mutex` which is poisoned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`ErrorKind::LimitExceeded`] if the budget is exceeded, or of another kind if a value
+    /// cannot be converted.
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError>
+    where
+        Self: Sized,
+    {
+        let _ = budget;
+        Ok(self.into_static())
+    }
+
+    /// Convert an owned `T` into an owned `T` such that `T: 'static`, allocating at most `max_bytes` heap bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`ErrorKind::LimitExceeded`] if more than `max_bytes` would be allocated.
+    fn into_static_with_limit(self, max_bytes: usize) -> Result<Self::Static, TryStaticError>
+    where
+        Self: Sized,
+    {
+        self.into_static_with_budget(&mut Budget::new(max_bytes))
+    }
+
+    /// Perform the given conversion, taking the fast path which matches how values of this type are converted.
+    ///
+    /// This is used by the collection impls and is not part of the public API.  Types for which `into_static` is a
+    /// no-op override it to take the identity fast path.
+    #[doc(hidden)]
+    fn into_static_fast_path<F>(f: F) -> F::Output
+    where
+        F: __private::IntoStaticFastPath<Self>,
+    {
+        f.convert()
+    }
+}
+
+/// A trait for fallibly converting `&T` to an owned `T` such that `T: 'static`.
+///
+/// This is the fallible counterpart of [`ToBoundedStatic`].  See the module level documentation for details.
+///
+/// This trait is blanket implemented for every type which implements [`ToBoundedStatic`], with an `Error` type of
+/// [`TryStaticError`], by converting with [`to_static_with_budget`](ToBoundedStatic::to_static_with_budget) and an
+/// [`unlimited`](Budget::unlimited) budget.  Other types may implement it directly.
+pub trait TryToBoundedStatic {
+    /// The target type is bounded by the `'static` lifetime.
+    type Static: 'static;
+
+    /// The type returned in the event of a conversion error.
+    type Error;
+
+    /// Try to convert an `&T` to an owned `T` such that `T: 'static`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be converted.
+    fn try_to_static(&self) -> Result<Self::Static, Self::Error>;
+}
+
+/// A trait for fallibly converting an owned `T` into an owned `T` such that `T: 'static`.
+///
+/// This is the fallible counterpart of [`IntoBoundedStatic`].  See the module level documentation for details.
+///
+/// This trait is blanket implemented for every type which implements [`IntoBoundedStatic`], with an `Error` type of
+/// [`TryStaticError`], by converting with [`into_static_with_budget`](IntoBoundedStatic::into_static_with_budget) and
+/// an [`unlimited`](Budget::unlimited) budget.  Other types may implement it directly.
+pub trait TryIntoBoundedStatic {
+    /// The target type is bounded by the `'static` lifetime.
+    type Static: 'static;
+
+    /// The type returned in the event of a conversion error.
+    type Error;
+
+    /// Try to convert an owned `T` into an owned `T` such that `T: 'static`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be converted.
+    fn try_into_static(self) -> Result<Self::Static, Self::Error>;
+}
+
+/// Blanket [`TryToBoundedStatic`] impl for every type which implements [`ToBoundedStatic`].
+impl<T> TryToBoundedStatic for T
+where
+    T: ToBoundedStatic + ?Sized,
+{
+    type Static = T::Static;
+    type Error = TryStaticError;
+
+    fn try_to_static(&self) -> Result<Self::Static, Self::Error> {
+        self.to_static_with_budget(&mut Budget::unlimited())
+    }
+}
+
+/// Blanket [`TryIntoBoundedStatic`] impl for every type which implements [`IntoBoundedStatic`].
+impl<T> TryIntoBoundedStatic for T
+where
+    T: IntoBoundedStatic,
+{
+    type Static = T::Static;
+    type Error = TryStaticError;
+
+    fn try_into_static(self) -> Result<Self::Static, Self::Error> {
+        self.into_static_with_budget(&mut Budget::unlimited())
+    }
+}
+
+/// A trait for the borrowed types held by a [`StaticCow`], which copies a value to its owned form under a [`Budget`].
+///
+/// The `StaticCow` impls use this trait to charge the owned copy of a borrowed value to a `Budget`.  It is implemented
+/// for `str` and `[T]`, which reserve the owned copy with `try_reserve_exact` and charge the elements of a slice as a
+/// `Vec<T>` does, and for `CStr`, `Path` and `OsStr`, which do not provide a stable fallible allocation API and so are
+/// copied infallibly.  Other `ToOwned` types may implement it with an empty impl block to use the default.
+#[cfg(feature = "alloc")]
+pub trait ToOwnedStatic: ToOwned {
+    /// Copy a borrowed value to its owned form, charging the heap allocations of the copy to the given [`Budget`]
+    /// before making them.
+    ///
+    /// The default implementation charges the size of the borrowed value and delegates to `to_owned()`, which is
+    /// correct for types which hold no heap data of their own, such as `CStr`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`ErrorKind::LimitExceeded`] if the budget is exceeded, or of kind
+    /// [`ErrorKind::Alloc`] if the owned copy cannot be allocated.
+    fn to_owned_with_budget(&self, budget: &mut Budget) -> Result<Self::Owned, TryStaticError> {
+        budget.charge(core::mem::size_of_val(self))?;
+        record_alloc(core::mem::size_of_val(self));
+        Ok(self.to_owned())
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// [`ToOwnedStatic`] impl for `str`, which reserves the `String` with `try_reserve_exact`.
+impl ToOwnedStatic for str {
+    fn to_owned_with_budget(&self, budget: &mut Budget) -> Result<Self::Owned, TryStaticError> {
+        budget.charge(self.len())?;
+        record_alloc(self.len());
+        let mut string = String::new();
+        string.try_reserve_exact(self.len())?;
+        string.push_str(self);
+        Ok(string)
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// [`ToOwnedStatic`] impl for `[T]`, which reserves the `Vec` with `try_reserve_exact` and clones each element.
+///
+/// The elements are charged to a [`Budget`] with [`to_static_with_budget`](ToBoundedStatic::to_static_with_budget),
+/// as the elements of a `Vec<T>` are, and so must convert to themselves.
+impl<T> ToOwnedStatic for [T]
+where
+    T: Clone + ToBoundedStatic<Static = T>,
+{
+    fn to_owned_with_budget(&self, budget: &mut Budget) -> Result<Self::Owned, TryStaticError> {
+        extend_with_budget(try_vec_with_capacity, self.iter(), budget)
+    }
 }
 
+#[cfg(feature = "alloc")]
+impl ToOwnedStatic for CStr {}
+
+#[cfg(feature = "std")]
+impl ToOwnedStatic for std::path::Path {}
+
+#[cfg(feature = "std")]
+impl ToOwnedStatic for std::ffi::OsStr {}
+
 /// No-op [`ToBoundedStatic`] impl for converting `&'static str` to `&'static str`.
 impl ToBoundedStatic for &'static str {
     type Static = &'static str;
@@ -239,6 +1003,8 @@ impl ToBoundedStatic for &'static str {
     fn to_static(&self) -> Self::Static {
         self
     }
+
+    crate::__to_static_fast_path!(copy);
 }
 
 /// No-op [`IntoBoundedStatic`] impl for converting `&'static str` into `&'static str`.
@@ -248,64 +1014,66 @@ impl IntoBoundedStatic for &'static str {
     fn into_static(self) -> Self::Static {
         self
     }
-}
-
-/// No-op [`ToBoundedStatic`] and [`IntoBoundedStatic`] impls for `Copy` types.
-macro_rules! make_copy_impl {
-    ($id:ty) => {
-        /// No-op [`ToBoundedStatic`] impl for this `Copy` type.
-        impl ToBoundedStatic for $id {
-            type Static = Self;
-
-            fn to_static(&self) -> Self::Static {
-                *self
-            }
-        }
-        /// No-op [`IntoBoundedStatic`] impl for this `Copy` type.
-        impl IntoBoundedStatic for $id {
-            type Static = Self;
 
-            fn into_static(self) -> Self::Static {
-                self
-            }
-        }
-    };
+    crate::__into_static_fast_path!();
 }
 
-make_copy_impl!(bool);
-make_copy_impl!(char);
-make_copy_impl!(f32);
-make_copy_impl!(f64);
-make_copy_impl!(usize);
-make_copy_impl!(u8);
-make_copy_impl!(u16);
-make_copy_impl!(u32);
-make_copy_impl!(u64);
-make_copy_impl!(u128);
-make_copy_impl!(isize);
-make_copy_impl!(i8);
-make_copy_impl!(i16);
-make_copy_impl!(i32);
-make_copy_impl!(i64);
-make_copy_impl!(i128);
-make_copy_impl!(NonZeroUsize);
-make_copy_impl!(NonZeroU8);
-make_copy_impl!(NonZeroU16);
-make_copy_impl!(NonZeroU32);
-make_copy_impl!(NonZeroU64);
-make_copy_impl!(NonZeroU128);
-make_copy_impl!(NonZeroIsize);
-make_copy_impl!(NonZeroI8);
-make_copy_impl!(NonZeroI16);
-make_copy_impl!(NonZeroI32);
-make_copy_impl!(NonZeroI64);
-make_copy_impl!(NonZeroI128);
+impl_copy!(send bool);
+impl_copy!(send char);
+impl_copy!(send f32);
+impl_copy!(send f64);
+impl_copy!(send usize);
+impl_copy!(send u8);
+impl_copy!(send u16);
+impl_copy!(send u32);
+impl_copy!(send u64);
+impl_copy!(send u128);
+impl_copy!(send isize);
+impl_copy!(send i8);
+impl_copy!(send i16);
+impl_copy!(send i32);
+impl_copy!(send i64);
+impl_copy!(send i128);
+impl_copy!(send NonZeroUsize);
+impl_copy!(send NonZeroU8);
+impl_copy!(send NonZeroU16);
+impl_copy!(send NonZeroU32);
+impl_copy!(send NonZeroU64);
+impl_copy!(send NonZeroU128);
+impl_copy!(send NonZeroIsize);
+impl_copy!(send NonZeroI8);
+impl_copy!(send NonZeroI16);
+impl_copy!(send NonZeroI32);
+impl_copy!(send NonZeroI64);
+impl_copy!(send NonZeroI128);
+impl_copy!(send Ordering);
+impl_copy!(send RangeFull);
+impl_copy!(send PhantomPinned);
+impl_copy!(send Duration);
+#[cfg(feature = "std")]
+impl_copy!(send std::time::Instant);
+#[cfg(feature = "std")]
+impl_copy!(send std::time::SystemTime);
+#[cfg(feature = "std")]
+impl_copy!(send std::net::IpAddr);
+#[cfg(feature = "std")]
+impl_copy!(send std::net::Ipv4Addr);
+#[cfg(feature = "std")]
+impl_copy!(send std::net::Ipv6Addr);
+#[cfg(feature = "std")]
+impl_copy!(send std::net::SocketAddr);
+#[cfg(feature = "std")]
+impl_copy!(send std::net::SocketAddrV4);
+#[cfg(feature = "std")]
+impl_copy!(send std::net::SocketAddrV6);
 
 /// No-op [`ToBoundedStatic`] impl for unit type `()`.
 impl ToBoundedStatic for () {
     type Static = ();
 
     fn to_static(&self) -> Self::Static {}
+
+    crate::__to_static_fast_path!(copy);
 }
 
 /// No-op [`IntoBoundedStatic`] impl for unit type `()`.
@@ -313,6 +1081,8 @@ impl IntoBoundedStatic for () {
     type Static = ();
 
     fn into_static(self) -> Self::Static {}
+
+    crate::__into_static_fast_path!();
 }
 
 /// Blanket [`ToBoundedStatic`] impl for converting `Option<T>` to `Option<T>: 'static`.
@@ -325,18 +1095,46 @@ where
     fn to_static(&self) -> Self::Static {
         self.as_ref().map(ToBoundedStatic::to_static)
     }
-}
 
-/// Blanket [`IntoBoundedStatic`] impl for converting `Option<T>` into `Option<T>: 'static`.
-impl<T> IntoBoundedStatic for Option<T>
-where
-    T: IntoBoundedStatic,
-{
-    type Static = Option<T::Static>;
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        self.as_ref()
+            .map(|value| value.to_static_with_budget(budget))
+            .transpose()
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        if let Some(value) = self {
+            value.add_static_cost(report);
+        }
+    }
+
+    fn to_static_into(&self, dst: &mut Self::Static) {
+        match (self, dst) {
+            (Some(value), Some(dst)) => value.to_static_into(dst),
+            (_, dst) => *dst = self.to_static(),
+        }
+    }
+
+    fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+        self.as_ref().map(|value| value.to_static_with(converter))
+    }
+}
+
+/// Blanket [`IntoBoundedStatic`] impl for converting `Option<T>` into `Option<T>: 'static`.
+impl<T> IntoBoundedStatic for Option<T>
+where
+    T: IntoBoundedStatic,
+{
+    type Static = Option<T::Static>;
 
     fn into_static(self) -> Self::Static {
         self.map(IntoBoundedStatic::into_static)
     }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        self.map(|value| value.into_static_with_budget(budget))
+            .transpose()
+    }
 }
 
 /// Blanket [`ToBoundedStatic`] impl for converting `Result<T, E>` to `Result<T, E>: 'static`.
@@ -353,6 +1151,41 @@ where
             Err(err) => Err(err.to_static()),
         }
     }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        match self {
+            Ok(value) => value
+                .to_static_with_budget(budget)
+                .map(Ok)
+                .map_err(|err| err.at(PathSegment::Variant("Ok"))),
+            Err(err) => err
+                .to_static_with_budget(budget)
+                .map(Err)
+                .map_err(|err| err.at(PathSegment::Variant("Err"))),
+        }
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        match self {
+            Ok(value) => value.add_static_cost(report),
+            Err(err) => err.add_static_cost(report),
+        }
+    }
+
+    fn to_static_into(&self, dst: &mut Self::Static) {
+        match (self, dst) {
+            (Ok(value), Ok(dst)) => value.to_static_into(dst),
+            (Err(err), Err(dst)) => err.to_static_into(dst),
+            (_, dst) => *dst = self.to_static(),
+        }
+    }
+
+    fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+        match self {
+            Ok(value) => Ok(value.to_static_with(converter)),
+            Err(err) => Err(err.to_static_with(converter)),
+        }
+    }
 }
 
 /// Blanket [`IntoBoundedStatic`] impl for converting `Result<T, E>` into `Result<T, E>: 'static`.
@@ -369,6 +1202,19 @@ where
             Err(err) => Err(err.into_static()),
         }
     }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        match self {
+            Ok(value) => value
+                .into_static_with_budget(budget)
+                .map(Ok)
+                .map_err(|err| err.at(PathSegment::Variant("Ok"))),
+            Err(err) => err
+                .into_static_with_budget(budget)
+                .map(Err)
+                .map_err(|err| err.at(PathSegment::Variant("Err"))),
+        }
+    }
 }
 
 /// Blanket [`ToBoundedStatic`] impl for converting `[T; const N: usize]` to `[T; const N: usize]: 'static`.
@@ -381,6 +1227,30 @@ where
     fn to_static(&self) -> Self::Static {
         core::array::from_fn(|i| self[i].to_static())
     }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        try_array_from_fn(|i| {
+            self[i]
+                .to_static_with_budget(budget)
+                .map_err(|err| err.at(PathSegment::Index(i)))
+        })
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        for value in self {
+            value.add_static_cost(report);
+        }
+    }
+
+    fn to_static_into(&self, dst: &mut Self::Static) {
+        for (value, dst) in self.iter().zip(dst) {
+            value.to_static_into(dst);
+        }
+    }
+
+    fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+        core::array::from_fn(|i| self[i].to_static_with(converter))
+    }
 }
 
 /// Blanket [`IntoBoundedStatic`] impl for converting `[T; const N: usize]` into `[T; const N: usize]: 'static`.
@@ -393,6 +1263,17 @@ where
     fn into_static(self) -> Self::Static {
         self.map(IntoBoundedStatic::into_static)
     }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let mut values = self.into_iter();
+        try_array_from_fn(|i| {
+            values
+                .next()
+                .expect("array has N values")
+                .into_static_with_budget(budget)
+                .map_err(|err| err.at(PathSegment::Index(i)))
+        })
+    }
 }
 
 /// Blanket [`ToBoundedStatic`] impl for converting tuples `(T1, T2, ...)` to `(T1, T2, ..): 'static`.
@@ -416,6 +1297,30 @@ macro_rules! tuple_to_static {
                 let ($(ref $name,)+) = *self;
                 ($($name.to_static(),)+)
             }
+            #[allow(non_snake_case, unused_assignments)]
+            fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+                let ($(ref $name,)+) = *self;
+                let mut index = 0;
+                Ok(($({
+                    let value = $name
+                        .to_static_with_budget(budget)
+                        .map_err(|err| err.at(PathSegment::Index(index)))?;
+                    index += 1;
+                    value
+                },)+))
+            }
+
+            #[allow(non_snake_case)]
+            fn add_static_cost(&self, report: &mut CostReport) {
+                let ($(ref $name,)+) = *self;
+                $($name.add_static_cost(report);)+
+            }
+
+            #[allow(non_snake_case)]
+            fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+                let ($(ref $name,)+) = *self;
+                ($($name.to_static_with(converter),)+)
+            }
         }
         tuple_to_static! {@peel $($name,)+ }
     };
@@ -443,6 +1348,18 @@ macro_rules! tuple_into_static {
                 let ($($name,)+) = self;
                 ($($name.into_static(),)+)
             }
+            #[allow(non_snake_case, unused_assignments)]
+            fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+                let ($($name,)+) = self;
+                let mut index = 0;
+                Ok(($({
+                    let value = $name
+                        .into_static_with_budget(budget)
+                        .map_err(|err| err.at(PathSegment::Index(index)))?;
+                    index += 1;
+                    value
+                },)+))
+            }
         }
         tuple_into_static! {@peel $($name,)+ }
     };
@@ -452,585 +1369,5194 @@ macro_rules! tuple_into_static {
 tuple_to_static! { T11, T10, T9, T8, T7, T6, T5, T4, T3, T2, T1, T0, }
 tuple_into_static! { T11, T10, T9, T8, T7, T6, T5, T4, T3, T2, T1, T0, }
 
-#[cfg(feature = "alloc")]
-/// Blanket [`ToBoundedStatic`] impl for converting `Cow<'a, T: ?Sized>` to `Cow<'static, T: ?Sized>`.
-impl<T> ToBoundedStatic for Cow<'_, T>
+/// Blanket [`ToBoundedStatic`] impl for converting `Cell<T>` to `Cell<T>: 'static`.
+impl<T> ToBoundedStatic for Cell<T>
 where
-    T: 'static + ToOwned + ?Sized,
+    T: Copy + ToBoundedStatic,
 {
-    type Static = Cow<'static, T>;
+    type Static = Cell<T::Static>;
 
     fn to_static(&self) -> Self::Static {
-        Cow::Owned(self.clone().into_owned())
+        Cell::new(self.get().to_static())
     }
 }
 
-#[cfg(feature = "alloc")]
-/// Blanket [`IntoBoundedStatic`] impl for converting `Cow<'a, T: ?Sized>` into `Cow<'static, T: ?Sized>`.
-impl<T> IntoBoundedStatic for Cow<'_, T>
+/// Blanket [`IntoBoundedStatic`] impl for converting `Cell<T>` into `Cell<T>: 'static`.
+impl<T> IntoBoundedStatic for Cell<T>
 where
-    T: 'static + ToOwned + ?Sized,
+    T: IntoBoundedStatic,
 {
-    type Static = Cow<'static, T>;
+    type Static = Cell<T::Static>;
 
     fn into_static(self) -> Self::Static {
-        Cow::Owned(self.into_owned())
+        Cell::new(self.into_inner().into_static())
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        self.into_inner()
+            .into_static_with_budget(budget)
+            .map(Cell::new)
     }
 }
 
-#[cfg(feature = "alloc")]
-/// [`ToBoundedStatic`] impl for `String`.
-impl ToBoundedStatic for String {
-    type Static = Self;
+/// Blanket [`ToBoundedStatic`] impl for converting `RefCell<T>` to `RefCell<T>: 'static`.
+///
+/// The value is immutably borrowed for the duration of the conversion.
+///
+/// # Panics
+///
+/// `to_static` panics if the value is currently mutably borrowed, whereas `to_static_with_budget` returns an error of
+/// kind [`ErrorKind::Borrowed`].
+impl<T> ToBoundedStatic for RefCell<T>
+where
+    T: ToBoundedStatic,
+{
+    type Static = RefCell<T::Static>;
 
     fn to_static(&self) -> Self::Static {
-        self.clone()
+        RefCell::new(self.borrow().to_static())
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let value = self
+            .try_borrow()
+            .map_err(|_| TryStaticError::new(ErrorKind::Borrowed))?;
+        value.to_static_with_budget(budget).map(RefCell::new)
+    }
+
+    /// The cost of a mutably borrowed value is not reported.
+    fn add_static_cost(&self, report: &mut CostReport) {
+        if let Ok(value) = self.try_borrow() {
+            value.add_static_cost(report);
+        }
+    }
+
+    fn to_static_into(&self, dst: &mut Self::Static) {
+        self.borrow().to_static_into(dst.get_mut());
     }
 }
 
-#[cfg(feature = "alloc")]
-/// No-op [`IntoBoundedStatic`] impl for `String`.
-impl IntoBoundedStatic for String {
-    type Static = Self;
+/// Blanket [`IntoBoundedStatic`] impl for converting `RefCell<T>` into `RefCell<T>: 'static`.
+impl<T> IntoBoundedStatic for RefCell<T>
+where
+    T: IntoBoundedStatic,
+{
+    type Static = RefCell<T::Static>;
 
     fn into_static(self) -> Self::Static {
-        self
+        RefCell::new(self.into_inner().into_static())
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        self.into_inner()
+            .into_static_with_budget(budget)
+            .map(RefCell::new)
     }
 }
 
-#[cfg(feature = "alloc")]
-/// Blanket [`ToBoundedStatic`] impl for converting `Vec<T>` to `Vec<T>: 'static`.
-impl<T> ToBoundedStatic for Vec<T>
+/// Blanket [`ToBoundedStatic`] impl for converting `OnceCell<T>` to `OnceCell<T>: 'static`.
+impl<T> ToBoundedStatic for OnceCell<T>
 where
     T: ToBoundedStatic,
 {
-    type Static = Vec<T::Static>;
+    type Static = OnceCell<T::Static>;
 
     fn to_static(&self) -> Self::Static {
-        self.iter().map(ToBoundedStatic::to_static).collect()
+        self.get()
+            .map_or_else(OnceCell::new, |value| OnceCell::from(value.to_static()))
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let Some(value) = self.get() else {
+            return Ok(OnceCell::new());
+        };
+        value.to_static_with_budget(budget).map(OnceCell::from)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        if let Some(value) = self.get() {
+            value.add_static_cost(report);
+        }
+    }
+
+    fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+        self.get().map_or_else(OnceCell::new, |value| {
+            OnceCell::from(value.to_static_with(converter))
+        })
     }
 }
 
-#[cfg(feature = "alloc")]
-/// Blanket [`IntoBoundedStatic`] impl for converting `Vec<T>` into `Vec<T>: 'static`.
-impl<T> IntoBoundedStatic for Vec<T>
+/// Blanket [`IntoBoundedStatic`] impl for converting `OnceCell<T>` into `OnceCell<T>: 'static`.
+impl<T> IntoBoundedStatic for OnceCell<T>
 where
     T: IntoBoundedStatic,
 {
-    type Static = Vec<T::Static>;
+    type Static = OnceCell<T::Static>;
 
     fn into_static(self) -> Self::Static {
-        self.into_iter()
-            .map(IntoBoundedStatic::into_static)
-            .collect()
+        self.into_inner()
+            .map_or_else(OnceCell::new, |value| OnceCell::from(value.into_static()))
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let Some(value) = self.into_inner() else {
+            return Ok(OnceCell::new());
+        };
+        value.into_static_with_budget(budget).map(OnceCell::from)
     }
 }
 
-#[cfg(feature = "collections")]
-/// Blanket [`ToBoundedStatic`] impl for converting `BinaryHeap<T>` into `BinaryHeap<T>: 'static`.
-impl<T> ToBoundedStatic for BinaryHeap<T>
+/// Blanket [`ToBoundedStatic`] impl for converting `Range<T>` to `Range<T>: 'static`.
+impl<T> ToBoundedStatic for Range<T>
 where
     T: ToBoundedStatic,
-    T::Static: Ord,
 {
-    type Static = BinaryHeap<T::Static>;
+    type Static = Range<T::Static>;
 
     fn to_static(&self) -> Self::Static {
-        self.iter().map(ToBoundedStatic::to_static).collect()
+        self.start.to_static()..self.end.to_static()
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let start = self
+            .start
+            .to_static_with_budget(budget)
+            .map_err(|err| err.at(PathSegment::Field("start")))?;
+        let end = self
+            .end
+            .to_static_with_budget(budget)
+            .map_err(|err| err.at(PathSegment::Field("end")))?;
+        Ok(start..end)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        self.start.add_static_cost(report);
+        self.end.add_static_cost(report);
     }
 }
 
-#[cfg(feature = "collections")]
-/// Blanket [`IntoBoundedStatic`] impl for converting `BinaryHeap<T>` into `BinaryHeap<T>: 'static`.
-impl<T> IntoBoundedStatic for BinaryHeap<T>
+/// Blanket [`IntoBoundedStatic`] impl for converting `Range<T>` into `Range<T>: 'static`.
+impl<T> IntoBoundedStatic for Range<T>
 where
     T: IntoBoundedStatic,
-    T::Static: Ord,
 {
-    type Static = BinaryHeap<T::Static>;
+    type Static = Range<T::Static>;
 
     fn into_static(self) -> Self::Static {
-        self.into_iter()
-            .map(IntoBoundedStatic::into_static)
-            .collect()
+        self.start.into_static()..self.end.into_static()
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let start = self
+            .start
+            .into_static_with_budget(budget)
+            .map_err(|err| err.at(PathSegment::Field("start")))?;
+        let end = self
+            .end
+            .into_static_with_budget(budget)
+            .map_err(|err| err.at(PathSegment::Field("end")))?;
+        Ok(start..end)
     }
 }
 
-#[cfg(feature = "collections")]
-/// Blanket [`ToBoundedStatic`] impl for converting `BTreeMap<K, V>` into `BTreeMap<K, V>: 'static`.
-impl<K, V> ToBoundedStatic for BTreeMap<K, V>
+/// Blanket [`ToBoundedStatic`] impl for converting `RangeFrom<T>` to `RangeFrom<T>: 'static`.
+impl<T> ToBoundedStatic for RangeFrom<T>
 where
-    K: ToBoundedStatic,
-    K::Static: Ord,
-    V: ToBoundedStatic,
+    T: ToBoundedStatic,
 {
-    type Static = BTreeMap<K::Static, V::Static>;
+    type Static = RangeFrom<T::Static>;
 
     fn to_static(&self) -> Self::Static {
-        self.iter()
-            .map(|(k, v)| (k.to_static(), v.to_static()))
-            .collect()
+        self.start.to_static()..
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        Ok(self.start.to_static_with_budget(budget)?..)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        self.start.add_static_cost(report);
     }
 }
 
-#[cfg(feature = "collections")]
-/// Blanket [`IntoBoundedStatic`] impl for converting `BTreeMap<K, V>` into `BTreeMap<K, V>: 'static`.
-impl<K, V> IntoBoundedStatic for BTreeMap<K, V>
+/// Blanket [`IntoBoundedStatic`] impl for converting `RangeFrom<T>` into `RangeFrom<T>: 'static`.
+impl<T> IntoBoundedStatic for RangeFrom<T>
 where
-    K: IntoBoundedStatic,
-    K::Static: Ord,
-    V: IntoBoundedStatic,
+    T: IntoBoundedStatic,
 {
-    type Static = BTreeMap<K::Static, V::Static>;
+    type Static = RangeFrom<T::Static>;
 
     fn into_static(self) -> Self::Static {
-        self.into_iter()
-            .map(|(k, v)| (k.into_static(), v.into_static()))
-            .collect()
+        self.start.into_static()..
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        Ok(self.start.into_static_with_budget(budget)?..)
     }
 }
 
-#[cfg(feature = "collections")]
-/// Blanket [`ToBoundedStatic`] impl for converting `BTreeSet<T>` into `BTreeSet<T>: 'static`.
-impl<T> ToBoundedStatic for BTreeSet<T>
+/// Blanket [`ToBoundedStatic`] impl for converting `RangeTo<T>` to `RangeTo<T>: 'static`.
+impl<T> ToBoundedStatic for RangeTo<T>
 where
     T: ToBoundedStatic,
-    T::Static: Ord,
 {
-    type Static = BTreeSet<T::Static>;
+    type Static = RangeTo<T::Static>;
 
     fn to_static(&self) -> Self::Static {
-        self.iter().map(ToBoundedStatic::to_static).collect()
+        ..self.end.to_static()
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        Ok(..self.end.to_static_with_budget(budget)?)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        self.end.add_static_cost(report);
     }
 }
 
-#[cfg(feature = "collections")]
-/// Blanket [`IntoBoundedStatic`] impl for converting `BTreeSet<T>` into `BTreeSet<T>: 'static`.
-impl<T> IntoBoundedStatic for BTreeSet<T>
+/// Blanket [`IntoBoundedStatic`] impl for converting `RangeTo<T>` into `RangeTo<T>: 'static`.
+impl<T> IntoBoundedStatic for RangeTo<T>
 where
     T: IntoBoundedStatic,
-    T::Static: Ord,
 {
-    type Static = BTreeSet<T::Static>;
+    type Static = RangeTo<T::Static>;
 
     fn into_static(self) -> Self::Static {
-        self.into_iter()
-            .map(IntoBoundedStatic::into_static)
-            .collect()
+        ..self.end.into_static()
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        Ok(..self.end.into_static_with_budget(budget)?)
     }
 }
 
-#[cfg(feature = "collections")]
-/// Blanket [`ToBoundedStatic`] impl for converting `LinkedList<T>` into `LinkedList<T>: 'static`.
-impl<T> ToBoundedStatic for LinkedList<T>
+/// Blanket [`ToBoundedStatic`] impl for converting `RangeInclusive<T>` to `RangeInclusive<T>: 'static`.
+///
+/// Note that the resulting range is not exhausted, even if this range has been exhausted by iteration.
+impl<T> ToBoundedStatic for RangeInclusive<T>
 where
     T: ToBoundedStatic,
 {
-    type Static = LinkedList<T::Static>;
+    type Static = RangeInclusive<T::Static>;
 
     fn to_static(&self) -> Self::Static {
-        self.iter().map(ToBoundedStatic::to_static).collect()
+        self.start().to_static()..=self.end().to_static()
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let start = self
+            .start()
+            .to_static_with_budget(budget)
+            .map_err(|err| err.at(PathSegment::Field("start")))?;
+        let end = self
+            .end()
+            .to_static_with_budget(budget)
+            .map_err(|err| err.at(PathSegment::Field("end")))?;
+        Ok(start..=end)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        self.start().add_static_cost(report);
+        self.end().add_static_cost(report);
     }
 }
 
-#[cfg(feature = "collections")]
-/// Blanket [`IntoBoundedStatic`] impl for converting `LinkedList<T>` into `LinkedList<T>: 'static`.
-impl<T> IntoBoundedStatic for LinkedList<T>
+/// Blanket [`IntoBoundedStatic`] impl for converting `RangeInclusive<T>` into `RangeInclusive<T>: 'static`.
+///
+/// Note that the resulting range is not exhausted, even if this range has been exhausted by iteration.
+impl<T> IntoBoundedStatic for RangeInclusive<T>
 where
     T: IntoBoundedStatic,
 {
-    type Static = LinkedList<T::Static>;
+    type Static = RangeInclusive<T::Static>;
 
     fn into_static(self) -> Self::Static {
-        self.into_iter()
-            .map(IntoBoundedStatic::into_static)
-            .collect()
+        let (start, end) = self.into_inner();
+        start.into_static()..=end.into_static()
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let (start, end) = self.into_inner();
+        let start = start
+            .into_static_with_budget(budget)
+            .map_err(|err| err.at(PathSegment::Field("start")))?;
+        let end = end
+            .into_static_with_budget(budget)
+            .map_err(|err| err.at(PathSegment::Field("end")))?;
+        Ok(start..=end)
     }
 }
 
-#[cfg(feature = "collections")]
-/// Blanket [`ToBoundedStatic`] impl for converting `VecDeque<T>` into `VecDeque<T>: 'static`.
-impl<T> ToBoundedStatic for VecDeque<T>
+/// Blanket [`ToBoundedStatic`] impl for converting `RangeToInclusive<T>` to `RangeToInclusive<T>: 'static`.
+impl<T> ToBoundedStatic for RangeToInclusive<T>
 where
     T: ToBoundedStatic,
 {
-    type Static = VecDeque<T::Static>;
+    type Static = RangeToInclusive<T::Static>;
 
     fn to_static(&self) -> Self::Static {
-        self.iter().map(ToBoundedStatic::to_static).collect()
+        ..=self.end.to_static()
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        Ok(..=self.end.to_static_with_budget(budget)?)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        self.end.add_static_cost(report);
     }
 }
 
-#[cfg(feature = "collections")]
-/// Blanket [`IntoBoundedStatic`] impl for converting `VecDeque<T>` into `VecDeque<T>: 'static`.
-impl<T> IntoBoundedStatic for VecDeque<T>
+/// Blanket [`IntoBoundedStatic`] impl for converting `RangeToInclusive<T>` into `RangeToInclusive<T>: 'static`.
+impl<T> IntoBoundedStatic for RangeToInclusive<T>
 where
     T: IntoBoundedStatic,
 {
-    type Static = VecDeque<T::Static>;
+    type Static = RangeToInclusive<T::Static>;
 
     fn into_static(self) -> Self::Static {
-        self.into_iter()
-            .map(IntoBoundedStatic::into_static)
-            .collect()
+        ..=self.end.into_static()
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        Ok(..=self.end.into_static_with_budget(budget)?)
     }
 }
 
-#[cfg(feature = "alloc")]
-/// Blanket [`ToBoundedStatic`] impl for converting `Box<T>` to `Box<T>: 'static`.
-impl<T> ToBoundedStatic for Box<T>
+/// Blanket [`ToBoundedStatic`] impl for converting `Bound<T>` to `Bound<T>: 'static`.
+impl<T> ToBoundedStatic for Bound<T>
 where
     T: ToBoundedStatic,
 {
-    type Static = Box<T::Static>;
+    type Static = Bound<T::Static>;
 
     fn to_static(&self) -> Self::Static {
-        Box::new(self.as_ref().to_static())
+        match self {
+            Self::Included(value) => Bound::Included(value.to_static()),
+            Self::Excluded(value) => Bound::Excluded(value.to_static()),
+            Self::Unbounded => Bound::Unbounded,
+        }
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        Ok(match self {
+            Self::Included(value) => Bound::Included(value.to_static_with_budget(budget)?),
+            Self::Excluded(value) => Bound::Excluded(value.to_static_with_budget(budget)?),
+            Self::Unbounded => Bound::Unbounded,
+        })
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        if let Self::Included(value) | Self::Excluded(value) = self {
+            value.add_static_cost(report);
+        }
     }
 }
 
-#[cfg(feature = "alloc")]
-/// Blanket [`IntoBoundedStatic`] impl for converting `Box<T>` into `Box<T>: 'static`.
-impl<T> IntoBoundedStatic for Box<T>
+/// Blanket [`IntoBoundedStatic`] impl for converting `Bound<T>` into `Bound<T>: 'static`.
+impl<T> IntoBoundedStatic for Bound<T>
 where
     T: IntoBoundedStatic,
 {
-    type Static = Box<T::Static>;
+    type Static = Bound<T::Static>;
 
     fn into_static(self) -> Self::Static {
-        Box::new((*self).into_static())
+        match self {
+            Self::Included(value) => Bound::Included(value.into_static()),
+            Self::Excluded(value) => Bound::Excluded(value.into_static()),
+            Self::Unbounded => Bound::Unbounded,
+        }
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        Ok(match self {
+            Self::Included(value) => Bound::Included(value.into_static_with_budget(budget)?),
+            Self::Excluded(value) => Bound::Excluded(value.into_static_with_budget(budget)?),
+            Self::Unbounded => Bound::Unbounded,
+        })
     }
 }
 
-#[cfg(feature = "std")]
-/// Blanket [`ToBoundedStatic`] impl for converting `HashMap<K, V>` to `HashMap<K, V>: 'static`.
-impl<K, V, S> ToBoundedStatic for std::collections::HashMap<K, V, S>
+/// Blanket [`ToBoundedStatic`] impl for converting `ControlFlow<B, C>` to `ControlFlow<B, C>: 'static`.
+impl<B, C> ToBoundedStatic for ControlFlow<B, C>
 where
-    K: ToBoundedStatic,
-    K::Static: Eq + std::hash::Hash,
-    V: ToBoundedStatic,
-    S: ToBoundedStatic,
-    S::Static: std::hash::BuildHasher,
+    B: ToBoundedStatic,
+    C: ToBoundedStatic,
 {
-    type Static = std::collections::HashMap<K::Static, V::Static, S::Static>;
+    type Static = ControlFlow<B::Static, C::Static>;
 
     fn to_static(&self) -> Self::Static {
-        let mut map = std::collections::HashMap::with_capacity_and_hasher(
-            self.len(),
-            self.hasher().to_static(),
-        );
-        map.extend(self.iter().map(|(k, v)| (k.to_static(), v.to_static())));
-        map
+        match self {
+            Self::Continue(value) => ControlFlow::Continue(value.to_static()),
+            Self::Break(value) => ControlFlow::Break(value.to_static()),
+        }
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        match self {
+            Self::Continue(value) => value
+                .to_static_with_budget(budget)
+                .map(ControlFlow::Continue)
+                .map_err(|err| err.at(PathSegment::Variant("Continue"))),
+            Self::Break(value) => value
+                .to_static_with_budget(budget)
+                .map(ControlFlow::Break)
+                .map_err(|err| err.at(PathSegment::Variant("Break"))),
+        }
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        match self {
+            Self::Continue(value) => value.add_static_cost(report),
+            Self::Break(value) => value.add_static_cost(report),
+        }
     }
 }
 
-#[cfg(feature = "std")]
-/// Blanket [`IntoBoundedStatic`] impl for for converting `HashMap<K, V>` into `HashMap<K, V>: 'static`.
-impl<K, V, S> IntoBoundedStatic for std::collections::HashMap<K, V, S>
+/// Blanket [`IntoBoundedStatic`] impl for converting `ControlFlow<B, C>` into `ControlFlow<B, C>: 'static`.
+impl<B, C> IntoBoundedStatic for ControlFlow<B, C>
 where
-    K: IntoBoundedStatic,
-    K::Static: Eq + std::hash::Hash,
-    V: IntoBoundedStatic,
-    S: ToBoundedStatic,
-    S::Static: std::hash::BuildHasher,
+    B: IntoBoundedStatic,
+    C: IntoBoundedStatic,
 {
-    type Static = std::collections::HashMap<K::Static, V::Static, S::Static>;
+    type Static = ControlFlow<B::Static, C::Static>;
 
     fn into_static(self) -> Self::Static {
-        let mut map = std::collections::HashMap::with_capacity_and_hasher(
-            self.len(),
-            self.hasher().to_static(),
-        );
-        map.extend(
-            self.into_iter()
-                .map(|(k, v)| (k.into_static(), v.into_static())),
-        );
-        map
+        match self {
+            Self::Continue(value) => ControlFlow::Continue(value.into_static()),
+            Self::Break(value) => ControlFlow::Break(value.into_static()),
+        }
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        match self {
+            Self::Continue(value) => value
+                .into_static_with_budget(budget)
+                .map(ControlFlow::Continue)
+                .map_err(|err| err.at(PathSegment::Variant("Continue"))),
+            Self::Break(value) => value
+                .into_static_with_budget(budget)
+                .map(ControlFlow::Break)
+                .map_err(|err| err.at(PathSegment::Variant("Break"))),
+        }
     }
 }
 
-#[cfg(feature = "std")]
-/// Blanket [`ToBoundedStatic`] impl for converting `HashSet<T>` into `HashSet<T>: 'static`.
-impl<T, S> ToBoundedStatic for std::collections::HashSet<T, S>
+/// Blanket [`ToBoundedStatic`] impl for converting `Reverse<T>` to `Reverse<T>: 'static`.
+impl<T> ToBoundedStatic for Reverse<T>
 where
     T: ToBoundedStatic,
-    T::Static: Eq + std::hash::Hash,
-    S: ToBoundedStatic,
-    S::Static: std::hash::BuildHasher,
 {
-    type Static = std::collections::HashSet<T::Static, S::Static>;
+    type Static = Reverse<T::Static>;
 
     fn to_static(&self) -> Self::Static {
-        let mut set = std::collections::HashSet::with_capacity_and_hasher(
-            self.len(),
-            self.hasher().to_static(),
-        );
-        set.extend(self.iter().map(ToBoundedStatic::to_static));
-        set
+        Reverse(self.0.to_static())
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        self.0.to_static_with_budget(budget).map(Reverse)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        self.0.add_static_cost(report);
     }
 }
 
-#[cfg(feature = "std")]
-/// Blanket [`IntoBoundedStatic`] impl for converting `HashSet<T>` into `HashSet<T>: 'static`.
-impl<T, S> IntoBoundedStatic for std::collections::HashSet<T, S>
+/// Blanket [`IntoBoundedStatic`] impl for converting `Reverse<T>` into `Reverse<T>: 'static`.
+impl<T> IntoBoundedStatic for Reverse<T>
 where
     T: IntoBoundedStatic,
-    T::Static: Eq + std::hash::Hash,
-    S: ToBoundedStatic,
-    S::Static: std::hash::BuildHasher,
 {
-    type Static = std::collections::HashSet<T::Static, S::Static>;
+    type Static = Reverse<T::Static>;
 
     fn into_static(self) -> Self::Static {
-        let mut set = std::collections::HashSet::with_capacity_and_hasher(
-            self.len(),
-            self.hasher().to_static(),
-        );
-        set.extend(self.into_iter().map(IntoBoundedStatic::into_static));
-        set
+        Reverse(self.0.into_static())
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        self.0.into_static_with_budget(budget).map(Reverse)
     }
 }
 
-#[cfg(feature = "std")]
-/// [`ToBoundedStatic`] impl for `std::collections::hash_map::RandomState`.
-impl ToBoundedStatic for std::collections::hash_map::RandomState {
-    type Static = Self;
+/// Blanket [`ToBoundedStatic`] impl for converting `Wrapping<T>` to `Wrapping<T>: 'static`.
+impl<T> ToBoundedStatic for Wrapping<T>
+where
+    T: ToBoundedStatic,
+{
+    type Static = Wrapping<T::Static>;
 
     fn to_static(&self) -> Self::Static {
-        self.clone()
+        Wrapping(self.0.to_static())
     }
-}
 
-/// [`ToBoundedStatic`] impl for `smol_str::SmolStr`.
-#[cfg(feature = "smol_str")]
-impl ToBoundedStatic for smol_str::SmolStr {
-    type Static = Self;
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        self.0.to_static_with_budget(budget).map(Wrapping)
+    }
 
-    fn to_static(&self) -> Self::Static {
-        self.clone()
+    fn add_static_cost(&self, report: &mut CostReport) {
+        self.0.add_static_cost(report);
     }
 }
 
-/// No-op [`IntoBoundedStatic`] impl for `smol_str::SmolStr`.
-#[cfg(feature = "smol_str")]
-impl IntoBoundedStatic for smol_str::SmolStr {
-    type Static = Self;
+/// Blanket [`IntoBoundedStatic`] impl for converting `Wrapping<T>` into `Wrapping<T>: 'static`.
+impl<T> IntoBoundedStatic for Wrapping<T>
+where
+    T: IntoBoundedStatic,
+{
+    type Static = Wrapping<T::Static>;
 
     fn into_static(self) -> Self::Static {
-        self
+        Wrapping(self.0.into_static())
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        self.0.into_static_with_budget(budget).map(Wrapping)
     }
 }
 
-/// [`ToBoundedStatic`] impl for `smallvec::SmallVec`.
-#[cfg(feature = "smallvec")]
-impl<A, T> ToBoundedStatic for smallvec::SmallVec<A>
+/// Blanket [`ToBoundedStatic`] impl for converting `Saturating<T>` to `Saturating<T>: 'static`.
+impl<T> ToBoundedStatic for Saturating<T>
 where
-    A: smallvec::Array<Item = T> + ToBoundedStatic,
     T: ToBoundedStatic,
-    <A as ToBoundedStatic>::Static: smallvec::Array<Item = T::Static>,
 {
-    type Static = smallvec::SmallVec<A::Static>;
+    type Static = Saturating<T::Static>;
 
     fn to_static(&self) -> Self::Static {
-        self.iter().map(ToBoundedStatic::to_static).collect()
+        Saturating(self.0.to_static())
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        self.0.to_static_with_budget(budget).map(Saturating)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        self.0.add_static_cost(report);
     }
 }
 
-/// [`IntoBoundedStatic`] impl for `smallvec::SmallVec`.
-#[cfg(feature = "smallvec")]
-impl<A, T> IntoBoundedStatic for smallvec::SmallVec<A>
+/// Blanket [`IntoBoundedStatic`] impl for converting `Saturating<T>` into `Saturating<T>: 'static`.
+impl<T> IntoBoundedStatic for Saturating<T>
 where
-    A: smallvec::Array<Item = T> + IntoBoundedStatic,
     T: IntoBoundedStatic,
-    <A as IntoBoundedStatic>::Static: smallvec::Array<Item = T::Static>,
 {
-    type Static = smallvec::SmallVec<A::Static>;
+    type Static = Saturating<T::Static>;
 
     fn into_static(self) -> Self::Static {
-        self.into_iter()
-            .map(IntoBoundedStatic::into_static)
-            .collect()
+        Saturating(self.0.into_static())
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        self.0.into_static_with_budget(budget).map(Saturating)
     }
 }
 
-/// [`ToBoundedStatic`] impl for `smartstring::SmartString`.
-#[cfg(feature = "smartstring")]
-impl<Mode> ToBoundedStatic for smartstring::SmartString<Mode>
+/// Blanket [`ToBoundedStatic`] impl for converting `ManuallyDrop<T>` to `ManuallyDrop<T>: 'static`.
+impl<T> ToBoundedStatic for ManuallyDrop<T>
 where
-    Mode: smartstring::SmartStringMode + 'static,
+    T: ToBoundedStatic,
 {
-    type Static = Self;
+    type Static = ManuallyDrop<T::Static>;
 
     fn to_static(&self) -> Self::Static {
-        self.clone()
+        ManuallyDrop::new((**self).to_static())
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        (**self)
+            .to_static_with_budget(budget)
+            .map(ManuallyDrop::new)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        (**self).add_static_cost(report);
     }
 }
 
-/// No-op [`IntoBoundedStatic`] impl for `smartstring::SmartString`.
-#[cfg(feature = "smartstring")]
-impl<Mode> IntoBoundedStatic for smartstring::SmartString<Mode>
+/// Blanket [`IntoBoundedStatic`] impl for converting `ManuallyDrop<T>` into `ManuallyDrop<T>: 'static`.
+///
+/// Note that the inner value is moved out of the `ManuallyDrop` to be converted and so any part of it which is not
+/// carried over to the converted value will be dropped.
+impl<T> IntoBoundedStatic for ManuallyDrop<T>
 where
-    Mode: smartstring::SmartStringMode + 'static,
+    T: IntoBoundedStatic,
 {
-    type Static = Self;
+    type Static = ManuallyDrop<T::Static>;
 
     fn into_static(self) -> Self::Static {
-        self
+        ManuallyDrop::new(Self::into_inner(self).into_static())
     }
-}
-
-#[cfg(feature = "ahash")]
-/// [`ToBoundedStatic`] impl for `ahash::RandomState`.
-impl ToBoundedStatic for ahash::RandomState {
-    type Static = Self;
 
-    fn to_static(&self) -> Self::Static {
-        self.clone()
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        Self::into_inner(self)
+            .into_static_with_budget(budget)
+            .map(ManuallyDrop::new)
     }
 }
 
-#[cfg(all(feature = "ahash", feature = "std"))]
-/// Blanket [`ToBoundedStatic`] impl for converting `ahash::AHashMap<K, V, S>` to `ahash::AHashMap<K, V, S>: 'static`.
-impl<K, V, S> ToBoundedStatic for ahash::AHashMap<K, V, S>
+/// Blanket [`ToBoundedStatic`] impl for converting `Poll<T>` to `Poll<T>: 'static`.
+impl<T> ToBoundedStatic for Poll<T>
 where
-    K: ToBoundedStatic,
-    K::Static: Eq + std::hash::Hash,
-    V: ToBoundedStatic,
-    S: ToBoundedStatic,
-    S::Static: std::hash::BuildHasher,
+    T: ToBoundedStatic,
 {
-    type Static = ahash::AHashMap<K::Static, V::Static, S::Static>;
+    type Static = Poll<T::Static>;
 
     fn to_static(&self) -> Self::Static {
-        let mut map =
-            ahash::AHashMap::with_capacity_and_hasher(self.len(), self.hasher().to_static());
-        map.extend(self.iter().map(|(k, v)| (k.to_static(), v.to_static())));
-        map
+        match self {
+            Self::Ready(value) => Poll::Ready(value.to_static()),
+            Self::Pending => Poll::Pending,
+        }
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        Ok(match self {
+            Self::Ready(value) => Poll::Ready(value.to_static_with_budget(budget)?),
+            Self::Pending => Poll::Pending,
+        })
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        if let Self::Ready(value) = self {
+            value.add_static_cost(report);
+        }
     }
 }
 
-#[cfg(all(feature = "ahash", feature = "std"))]
-/// Blanket [`IntoBoundedStatic`] impl for converting `ahash::AHashMap<K, V, S>` into `ahash::AHashMap<K, V, S>: 'static`.
-impl<K, V, S> IntoBoundedStatic for ahash::AHashMap<K, V, S>
+/// Blanket [`IntoBoundedStatic`] impl for converting `Poll<T>` into `Poll<T>: 'static`.
+impl<T> IntoBoundedStatic for Poll<T>
 where
-    K: IntoBoundedStatic,
-    K::Static: Eq + std::hash::Hash,
-    V: IntoBoundedStatic,
-    S: ToBoundedStatic,
-    S::Static: std::hash::BuildHasher,
+    T: IntoBoundedStatic,
 {
-    type Static = ahash::AHashMap<K::Static, V::Static, S::Static>;
+    type Static = Poll<T::Static>;
 
     fn into_static(self) -> Self::Static {
-        let mut map =
-            ahash::AHashMap::with_capacity_and_hasher(self.len(), self.hasher().to_static());
-        map.extend(
-            self.into_iter()
-                .map(|(k, v)| (k.into_static(), v.into_static())),
-        );
-        map
+        self.map(IntoBoundedStatic::into_static)
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        Ok(match self {
+            Self::Ready(value) => Poll::Ready(value.into_static_with_budget(budget)?),
+            Self::Pending => Poll::Pending,
+        })
     }
 }
 
-#[cfg(all(feature = "ahash", feature = "std"))]
-/// Blanket [`ToBoundedStatic`] impl for converting `ahash::AHashSet<T, S>` to `ahash::AHashSet<T, S>: 'static`.
-impl<T, S> ToBoundedStatic for ahash::AHashSet<T, S>
+/// Blanket [`ToBoundedStatic`] impl for converting `PhantomData<T>` to `PhantomData<T>: 'static`.
+impl<T> ToBoundedStatic for PhantomData<T>
 where
     T: ToBoundedStatic,
-    T::Static: Eq + std::hash::Hash,
-    S: ToBoundedStatic,
-    S::Static: std::hash::BuildHasher,
 {
-    type Static = ahash::AHashSet<T::Static, S::Static>;
+    type Static = PhantomData<T::Static>;
 
     fn to_static(&self) -> Self::Static {
-        let mut set =
-            ahash::AHashSet::with_capacity_and_hasher(self.len(), self.hasher().to_static());
-        set.extend(self.iter().map(ToBoundedStatic::to_static));
-        set
+        PhantomData
     }
 }
 
-#[cfg(all(feature = "ahash", feature = "std"))]
-/// Blanket [`IntoBoundedStatic`] impl for converting `ahash::AHashSet<T, S>` into `ahash::AHashSet<T, S>: 'static`.
-impl<T, S> IntoBoundedStatic for ahash::AHashSet<T, S>
+/// Blanket [`IntoBoundedStatic`] impl for converting `PhantomData<T>` into `PhantomData<T>: 'static`.
+impl<T> IntoBoundedStatic for PhantomData<T>
 where
     T: IntoBoundedStatic,
-    T::Static: Eq + std::hash::Hash,
-    S: ToBoundedStatic,
-    S::Static: std::hash::BuildHasher,
 {
-    type Static = ahash::AHashSet<T::Static, S::Static>;
+    type Static = PhantomData<T::Static>;
 
     fn into_static(self) -> Self::Static {
-        let mut set =
-            ahash::AHashSet::with_capacity_and_hasher(self.len(), self.hasher().to_static());
-        set.extend(self.into_iter().map(IntoBoundedStatic::into_static));
-        set
+        PhantomData
     }
 }
 
-#[cfg(feature = "chrono")]
-/// Blanket [`ToBoundedStatic`] impl for converting `chrono::DateTime<Tz>` into `chrono::DateTime<Tz>: 'static`.
-impl<Tz> ToBoundedStatic for chrono::DateTime<Tz>
+/// No-op [`ToBoundedStatic`] impl for `BuildHasherDefault<H>`.
+impl<H> ToBoundedStatic for BuildHasherDefault<H>
 where
-    Tz: ToBoundedStatic + chrono::TimeZone,
-    Tz::Static: chrono::TimeZone,
+    H: 'static,
 {
-    type Static = chrono::DateTime<Tz::Static>;
+    type Static = Self;
 
     fn to_static(&self) -> Self::Static {
-        self.with_timezone(&self.timezone().to_static())
+        self.clone()
     }
+
+    crate::__to_static_fast_path!(clone);
 }
 
-#[cfg(feature = "chrono")]
-/// Blanket [`IntoBoundedStatic`] impl for converting `chrono::DateTime<Tz>` into `chrono::DateTime<Tz>: 'static`.
-impl<Tz> IntoBoundedStatic for chrono::DateTime<Tz>
+/// No-op [`IntoBoundedStatic`] impl for `BuildHasherDefault<H>`.
+impl<H> IntoBoundedStatic for BuildHasherDefault<H>
 where
-    Tz: IntoBoundedStatic + chrono::TimeZone,
-    Tz::Static: chrono::TimeZone,
+    H: 'static,
 {
-    type Static = chrono::DateTime<Tz::Static>;
+    type Static = Self;
 
     fn into_static(self) -> Self::Static {
-        self.with_timezone(&self.timezone().into_static())
+        self
     }
+
+    crate::__into_static_fast_path!();
 }
 
-#[cfg(feature = "chrono")]
-make_copy_impl!(chrono::FixedOffset);
-#[cfg(feature = "chrono")]
-make_copy_impl!(chrono::Months);
-#[cfg(feature = "chrono")]
-make_copy_impl!(chrono::TimeDelta);
-#[cfg(feature = "chrono")]
-make_copy_impl!(chrono::Utc);
-#[cfg(feature = "chrono")]
-make_copy_impl!(chrono::Month);
-#[cfg(feature = "chrono")]
-make_copy_impl!(chrono::Weekday);
-#[cfg(feature = "chrono")]
-make_copy_impl!(chrono::naive::Days);
-#[cfg(feature = "chrono")]
-make_copy_impl!(chrono::naive::IsoWeek);
-#[cfg(feature = "chrono")]
-make_copy_impl!(chrono::naive::NaiveDate);
-#[cfg(feature = "chrono")]
-make_copy_impl!(chrono::naive::NaiveDateTime);
-#[cfg(feature = "chrono")]
-make_copy_impl!(chrono::naive::NaiveTime);
-#[cfg(feature = "chrono-clock")]
-make_copy_impl!(chrono::Local);
-// No implementation for chrono::NaiveWeek as it's not Copy nor Clone.
+#[cfg(feature = "alloc")]
+/// Blanket [`ToBoundedStatic`] impl for converting `Cow<'a, T: ?Sized>` to `Cow<'static, T: ?Sized>`.
+///
+/// The budgeted conversion charges the size of the value which is copied.  The value is cloned with `ToOwned`, which
+/// gives no way to measure any heap data it owns, such as the elements of a `Cow<[String]>`, and so unless `T` is known
+/// to own none the budgeted conversion fails with an error of kind [`ErrorKind::Unmeasurable`] for a limited budget.
+/// Use a [`StaticCow<[T]>`](StaticCow) or a `Vec<T>` for the elements to be charged.
+impl<T> ToBoundedStatic for Cow<'_, T>
+where
+    T: 'static + ToOwned + ?Sized,
+{
+    type Static = Cow<'static, T>;
 
-#[cfg(test)]
-mod core_tests {
-    use super::*;
-    use test_case::test_case;
+    fn to_static(&self) -> Self::Static {
+        record_alloc(core::mem::size_of_val(self.as_ref()));
+        Cow::Owned(self.clone().into_owned())
+    }
 
-    fn ensure_static<T: 'static>(t: T) {
-        drop(t);
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        charge_cow(self.as_ref(), budget)?;
+        Ok(self.to_static())
+    }
+
+    /// The cost of any heap data owned by the value is not reported.
+    fn add_static_cost(&self, report: &mut CostReport) {
+        match self {
+            Cow::Borrowed(value) => report.add_borrowed(core::mem::size_of_val(*value)),
+            Cow::Owned(_) => report.add_owned(core::mem::size_of_val(self.as_ref())),
+        }
+    }
+
+    fn to_static_into(&self, dst: &mut Self::Static) {
+        match dst {
+            Cow::Owned(dst) => self.as_ref().clone_into(dst),
+            Cow::Borrowed(_) => *dst = self.to_static(),
+        }
+    }
+
+    #[cfg(feature = "hashbrown")]
+    crate::__to_static_fast_path!(rebind);
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`IntoBoundedStatic`] impl for converting `Cow<'a, T: ?Sized>` into `Cow<'static, T: ?Sized>`.
+///
+/// The budgeted conversion charges the size of a borrowed value which is copied, and fails with an error of kind
+/// [`ErrorKind::Unmeasurable`] for a limited budget if the value may own heap data, as for [`ToBoundedStatic`].
+impl<T> IntoBoundedStatic for Cow<'_, T>
+where
+    T: 'static + ToOwned + ?Sized,
+{
+    type Static = Cow<'static, T>;
+
+    fn into_static(self) -> Self::Static {
+        match &self {
+            Cow::Borrowed(value) => record_alloc(core::mem::size_of_val(*value)),
+            Cow::Owned(_) => record_reuse(),
+        }
+        Cow::Owned(self.into_owned())
+    }
+
+    #[cfg(feature = "hashbrown")]
+    crate::__into_static_fast_path!(rebind);
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        if let Cow::Borrowed(value) = self {
+            charge_cow(value, budget)?;
+        }
+        Ok(self.into_static())
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+#[allow(unsafe_code)]
+// SAFETY: `Cow<'static, T>` is `Cow<'a, T>` with its lifetime replaced, and hashes and compares as the `T` it holds.
+unsafe impl<T> fast_path::RebindStatic for Cow<'_, T>
+where
+    T: 'static + ToOwned + ?Sized,
+{
+    fn clone_to_static(self) -> Cow<'static, T> {
+        record_alloc(core::mem::size_of_val(self.as_ref()));
+        Cow::Owned(self.into_owned())
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToBoundedStatic`] impl for converting `StaticCow<'a, T: ?Sized>` to `StaticCow<'static, T: ?Sized>`.
+///
+/// Data borrowed for the `'static` lifetime is kept without copying.
+impl<T> ToBoundedStatic for StaticCow<'_, T>
+where
+    T: 'static + ToOwnedStatic + ?Sized,
+{
+    type Static = StaticCow<'static, T>;
+
+    fn to_static(&self) -> <Self as ToBoundedStatic>::Static {
+        match self {
+            StaticCow::Static(value) => StaticCow::Static(value),
+            StaticCow::Borrowed(_) | StaticCow::Owned(_) => {
+                record_alloc(core::mem::size_of_val::<T>(self));
+                StaticCow::Owned((**self).to_owned())
+            }
+        }
+    }
+
+    fn to_static_with_budget(
+        &self,
+        budget: &mut Budget,
+    ) -> Result<<Self as ToBoundedStatic>::Static, TryStaticError> {
+        match self {
+            StaticCow::Static(value) => Ok(StaticCow::Static(value)),
+            StaticCow::Borrowed(_) | StaticCow::Owned(_) => {
+                (**self).to_owned_with_budget(budget).map(StaticCow::Owned)
+            }
+        }
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        match self {
+            StaticCow::Static(_) => {}
+            StaticCow::Borrowed(value) => report.add_borrowed(core::mem::size_of_val(*value)),
+            StaticCow::Owned(_) => report.add_owned(core::mem::size_of_val::<T>(self)),
+        }
+    }
+
+    fn to_static_into(&self, dst: &mut <Self as ToBoundedStatic>::Static) {
+        match (self, dst) {
+            (StaticCow::Borrowed(_) | StaticCow::Owned(_), StaticCow::Owned(dst)) => {
+                (**self).clone_into(dst);
+            }
+            (_, dst) => *dst = self.to_static(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`IntoBoundedStatic`] impl for converting `StaticCow<'a, T: ?Sized>` into `StaticCow<'static, T: ?Sized>`.
+///
+/// Data borrowed for the `'static` lifetime is kept without copying.
+impl<T> IntoBoundedStatic for StaticCow<'_, T>
+where
+    T: 'static + ToOwnedStatic + ?Sized,
+{
+    type Static = StaticCow<'static, T>;
+
+    fn into_static(self) -> <Self as IntoBoundedStatic>::Static {
+        match self {
+            StaticCow::Static(value) => StaticCow::Static(value),
+            StaticCow::Borrowed(value) => {
+                record_alloc(core::mem::size_of_val(value));
+                StaticCow::Owned(value.to_owned())
+            }
+            StaticCow::Owned(value) => {
+                record_reuse();
+                StaticCow::Owned(value)
+            }
+        }
+    }
+
+    fn into_static_with_budget(
+        self,
+        budget: &mut Budget,
+    ) -> Result<<Self as IntoBoundedStatic>::Static, TryStaticError> {
+        match self {
+            StaticCow::Borrowed(value) => value.to_owned_with_budget(budget).map(StaticCow::Owned),
+            StaticCow::Static(_) | StaticCow::Owned(_) => Ok(self.into_static()),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// [`ToBoundedStatic`] impl for `String`.
+///
+/// `to_static_with_budget` copies the string into a new allocation which is reserved with `try_reserve_exact`.
+impl ToBoundedStatic for String {
+    type Static = Self;
+
+    fn to_static(&self) -> Self::Static {
+        record_alloc(self.len());
+        self.clone()
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        self.as_str().to_owned_with_budget(budget)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_owned(self.len());
+    }
+
+    fn to_static_into(&self, dst: &mut Self::Static) {
+        if dst.capacity() < self.len() {
+            record_alloc(self.len());
+        }
+        dst.clone_from(self);
+    }
+
+    crate::__to_static_fast_path!(clone);
+}
+
+#[cfg(feature = "alloc")]
+/// No-op [`IntoBoundedStatic`] impl for `String`.
+impl IntoBoundedStatic for String {
+    type Static = Self;
+
+    fn into_static(self) -> Self::Static {
+        self
+    }
+
+    crate::__into_static_fast_path!();
+}
+
+#[cfg(feature = "alloc")]
+impl_clone!(send heap(|value| value.as_bytes_with_nul().len()) CString);
+#[cfg(feature = "alloc")]
+impl_clone!(send heap(|value| value.to_bytes_with_nul().len()) Box<CStr>);
+#[cfg(feature = "std")]
+impl_clone!(send heap(|value| value.as_os_str().len()) std::path::PathBuf);
+#[cfg(feature = "std")]
+impl_clone!(send heap(|value| value.as_os_str().len()) Box<std::path::Path>);
+#[cfg(feature = "std")]
+impl_clone!(send heap(|value| value.len()) std::ffi::OsString);
+#[cfg(feature = "std")]
+impl_clone!(send heap(|value| value.len()) Box<std::ffi::OsStr>);
+#[cfg(feature = "std")]
+impl_clone!(send heap(|err| err.heap_bytes()) error::StaticError);
+
+/// Impls for converting a boxed error trait object `Box<dyn Error + 'a>` to `Box<dyn Error + 'static>` by capturing
+/// it as a [`StaticError`](error::StaticError).
+#[cfg(feature = "std")]
+macro_rules! boxed_error_to_static {
+    ($($bound:tt)*) => {
+        /// [`ToBoundedStatic`] impl for converting a boxed error to a boxed [`StaticError`](error::StaticError).
+        ///
+        /// The error is captured even if it is already `'static`, and so the original error type can no longer be
+        /// recovered with `downcast_ref`, which only finds a `StaticError`.
+        ///
+        /// The bytes charged to a budget and reported as the cost are the boxed `StaticError` and the length of the
+        /// `Display` and `Debug` output captured for the error and for each of its sources.
+        impl ToBoundedStatic for Box<dyn std::error::Error $($bound)* + '_> {
+            type Static = Box<dyn std::error::Error $($bound)* + 'static>;
+
+            fn to_static(&self) -> Self::Static {
+                record_alloc_for::<error::StaticError>(1);
+                Box::new(error::StaticError::new(&**self))
+            }
+
+            fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+                budget.charge(error::StaticError::captured_bytes(&**self))?;
+                Ok(self.to_static())
+            }
+
+            fn add_static_cost(&self, report: &mut CostReport) {
+                report.add_owned(error::StaticError::captured_bytes(&**self));
+            }
+        }
+
+        /// [`IntoBoundedStatic`] impl for converting a boxed error into a boxed [`StaticError`](error::StaticError).
+        ///
+        /// The error is captured even if it is already `'static`, such as a `Box<dyn Error + Send + Sync>` created
+        /// from an `io::Error`, and so the original error type can no longer be recovered with `downcast_ref`, which
+        /// only finds a `StaticError`.
+        impl IntoBoundedStatic for Box<dyn std::error::Error $($bound)* + '_> {
+            type Static = Box<dyn std::error::Error $($bound)* + 'static>;
+
+            fn into_static(self) -> Self::Static {
+                self.to_static()
+            }
+
+            fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+                self.to_static_with_budget(budget)
+            }
+        }
+
+        /// [`ToBounded`] impl for converting a boxed error to a boxed [`StaticError`](error::StaticError).
+        impl<'b, A: ?Sized> ToBounded<'b, A> for Box<dyn std::error::Error $($bound)* + '_> {
+            type Output = Box<dyn std::error::Error $($bound)* + 'static>;
+
+            fn to_bounded_in(&self, _: &'b A) -> Self::Output {
+                self.to_static()
+            }
+        }
+
+        /// [`ToStaticAs`] impl for converting a boxed error to a boxed [`StaticError`](error::StaticError).
+        impl<R: StaticRepr> ToStaticAs<R> for Box<dyn std::error::Error $($bound)* + '_> {
+            type Static = Box<dyn std::error::Error $($bound)* + 'static>;
+
+            fn to_static_as(&self, _: R) -> Self::Static {
+                self.to_static()
+            }
+        }
+
+        /// [`ToSendStatic`] impl for converting a boxed error to a boxed [`StaticError`](error::StaticError) which
+        /// is `Send + Sync`.
+        impl ToSendStatic for Box<dyn std::error::Error $($bound)* + '_> {
+            type Static = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+            fn to_send_static(&self) -> Self::Static {
+                record_alloc_for::<error::StaticError>(1);
+                Box::new(error::StaticError::new(&**self))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+boxed_error_to_static!();
+#[cfg(feature = "std")]
+boxed_error_to_static!(+ Send);
+#[cfg(feature = "std")]
+boxed_error_to_static!(+ Send + Sync);
+
+#[cfg(all(feature = "std", feature = "anyhow"))]
+/// [`ToBoundedStatic`] impl for `anyhow::Error`, which captures the error and its chain as a
+/// [`StaticError`](error::StaticError).
+///
+/// The bytes charged to a budget and reported as the cost are the boxed `StaticError` and the length of the `Display`
+/// and `Debug` output captured for the error and for each of its sources.
+impl ToBoundedStatic for anyhow::Error {
+    type Static = Self;
+
+    fn to_static(&self) -> Self::Static {
+        record_alloc_for::<error::StaticError>(1);
+        Self::new(error::StaticError::new(&**self))
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        budget.charge(error::StaticError::captured_bytes(&**self))?;
+        Ok(self.to_static())
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_owned(error::StaticError::captured_bytes(&**self));
+    }
+}
+
+#[cfg(all(feature = "std", feature = "anyhow"))]
+/// No-op [`IntoBoundedStatic`] impl for `anyhow::Error`.
+///
+/// The error is returned unchanged, and so it can still be downcast and nothing is charged to a budget.
+impl IntoBoundedStatic for anyhow::Error {
+    type Static = Self;
+
+    fn into_static(self) -> Self::Static {
+        self
+    }
+}
+
+#[cfg(all(feature = "std", feature = "eyre"))]
+/// [`ToBoundedStatic`] impl for `eyre::Report`, which captures the error and its chain as a
+/// [`StaticError`](error::StaticError).
+///
+/// The bytes charged to a budget and reported as the cost are the boxed `StaticError` and the length of the `Display`
+/// and `Debug` output captured for the error and for each of its sources.
+impl ToBoundedStatic for eyre::Report {
+    type Static = Self;
+
+    fn to_static(&self) -> Self::Static {
+        record_alloc_for::<error::StaticError>(1);
+        Self::new(error::StaticError::new(&**self))
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        budget.charge(error::StaticError::captured_bytes(&**self))?;
+        Ok(self.to_static())
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_owned(error::StaticError::captured_bytes(&**self));
+    }
+}
+
+#[cfg(all(feature = "std", feature = "eyre"))]
+/// No-op [`IntoBoundedStatic`] impl for `eyre::Report`.
+///
+/// The error is returned unchanged, and so it can still be downcast and nothing is charged to a budget.
+impl IntoBoundedStatic for eyre::Report {
+    type Static = Self;
+
+    fn into_static(self) -> Self::Static {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToBoundedStatic`] impl for converting `Vec<T>` to `Vec<T>: 'static`.
+impl<T> ToBoundedStatic for Vec<T>
+where
+    T: ToBoundedStatic,
+{
+    type Static = Vec<T::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        T::to_static_fast_path(fast_path::SliceToStatic(self))
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        extend_with_budget(try_vec_with_capacity, self.iter(), budget)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_bytes_for::<T::Static>(self.len());
+        for value in self {
+            value.add_static_cost(report);
+        }
+    }
+
+    fn to_static_into(&self, dst: &mut Self::Static) {
+        if dst.capacity() < self.len() {
+            record_alloc_for::<T::Static>(self.len());
+        }
+        dst.truncate(self.len());
+        for (value, dst) in self.iter().zip(dst.iter_mut()) {
+            value.to_static_into(dst);
+        }
+        let len = dst.len();
+        dst.extend(self[len..].iter().map(ToBoundedStatic::to_static));
+    }
+
+    fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+        record_alloc_for::<T::Static>(self.len());
+        self.iter()
+            .map(|value| value.to_static_with(converter))
+            .collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`IntoBoundedStatic`] impl for converting `Vec<T>` into `Vec<T>: 'static`.
+impl<T> IntoBoundedStatic for Vec<T>
+where
+    T: IntoBoundedStatic,
+{
+    type Static = Vec<T::Static>;
+
+    fn into_static(self) -> Self::Static {
+        T::into_static_fast_path(fast_path::VecIntoStatic(self))
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        extend_into_with_budget(try_vec_with_capacity, self.into_iter(), budget)
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToBoundedStatic`] impl for converting `BinaryHeap<T>` into `BinaryHeap<T>: 'static`.
+impl<T> ToBoundedStatic for BinaryHeap<T>
+where
+    T: ToBoundedStatic,
+    T::Static: Ord,
+{
+    type Static = BinaryHeap<T::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        record_alloc_for::<T::Static>(self.len());
+        self.iter().map(ToBoundedStatic::to_static).collect()
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        extend_with_budget(
+            |len| try_vec_with_capacity(len).map(BinaryHeap::from),
+            self.iter(),
+            budget,
+        )
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_bytes_for::<T::Static>(self.len());
+        for value in self {
+            value.add_static_cost(report);
+        }
+    }
+
+    fn to_static_into(&self, dst: &mut Self::Static) {
+        let mut values = core::mem::take(dst).into_vec();
+        if values.capacity() < self.len() {
+            record_alloc_for::<T::Static>(self.len());
+        }
+        values.clear();
+        values.extend(self.iter().map(ToBoundedStatic::to_static));
+        *dst = BinaryHeap::from(values);
+    }
+
+    fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+        record_alloc_for::<T::Static>(self.len());
+        self.iter()
+            .map(|value| value.to_static_with(converter))
+            .collect()
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`IntoBoundedStatic`] impl for converting `BinaryHeap<T>` into `BinaryHeap<T>: 'static`.
+impl<T> IntoBoundedStatic for BinaryHeap<T>
+where
+    T: IntoBoundedStatic,
+    T::Static: Ord,
+{
+    type Static = BinaryHeap<T::Static>;
+
+    fn into_static(self) -> Self::Static {
+        T::into_static_fast_path(fast_path::BinaryHeapIntoStatic(self))
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        extend_into_with_budget(
+            |len| try_vec_with_capacity(len).map(BinaryHeap::from),
+            self.into_iter(),
+            budget,
+        )
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToBoundedStatic`] impl for converting `BTreeMap<K, V>` into `BTreeMap<K, V>: 'static`.
+impl<K, V> ToBoundedStatic for BTreeMap<K, V>
+where
+    K: ToBoundedStatic,
+    K::Static: Ord,
+    V: ToBoundedStatic,
+{
+    type Static = BTreeMap<K::Static, V::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        record_alloc_for::<(K::Static, V::Static)>(self.len());
+        self.iter()
+            .map(|(k, v)| (k.to_static(), v.to_static()))
+            .collect()
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        extend_entries_with_budget(|_| Ok(BTreeMap::new()), self.iter(), budget)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_bytes_for::<(K::Static, V::Static)>(self.len());
+        for (k, v) in self {
+            k.add_static_cost(report);
+            v.add_static_cost(report);
+        }
+    }
+
+    fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+        record_alloc_for::<(K::Static, V::Static)>(self.len());
+        self.iter()
+            .map(|(k, v)| (k.to_static_with(converter), v.to_static_with(converter)))
+            .collect()
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`IntoBoundedStatic`] impl for converting `BTreeMap<K, V>` into `BTreeMap<K, V>: 'static`.
+impl<K, V> IntoBoundedStatic for BTreeMap<K, V>
+where
+    K: IntoBoundedStatic,
+    K::Static: Ord,
+    V: IntoBoundedStatic,
+{
+    type Static = BTreeMap<K::Static, V::Static>;
+
+    fn into_static(self) -> Self::Static {
+        record_alloc_for::<(K::Static, V::Static)>(self.len());
+        self.into_iter()
+            .map(|(k, v)| (k.into_static(), v.into_static()))
+            .collect()
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        extend_entries_into_with_budget(|_| Ok(BTreeMap::new()), self.into_iter(), budget)
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToBoundedStatic`] impl for converting `BTreeSet<T>` into `BTreeSet<T>: 'static`.
+impl<T> ToBoundedStatic for BTreeSet<T>
+where
+    T: ToBoundedStatic,
+    T::Static: Ord,
+{
+    type Static = BTreeSet<T::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        record_alloc_for::<T::Static>(self.len());
+        self.iter().map(ToBoundedStatic::to_static).collect()
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        extend_with_budget(|_| Ok(BTreeSet::new()), self.iter(), budget)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_bytes_for::<T::Static>(self.len());
+        for value in self {
+            value.add_static_cost(report);
+        }
+    }
+
+    fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+        record_alloc_for::<T::Static>(self.len());
+        self.iter()
+            .map(|value| value.to_static_with(converter))
+            .collect()
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`IntoBoundedStatic`] impl for converting `BTreeSet<T>` into `BTreeSet<T>: 'static`.
+impl<T> IntoBoundedStatic for BTreeSet<T>
+where
+    T: IntoBoundedStatic,
+    T::Static: Ord,
+{
+    type Static = BTreeSet<T::Static>;
+
+    fn into_static(self) -> Self::Static {
+        record_alloc_for::<T::Static>(self.len());
+        self.into_iter()
+            .map(IntoBoundedStatic::into_static)
+            .collect()
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        extend_into_with_budget(|_| Ok(BTreeSet::new()), self.into_iter(), budget)
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToBoundedStatic`] impl for converting `LinkedList<T>` into `LinkedList<T>: 'static`.
+impl<T> ToBoundedStatic for LinkedList<T>
+where
+    T: ToBoundedStatic,
+{
+    type Static = LinkedList<T::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        record_alloc_for::<T::Static>(self.len());
+        self.iter().map(ToBoundedStatic::to_static).collect()
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        extend_with_budget(|_| Ok(LinkedList::new()), self.iter(), budget)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_bytes_for::<T::Static>(self.len());
+        for value in self {
+            value.add_static_cost(report);
+        }
+    }
+
+    fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+        record_alloc_for::<T::Static>(self.len());
+        self.iter()
+            .map(|value| value.to_static_with(converter))
+            .collect()
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`IntoBoundedStatic`] impl for converting `LinkedList<T>` into `LinkedList<T>: 'static`.
+impl<T> IntoBoundedStatic for LinkedList<T>
+where
+    T: IntoBoundedStatic,
+{
+    type Static = LinkedList<T::Static>;
+
+    fn into_static(self) -> Self::Static {
+        record_alloc_for::<T::Static>(self.len());
+        self.into_iter()
+            .map(IntoBoundedStatic::into_static)
+            .collect()
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        extend_into_with_budget(|_| Ok(LinkedList::new()), self.into_iter(), budget)
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToBoundedStatic`] impl for converting `VecDeque<T>` into `VecDeque<T>: 'static`.
+impl<T> ToBoundedStatic for VecDeque<T>
+where
+    T: ToBoundedStatic,
+{
+    type Static = VecDeque<T::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        record_alloc_for::<T::Static>(self.len());
+        self.iter().map(ToBoundedStatic::to_static).collect()
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        extend_with_budget(
+            |len| try_vec_with_capacity(len).map(VecDeque::from),
+            self.iter(),
+            budget,
+        )
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_bytes_for::<T::Static>(self.len());
+        for value in self {
+            value.add_static_cost(report);
+        }
+    }
+
+    fn to_static_into(&self, dst: &mut Self::Static) {
+        if dst.capacity() < self.len() {
+            record_alloc_for::<T::Static>(self.len());
+        }
+        dst.truncate(self.len());
+        for (value, dst) in self.iter().zip(dst.iter_mut()) {
+            value.to_static_into(dst);
+        }
+        let len = dst.len();
+        dst.extend(self.range(len..).map(ToBoundedStatic::to_static));
+    }
+
+    fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+        record_alloc_for::<T::Static>(self.len());
+        self.iter()
+            .map(|value| value.to_static_with(converter))
+            .collect()
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`IntoBoundedStatic`] impl for converting `VecDeque<T>` into `VecDeque<T>: 'static`.
+impl<T> IntoBoundedStatic for VecDeque<T>
+where
+    T: IntoBoundedStatic,
+{
+    type Static = VecDeque<T::Static>;
+
+    fn into_static(self) -> Self::Static {
+        T::into_static_fast_path(fast_path::VecDequeIntoStatic(self))
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        extend_into_with_budget(
+            |len| try_vec_with_capacity(len).map(VecDeque::from),
+            self.into_iter(),
+            budget,
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToBoundedStatic`] impl for converting `Box<T>` to `Box<T>: 'static`.
+impl<T> ToBoundedStatic for Box<T>
+where
+    T: ToBoundedStatic,
+{
+    type Static = Box<T::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        record_alloc_for::<T::Static>(1);
+        Box::new(self.as_ref().to_static())
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        budget.charge_for::<T::Static>(1)?;
+        record_alloc_for::<T::Static>(1);
+        self.as_ref().to_static_with_budget(budget).map(Box::new)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_bytes_for::<T::Static>(1);
+        self.as_ref().add_static_cost(report);
+    }
+
+    fn to_static_into(&self, dst: &mut Self::Static) {
+        self.as_ref().to_static_into(dst);
+    }
+
+    fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+        record_alloc_for::<T::Static>(1);
+        Box::new(self.as_ref().to_static_with(converter))
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`IntoBoundedStatic`] impl for converting `Box<T>` into `Box<T>: 'static`.
+impl<T> IntoBoundedStatic for Box<T>
+where
+    T: IntoBoundedStatic,
+{
+    type Static = Box<T::Static>;
+
+    fn into_static(self) -> Self::Static {
+        T::into_static_fast_path(fast_path::BoxIntoStatic(self))
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        budget.charge_for::<T::Static>(1)?;
+        record_alloc_for::<T::Static>(1);
+        (*self).into_static_with_budget(budget).map(Box::new)
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToBoundedStatic`] impl for converting `Pin<Box<T>>` to `Pin<Box<T>>: 'static`.
+impl<T> ToBoundedStatic for Pin<Box<T>>
+where
+    T: ToBoundedStatic,
+{
+    type Static = Pin<Box<T::Static>>;
+
+    fn to_static(&self) -> Self::Static {
+        record_alloc_for::<T::Static>(1);
+        Box::pin(self.as_ref().get_ref().to_static())
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        budget.charge_for::<T::Static>(1)?;
+        record_alloc_for::<T::Static>(1);
+        self.as_ref()
+            .get_ref()
+            .to_static_with_budget(budget)
+            .map(Box::pin)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_bytes_for::<T::Static>(1);
+        self.as_ref().get_ref().add_static_cost(report);
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`IntoBoundedStatic`] impl for converting `Pin<Box<T>>` into `Pin<Box<T>>: 'static`.
+///
+/// The value must be moved out of the `Pin` to be converted and so `T` must be `Unpin`.
+impl<T> IntoBoundedStatic for Pin<Box<T>>
+where
+    T: IntoBoundedStatic + Unpin,
+{
+    type Static = Pin<Box<T::Static>>;
+
+    fn into_static(self) -> Self::Static {
+        Box::into_pin(T::into_static_fast_path(fast_path::BoxIntoStatic(
+            Self::into_inner(self),
+        )))
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        budget.charge_for::<T::Static>(1)?;
+        record_alloc_for::<T::Static>(1);
+        (*Self::into_inner(self))
+            .into_static_with_budget(budget)
+            .map(Box::pin)
+    }
+}
+
+/// Blanket impls for converting the shared pointers `Rc<T>` and `Arc<T>` to a shared pointer to `T: 'static`.
+#[cfg(feature = "alloc")]
+macro_rules! shared_pointer_to_static {
+    ($ptr:ident, $dedup:ident) => {
+        #[doc = concat!(
+            "Blanket [`ToBoundedStatic`] impl for converting `", stringify!($ptr), "<T>` to `", stringify!($ptr),
+            "<T>: 'static`."
+        )]
+        ///
+        /// Each pointer is converted to a new pointer to a copy of the shared value.  Use
+        /// [`to_static_with`](ToBoundedStatic::to_static_with) to convert pointers to the same value to pointers to the
+        /// same converted value.
+        impl<T> ToBoundedStatic for $ptr<T>
+        where
+            T: ToBoundedStatic,
+        {
+            type Static = $ptr<T::Static>;
+
+            fn to_static(&self) -> Self::Static {
+                record_alloc_for::<T::Static>(1);
+                $ptr::new((**self).to_static())
+            }
+
+            fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+                budget.charge_for::<T::Static>(1)?;
+                record_alloc_for::<T::Static>(1);
+                (**self).to_static_with_budget(budget).map($ptr::new)
+            }
+
+            fn add_static_cost(&self, report: &mut CostReport) {
+                report.add_bytes_for::<T::Static>(1);
+                (**self).add_static_cost(report);
+            }
+
+            fn to_static_into(&self, dst: &mut Self::Static) {
+                match $ptr::get_mut(dst) {
+                    Some(dst) => (**self).to_static_into(dst),
+                    None => *dst = self.to_static(),
+                }
+            }
+
+            fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+                converter.shared($ptr::as_ptr(self), |converter| {
+                    record_alloc_for::<T::Static>(1);
+                    $ptr::new((**self).to_static_with(converter))
+                })
+            }
+        }
+
+        #[doc = concat!(
+            "Blanket [`IntoBoundedStatic`] impl for converting `", stringify!($ptr), "<T>` into `",
+            stringify!($ptr), "<T>: 'static`."
+        )]
+        ///
+        /// The shared value cannot be moved out of the pointer and so is converted by reference.
+        impl<T> IntoBoundedStatic for $ptr<T>
+        where
+            T: ToBoundedStatic,
+        {
+            type Static = $ptr<T::Static>;
+
+            fn into_static(self) -> Self::Static {
+                self.to_static()
+            }
+
+            fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+                self.to_static_with_budget(budget)
+            }
+        }
+
+        #[doc = concat!("No-op [`ToBoundedStatic`] impl for `", stringify!($ptr), "<str>`.")]
+        ///
+        /// The string is shared rather than copied.  Use [`to_static_with`](ToBoundedStatic::to_static_with) with
+        /// [`StaticConverter::dedup_strings`] to also share equal strings which do not share the same allocation.
+        impl ToBoundedStatic for $ptr<str> {
+            type Static = Self;
+
+            fn to_static(&self) -> Self::Static {
+                self.clone()
+            }
+
+            fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+                converter.$dedup(self)
+            }
+
+            crate::__to_static_fast_path!(clone);
+        }
+
+        #[doc = concat!("No-op [`IntoBoundedStatic`] impl for `", stringify!($ptr), "<str>`.")]
+        impl IntoBoundedStatic for $ptr<str> {
+            type Static = Self;
+
+            fn into_static(self) -> Self::Static {
+                self
+            }
+
+            crate::__into_static_fast_path!();
+        }
+
+    };
+}
+
+#[cfg(feature = "alloc")]
+shared_pointer_to_static!(Rc, rc_str);
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+shared_pointer_to_static!(Arc, arc_str);
+
+#[cfg(feature = "std")]
+/// Blanket [`ToBoundedStatic`] impl for converting `HashMap<K, V>` to `HashMap<K, V>: 'static`.
+///
+/// The map is cloned, which copies the table without rehashing, if `to_static()` is a copy or a clone for the keys and
+/// values and the hasher, such as for `HashMap<String, u64>`.  Otherwise every entry is converted and rehashed into a
+/// new map, such as for `Cow<'a, str>` keys, as the map provides no stable API to insert an entry with a known hash.
+/// A `hashbrown::HashMap`, with the `hashbrown` feature, converts such keys without rehashing.
+impl<K, V, S> ToBoundedStatic for std::collections::HashMap<K, V, S>
+where
+    K: ToBoundedStatic,
+    K::Static: Eq + std::hash::Hash,
+    V: ToBoundedStatic,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher,
+{
+    type Static = std::collections::HashMap<K::Static, V::Static, S::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        K::to_static_fast_path(fast_path::std_hash::MapToStatic(self))
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let hasher = self.hasher().to_static();
+        extend_entries_with_budget(
+            |len| {
+                let mut collection = std::collections::HashMap::with_hasher(hasher);
+                collection.try_reserve(len)?;
+                Ok(collection)
+            },
+            self.iter(),
+            budget,
+        )
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_bytes_for::<(K::Static, V::Static)>(self.len());
+        for (k, v) in self {
+            k.add_static_cost(report);
+            v.add_static_cost(report);
+        }
+    }
+
+    /// The hasher of `dst` is kept.
+    fn to_static_into(&self, dst: &mut Self::Static) {
+        if dst.capacity() < self.len() {
+            record_alloc_for::<(K::Static, V::Static)>(self.len());
+        }
+        dst.clear();
+        dst.extend(self.iter().map(|(k, v)| (k.to_static(), v.to_static())));
+    }
+
+    fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+        record_alloc_for::<(K::Static, V::Static)>(self.len());
+        let mut map = std::collections::HashMap::with_capacity_and_hasher(
+            self.len(),
+            self.hasher().to_static(),
+        );
+        map.extend(
+            self.iter()
+                .map(|(k, v)| (k.to_static_with(converter), v.to_static_with(converter))),
+        );
+        map
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`IntoBoundedStatic`] impl for for converting `HashMap<K, V>` into `HashMap<K, V>: 'static`.
+///
+/// The map is returned unchanged if `into_static()` is a no-op for the keys and values and `to_static()` is a clone for
+/// the hasher.  Otherwise every entry is converted and rehashed into a new map.
+impl<K, V, S> IntoBoundedStatic for std::collections::HashMap<K, V, S>
+where
+    K: IntoBoundedStatic,
+    K::Static: Eq + std::hash::Hash,
+    V: IntoBoundedStatic,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher,
+{
+    type Static = std::collections::HashMap<K::Static, V::Static, S::Static>;
+
+    fn into_static(self) -> Self::Static {
+        K::into_static_fast_path(fast_path::std_hash::MapIntoStatic(self))
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let hasher = self.hasher().to_static();
+        extend_entries_into_with_budget(
+            |len| {
+                let mut collection = std::collections::HashMap::with_hasher(hasher);
+                collection.try_reserve(len)?;
+                Ok(collection)
+            },
+            self.into_iter(),
+            budget,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToBoundedStatic`] impl for converting `HashSet<T>` into `HashSet<T>: 'static`.
+///
+/// The set is cloned, which copies the table without rehashing, if `to_static()` is a copy or a clone for the values
+/// and the hasher, such as for `HashSet<String>`.  Otherwise every value is converted and rehashed into a new set, such
+/// as for `Cow<'a, str>` values, as the set provides no stable API to insert a value with a known hash.  A
+/// `hashbrown::HashSet`, with the `hashbrown` feature, converts such values without rehashing.
+impl<T, S> ToBoundedStatic for std::collections::HashSet<T, S>
+where
+    T: ToBoundedStatic,
+    T::Static: Eq + std::hash::Hash,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher,
+{
+    type Static = std::collections::HashSet<T::Static, S::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        T::to_static_fast_path(fast_path::std_hash::SetToStatic(self))
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let hasher = self.hasher().to_static();
+        extend_with_budget(
+            |len| {
+                let mut collection = std::collections::HashSet::with_hasher(hasher);
+                collection.try_reserve(len)?;
+                Ok(collection)
+            },
+            self.iter(),
+            budget,
+        )
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_bytes_for::<T::Static>(self.len());
+        for value in self {
+            value.add_static_cost(report);
+        }
+    }
+
+    /// The hasher of `dst` is kept.
+    fn to_static_into(&self, dst: &mut Self::Static) {
+        if dst.capacity() < self.len() {
+            record_alloc_for::<T::Static>(self.len());
+        }
+        dst.clear();
+        dst.extend(self.iter().map(ToBoundedStatic::to_static));
+    }
+
+    fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+        record_alloc_for::<T::Static>(self.len());
+        let mut set = std::collections::HashSet::with_capacity_and_hasher(
+            self.len(),
+            self.hasher().to_static(),
+        );
+        set.extend(self.iter().map(|value| value.to_static_with(converter)));
+        set
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`IntoBoundedStatic`] impl for converting `HashSet<T>` into `HashSet<T>: 'static`.
+///
+/// The set is returned unchanged if `into_static()` is a no-op for the values and `to_static()` is a clone for the
+/// hasher.  Otherwise every value is converted and rehashed into a new set.
+impl<T, S> IntoBoundedStatic for std::collections::HashSet<T, S>
+where
+    T: IntoBoundedStatic,
+    T::Static: Eq + std::hash::Hash,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher,
+{
+    type Static = std::collections::HashSet<T::Static, S::Static>;
+
+    fn into_static(self) -> Self::Static {
+        T::into_static_fast_path(fast_path::std_hash::SetIntoStatic(self))
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let hasher = self.hasher().to_static();
+        extend_into_with_budget(
+            |len| {
+                let mut collection = std::collections::HashSet::with_hasher(hasher);
+                collection.try_reserve(len)?;
+                Ok(collection)
+            },
+            self.into_iter(),
+            budget,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+/// [`ToBoundedStatic`] impl for `std::collections::hash_map::RandomState`.
+impl ToBoundedStatic for std::collections::hash_map::RandomState {
+    type Static = Self;
+
+    fn to_static(&self) -> Self::Static {
+        self.clone()
+    }
+
+    crate::__to_static_fast_path!(clone);
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToBoundedStatic`] impl for converting `Mutex<T>` to `Mutex<T>: 'static`.
+///
+/// The lock is held for the duration of the conversion and so calling this whilst the current thread holds the lock
+/// will deadlock.
+///
+/// If the lock is poisoned `to_static` ignores the poison and converts the inner value, the resulting `Mutex` is not
+/// poisoned, whereas `to_static_with_budget` returns an error of kind [`ErrorKind::Poisoned`].
+impl<T> ToBoundedStatic for std::sync::Mutex<T>
+where
+    T: ToBoundedStatic,
+{
+    type Static = std::sync::Mutex<T::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        let guard = self
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        std::sync::Mutex::new(guard.to_static())
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let guard = self
+            .lock()
+            .map_err(|_| TryStaticError::new(ErrorKind::Poisoned))?;
+        guard
+            .to_static_with_budget(budget)
+            .map(std::sync::Mutex::new)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        let guard = self
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        guard.add_static_cost(report);
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`IntoBoundedStatic`] impl for converting `Mutex<T>` into `Mutex<T>: 'static`.
+///
+/// The value is taken with `into_inner` without locking.  If the lock is poisoned `into_static` ignores the poison and
+/// converts the inner value, the resulting `Mutex` is not poisoned, whereas `into_static_with_budget` returns an error
+/// of kind [`ErrorKind::Poisoned`].
+impl<T> IntoBoundedStatic for std::sync::Mutex<T>
+where
+    T: IntoBoundedStatic,
+{
+    type Static = std::sync::Mutex<T::Static>;
+
+    fn into_static(self) -> Self::Static {
+        let value = self
+            .into_inner()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        std::sync::Mutex::new(value.into_static())
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let value = self
+            .into_inner()
+            .map_err(|_| TryStaticError::new(ErrorKind::Poisoned))?;
+        value
+            .into_static_with_budget(budget)
+            .map(std::sync::Mutex::new)
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToBoundedStatic`] impl for converting `RwLock<T>` to `RwLock<T>: 'static`.
+///
+/// A read lock is held for the duration of the conversion and so calling this whilst the current thread holds the
+/// write lock will deadlock.
+///
+/// If the lock is poisoned `to_static` ignores the poison and converts the inner value, the resulting `RwLock` is not
+/// poisoned, whereas `to_static_with_budget` returns an error of kind [`ErrorKind::Poisoned`].
+impl<T> ToBoundedStatic for std::sync::RwLock<T>
+where
+    T: ToBoundedStatic,
+{
+    type Static = std::sync::RwLock<T::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        let guard = self
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        std::sync::RwLock::new(guard.to_static())
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let guard = self
+            .read()
+            .map_err(|_| TryStaticError::new(ErrorKind::Poisoned))?;
+        guard
+            .to_static_with_budget(budget)
+            .map(std::sync::RwLock::new)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        let guard = self
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        guard.add_static_cost(report);
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`IntoBoundedStatic`] impl for converting `RwLock<T>` into `RwLock<T>: 'static`.
+///
+/// The value is taken with `into_inner` without locking.  If the lock is poisoned `into_static` ignores the poison and
+/// converts the inner value, the resulting `RwLock` is not poisoned, whereas `into_static_with_budget` returns an error
+/// of kind [`ErrorKind::Poisoned`].
+impl<T> IntoBoundedStatic for std::sync::RwLock<T>
+where
+    T: IntoBoundedStatic,
+{
+    type Static = std::sync::RwLock<T::Static>;
+
+    fn into_static(self) -> Self::Static {
+        let value = self
+            .into_inner()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        std::sync::RwLock::new(value.into_static())
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let value = self
+            .into_inner()
+            .map_err(|_| TryStaticError::new(ErrorKind::Poisoned))?;
+        value
+            .into_static_with_budget(budget)
+            .map(std::sync::RwLock::new)
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToBoundedStatic`] impl for converting `OnceLock<T>` to `OnceLock<T>: 'static`.
+impl<T> ToBoundedStatic for std::sync::OnceLock<T>
+where
+    T: ToBoundedStatic,
+{
+    type Static = std::sync::OnceLock<T::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        self.get().map_or_else(std::sync::OnceLock::new, |value| {
+            std::sync::OnceLock::from(value.to_static())
+        })
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let Some(value) = self.get() else {
+            return Ok(std::sync::OnceLock::new());
+        };
+        value
+            .to_static_with_budget(budget)
+            .map(std::sync::OnceLock::from)
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        if let Some(value) = self.get() {
+            value.add_static_cost(report);
+        }
+    }
+
+    fn to_static_with<'a>(&'a self, converter: &mut StaticConverter<'a>) -> Self::Static {
+        self.get().map_or_else(std::sync::OnceLock::new, |value| {
+            std::sync::OnceLock::from(value.to_static_with(converter))
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`IntoBoundedStatic`] impl for converting `OnceLock<T>` into `OnceLock<T>: 'static`.
+impl<T> IntoBoundedStatic for std::sync::OnceLock<T>
+where
+    T: IntoBoundedStatic,
+{
+    type Static = std::sync::OnceLock<T::Static>;
+
+    fn into_static(self) -> Self::Static {
+        self.into_inner()
+            .map_or_else(std::sync::OnceLock::new, |value| {
+                std::sync::OnceLock::from(value.into_static())
+            })
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let Some(value) = self.into_inner() else {
+            return Ok(std::sync::OnceLock::new());
+        };
+        value
+            .into_static_with_budget(budget)
+            .map(std::sync::OnceLock::from)
+    }
+}
+
+/// [`ToBoundedStatic`] impl for `smol_str::SmolStr`.
+#[cfg(feature = "smol_str")]
+impl ToBoundedStatic for smol_str::SmolStr {
+    type Static = Self;
+
+    fn to_static(&self) -> Self::Static {
+        self.clone()
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_owned(0);
+    }
+
+    crate::__to_static_fast_path!(clone);
+}
+
+/// No-op [`IntoBoundedStatic`] impl for `smol_str::SmolStr`.
+#[cfg(feature = "smol_str")]
+impl IntoBoundedStatic for smol_str::SmolStr {
+    type Static = Self;
+
+    fn into_static(self) -> Self::Static {
+        self
+    }
+
+    crate::__into_static_fast_path!();
+}
+
+/// [`ToBoundedStatic`] impl for `smallvec::SmallVec`.
+#[cfg(feature = "smallvec")]
+impl<A, T> ToBoundedStatic for smallvec::SmallVec<A>
+where
+    A: smallvec::Array<Item = T> + ToBoundedStatic,
+    T: ToBoundedStatic,
+    <A as ToBoundedStatic>::Static: smallvec::Array<Item = T::Static>,
+{
+    type Static = smallvec::SmallVec<A::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        if self.spilled() {
+            record_alloc_for::<T::Static>(self.len());
+        }
+        self.iter().map(ToBoundedStatic::to_static).collect()
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        extend_with_budget(
+            |len| Ok(smallvec::SmallVec::with_capacity(len)),
+            self.iter(),
+            budget,
+        )
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_bytes_for::<T::Static>(self.len());
+        for value in self {
+            value.add_static_cost(report);
+        }
+    }
+}
+
+/// [`IntoBoundedStatic`] impl for `smallvec::SmallVec`.
+#[cfg(feature = "smallvec")]
+impl<A, T> IntoBoundedStatic for smallvec::SmallVec<A>
+where
+    A: smallvec::Array<Item = T> + IntoBoundedStatic,
+    T: IntoBoundedStatic,
+    <A as IntoBoundedStatic>::Static: smallvec::Array<Item = T::Static>,
+{
+    type Static = smallvec::SmallVec<A::Static>;
+
+    fn into_static(self) -> Self::Static {
+        if self.spilled() {
+            record_alloc_for::<T::Static>(self.len());
+        }
+        self.into_iter()
+            .map(IntoBoundedStatic::into_static)
+            .collect()
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        extend_into_with_budget(
+            |len| Ok(smallvec::SmallVec::with_capacity(len)),
+            self.into_iter(),
+            budget,
+        )
+    }
+}
+
+/// [`ToBoundedStatic`] impl for `smartstring::SmartString`.
+#[cfg(feature = "smartstring")]
+impl<Mode> ToBoundedStatic for smartstring::SmartString<Mode>
+where
+    Mode: smartstring::SmartStringMode + 'static,
+{
+    type Static = Self;
+
+    fn to_static(&self) -> Self::Static {
+        if !self.is_inline() {
+            record_alloc(self.len());
+        }
+        self.clone()
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        if !self.is_inline() {
+            budget.charge(self.len())?;
+        }
+        Ok(self.to_static())
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_owned(if self.is_inline() { 0 } else { self.len() });
+    }
+
+    crate::__to_static_fast_path!(clone);
+}
+
+/// No-op [`IntoBoundedStatic`] impl for `smartstring::SmartString`.
+#[cfg(feature = "smartstring")]
+impl<Mode> IntoBoundedStatic for smartstring::SmartString<Mode>
+where
+    Mode: smartstring::SmartStringMode + 'static,
+{
+    type Static = Self;
+
+    fn into_static(self) -> Self::Static {
+        self
+    }
+
+    crate::__into_static_fast_path!();
+}
+
+#[cfg(feature = "ahash")]
+/// [`ToBoundedStatic`] impl for `ahash::RandomState`.
+impl ToBoundedStatic for ahash::RandomState {
+    type Static = Self;
+
+    fn to_static(&self) -> Self::Static {
+        self.clone()
+    }
+
+    crate::__to_static_fast_path!(clone);
+}
+
+#[cfg(all(feature = "ahash", feature = "std"))]
+/// Blanket [`ToBoundedStatic`] impl for converting `ahash::AHashMap<K, V, S>` to `ahash::AHashMap<K, V, S>: 'static`.
+///
+/// The map is cloned, which copies the table without rehashing, if `to_static()` is a copy or a clone for the keys and
+/// values and the hasher, such as for `AHashMap<String, u64>`.  Otherwise every entry is converted and rehashed into a
+/// new map, such as for `Cow<'a, str>` keys, as the map provides no stable API to insert an entry with a known hash.
+/// A `hashbrown::HashMap`, with the `hashbrown` feature, converts such keys without rehashing.
+impl<K, V, S> ToBoundedStatic for ahash::AHashMap<K, V, S>
+where
+    K: ToBoundedStatic,
+    K::Static: Eq + std::hash::Hash,
+    V: ToBoundedStatic,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher,
+{
+    type Static = ahash::AHashMap<K::Static, V::Static, S::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        K::to_static_fast_path(fast_path::ahash_hash::MapToStatic(self))
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let hasher = self.hasher().to_static();
+        extend_entries_with_budget(
+            |len| {
+                let mut collection = ahash::AHashMap::with_hasher(hasher);
+                collection.try_reserve(len)?;
+                Ok(collection)
+            },
+            self.iter(),
+            budget,
+        )
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_bytes_for::<(K::Static, V::Static)>(self.len());
+        for (k, v) in self {
+            k.add_static_cost(report);
+            v.add_static_cost(report);
+        }
+    }
+
+    /// The hasher of `dst` is kept.
+    fn to_static_into(&self, dst: &mut Self::Static) {
+        if dst.capacity() < self.len() {
+            record_alloc_for::<(K::Static, V::Static)>(self.len());
+        }
+        dst.clear();
+        dst.extend(self.iter().map(|(k, v)| (k.to_static(), v.to_static())));
+    }
+}
+
+#[cfg(all(feature = "ahash", feature = "std"))]
+/// Blanket [`IntoBoundedStatic`] impl for converting `ahash::AHashMap<K, V, S>` into `ahash::AHashMap<K, V, S>: 'static`.
+impl<K, V, S> IntoBoundedStatic for ahash::AHashMap<K, V, S>
+where
+    K: IntoBoundedStatic,
+    K::Static: Eq + std::hash::Hash,
+    V: IntoBoundedStatic,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher,
+{
+    type Static = ahash::AHashMap<K::Static, V::Static, S::Static>;
+
+    fn into_static(self) -> Self::Static {
+        K::into_static_fast_path(fast_path::ahash_hash::MapIntoStatic(self))
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let hasher = self.hasher().to_static();
+        extend_entries_into_with_budget(
+            |len| {
+                let mut collection = ahash::AHashMap::with_hasher(hasher);
+                collection.try_reserve(len)?;
+                Ok(collection)
+            },
+            self.into_iter(),
+            budget,
+        )
+    }
+}
+
+#[cfg(all(feature = "ahash", feature = "std"))]
+/// Blanket [`ToBoundedStatic`] impl for converting `ahash::AHashSet<T, S>` to `ahash::AHashSet<T, S>: 'static`.
+///
+/// The set is cloned, which copies the table without rehashing, if `to_static()` is a copy or a clone for the values
+/// and the hasher, such as for `AHashSet<String>`.  Otherwise every value is converted and rehashed into a new set,
+/// such as for `Cow<'a, str>` values, as the set provides no stable API to insert a value with a known hash.  A
+/// `hashbrown::HashSet`, with the `hashbrown` feature, converts such values without rehashing.
+impl<T, S> ToBoundedStatic for ahash::AHashSet<T, S>
+where
+    T: ToBoundedStatic,
+    T::Static: Eq + std::hash::Hash,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher,
+{
+    type Static = ahash::AHashSet<T::Static, S::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        T::to_static_fast_path(fast_path::ahash_hash::SetToStatic(self))
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let hasher = self.hasher().to_static();
+        extend_with_budget(
+            |len| {
+                let mut collection = ahash::AHashSet::with_hasher(hasher);
+                collection.try_reserve(len)?;
+                Ok(collection)
+            },
+            self.iter(),
+            budget,
+        )
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_bytes_for::<T::Static>(self.len());
+        for value in self {
+            value.add_static_cost(report);
+        }
+    }
+
+    /// The hasher of `dst` is kept.
+    fn to_static_into(&self, dst: &mut Self::Static) {
+        if dst.capacity() < self.len() {
+            record_alloc_for::<T::Static>(self.len());
+        }
+        dst.clear();
+        dst.extend(self.iter().map(ToBoundedStatic::to_static));
+    }
+}
+
+#[cfg(all(feature = "ahash", feature = "std"))]
+/// Blanket [`IntoBoundedStatic`] impl for converting `ahash::AHashSet<T, S>` into `ahash::AHashSet<T, S>: 'static`.
+impl<T, S> IntoBoundedStatic for ahash::AHashSet<T, S>
+where
+    T: IntoBoundedStatic,
+    T::Static: Eq + std::hash::Hash,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher,
+{
+    type Static = ahash::AHashSet<T::Static, S::Static>;
+
+    fn into_static(self) -> Self::Static {
+        T::into_static_fast_path(fast_path::ahash_hash::SetIntoStatic(self))
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let hasher = self.hasher().to_static();
+        extend_into_with_budget(
+            |len| {
+                let mut collection = ahash::AHashSet::with_hasher(hasher);
+                collection.try_reserve(len)?;
+                Ok(collection)
+            },
+            self.into_iter(),
+            budget,
+        )
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+/// Blanket [`ToBoundedStatic`] impl for converting `hashbrown::HashMap<K, V, S>` to
+/// `hashbrown::HashMap<K, V, S>: 'static`.
+///
+/// The map is cloned, which copies the table without rehashing, if `to_static()` is a copy or a clone for the keys and
+/// values and the hasher, such as for `HashMap<String, u64>`.  The same applies to keys whose conversion only replaces
+/// their lifetime with `'static`, such as `Cow<'a, str>`, each of which is then converted in its bucket with the raw
+/// table API.  Otherwise every entry is converted and rehashed into a new map.
+impl<K, V, S> ToBoundedStatic for hashbrown::HashMap<K, V, S>
+where
+    K: ToBoundedStatic,
+    K::Static: Eq + std::hash::Hash,
+    V: ToBoundedStatic,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher,
+{
+    type Static = hashbrown::HashMap<K::Static, V::Static, S::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        K::to_static_fast_path(fast_path::hashbrown_hash::MapToStatic(self))
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let hasher = self.hasher().to_static();
+        extend_entries_with_budget(
+            |len| Ok(hashbrown::HashMap::with_capacity_and_hasher(len, hasher)),
+            self.iter(),
+            budget,
+        )
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_bytes_for::<(K::Static, V::Static)>(self.len());
+        for (k, v) in self {
+            k.add_static_cost(report);
+            v.add_static_cost(report);
+        }
+    }
+
+    /// The hasher of `dst` is kept.
+    fn to_static_into(&self, dst: &mut Self::Static) {
+        if dst.capacity() < self.len() {
+            record_alloc_for::<(K::Static, V::Static)>(self.len());
+        }
+        dst.clear();
+        dst.extend(self.iter().map(|(k, v)| (k.to_static(), v.to_static())));
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+/// Blanket [`IntoBoundedStatic`] impl for converting `hashbrown::HashMap<K, V, S>` into
+/// `hashbrown::HashMap<K, V, S>: 'static`.
+///
+/// The map is returned unchanged if `into_static()` is a no-op for the keys and values and `to_static()` is a clone
+/// for the hasher, and keys such as `Cow<'a, str>` are converted in their buckets without rehashing, as for
+/// [`ToBoundedStatic`].
+impl<K, V, S> IntoBoundedStatic for hashbrown::HashMap<K, V, S>
+where
+    K: IntoBoundedStatic,
+    K::Static: Eq + std::hash::Hash,
+    V: IntoBoundedStatic,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher,
+{
+    type Static = hashbrown::HashMap<K::Static, V::Static, S::Static>;
+
+    fn into_static(self) -> Self::Static {
+        K::into_static_fast_path(fast_path::hashbrown_hash::MapIntoStatic(self))
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let hasher = self.hasher().to_static();
+        extend_entries_into_with_budget(
+            |len| Ok(hashbrown::HashMap::with_capacity_and_hasher(len, hasher)),
+            self.into_iter(),
+            budget,
+        )
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+/// Blanket [`ToBoundedStatic`] impl for converting `hashbrown::HashSet<T, S>` to `hashbrown::HashSet<T, S>: 'static`.
+///
+/// The set is cloned, which copies the table without rehashing, if `to_static()` is a copy or a clone for the values
+/// and the hasher, such as for `HashSet<String>`, or if it only replaces the lifetime of the values, such as for
+/// `Cow<'a, str>`, as for `hashbrown::HashMap`.  Otherwise every value is converted and rehashed into a new set.
+impl<T, S> ToBoundedStatic for hashbrown::HashSet<T, S>
+where
+    T: ToBoundedStatic,
+    T::Static: Eq + std::hash::Hash,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher,
+{
+    type Static = hashbrown::HashSet<T::Static, S::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        T::to_static_fast_path(fast_path::hashbrown_hash::SetToStatic(self))
+    }
+
+    fn to_static_with_budget(&self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let hasher = self.hasher().to_static();
+        extend_with_budget(
+            |len| Ok(hashbrown::HashSet::with_capacity_and_hasher(len, hasher)),
+            self.iter(),
+            budget,
+        )
+    }
+
+    fn add_static_cost(&self, report: &mut CostReport) {
+        report.add_bytes_for::<T::Static>(self.len());
+        for value in self {
+            value.add_static_cost(report);
+        }
+    }
+
+    /// The hasher of `dst` is kept.
+    fn to_static_into(&self, dst: &mut Self::Static) {
+        if dst.capacity() < self.len() {
+            record_alloc_for::<T::Static>(self.len());
+        }
+        dst.clear();
+        dst.extend(self.iter().map(ToBoundedStatic::to_static));
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+/// Blanket [`IntoBoundedStatic`] impl for converting `hashbrown::HashSet<T, S>` into
+/// `hashbrown::HashSet<T, S>: 'static`.
+impl<T, S> IntoBoundedStatic for hashbrown::HashSet<T, S>
+where
+    T: IntoBoundedStatic,
+    T::Static: Eq + std::hash::Hash,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher,
+{
+    type Static = hashbrown::HashSet<T::Static, S::Static>;
+
+    fn into_static(self) -> Self::Static {
+        T::into_static_fast_path(fast_path::hashbrown_hash::SetIntoStatic(self))
+    }
+
+    fn into_static_with_budget(self, budget: &mut Budget) -> Result<Self::Static, TryStaticError> {
+        let hasher = self.hasher().to_static();
+        extend_into_with_budget(
+            |len| Ok(hashbrown::HashSet::with_capacity_and_hasher(len, hasher)),
+            self.into_iter(),
+            budget,
+        )
+    }
+}
+
+#[cfg(feature = "chrono")]
+/// Blanket [`ToBoundedStatic`] impl for converting `chrono::DateTime<Tz>` into `chrono::DateTime<Tz>: 'static`.
+impl<Tz> ToBoundedStatic for chrono::DateTime<Tz>
+where
+    Tz: ToBoundedStatic + chrono::TimeZone,
+    Tz::Static: chrono::TimeZone,
+{
+    type Static = chrono::DateTime<Tz::Static>;
+
+    fn to_static(&self) -> Self::Static {
+        self.with_timezone(&self.timezone().to_static())
+    }
+}
+
+#[cfg(feature = "chrono")]
+/// Blanket [`IntoBoundedStatic`] impl for converting `chrono::DateTime<Tz>` into `chrono::DateTime<Tz>: 'static`.
+impl<Tz> IntoBoundedStatic for chrono::DateTime<Tz>
+where
+    Tz: IntoBoundedStatic + chrono::TimeZone,
+    Tz::Static: chrono::TimeZone,
+{
+    type Static = chrono::DateTime<Tz::Static>;
+
+    fn into_static(self) -> Self::Static {
+        self.with_timezone(&self.timezone().into_static())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl_copy!(send chrono::FixedOffset);
+#[cfg(feature = "chrono")]
+impl_copy!(send chrono::Months);
+#[cfg(feature = "chrono")]
+impl_copy!(send chrono::TimeDelta);
+#[cfg(feature = "chrono")]
+impl_copy!(send chrono::Utc);
+#[cfg(feature = "chrono")]
+impl_copy!(send chrono::Month);
+#[cfg(feature = "chrono")]
+impl_copy!(send chrono::Weekday);
+#[cfg(feature = "chrono")]
+impl_copy!(send chrono::naive::Days);
+#[cfg(feature = "chrono")]
+impl_copy!(send chrono::naive::IsoWeek);
+#[cfg(feature = "chrono")]
+impl_copy!(send chrono::naive::NaiveDate);
+#[cfg(feature = "chrono")]
+impl_copy!(send chrono::naive::NaiveDateTime);
+#[cfg(feature = "chrono")]
+impl_copy!(send chrono::naive::NaiveTime);
+#[cfg(feature = "chrono-clock")]
+impl_copy!(send chrono::Local);
+// No implementation for chrono::NaiveWeek as it's not Copy nor Clone.
+
+#[cfg(feature = "rustc-hash")]
+impl_copy!(send rustc_hash::FxBuildHasher);
+#[cfg(feature = "foldhash")]
+impl_copy!(send foldhash::fast::RandomState);
+#[cfg(feature = "foldhash")]
+impl_copy!(send foldhash::fast::SeedableRandomState);
+#[cfg(feature = "foldhash")]
+impl_copy!(send foldhash::fast::FixedState);
+#[cfg(feature = "foldhash")]
+impl_copy!(send foldhash::quality::RandomState);
+#[cfg(feature = "foldhash")]
+impl_copy!(send foldhash::quality::SeedableRandomState);
+#[cfg(feature = "foldhash")]
+impl_copy!(send foldhash::quality::FixedState);
+
+/// No-op [`ToBounded`] impl for converting `&'static str` to `&'static str`.
+impl<'b, A: ?Sized> ToBounded<'b, A> for &'static str {
+    type Output = &'static str;
+
+    fn to_bounded_in(&self, _: &'b A) -> Self::Output {
+        self
+    }
+}
+
+/// No-op [`ToBounded`] impl for unit type `()`.
+impl<'b, A: ?Sized> ToBounded<'b, A> for () {
+    type Output = ();
+
+    fn to_bounded_in(&self, _: &'b A) -> Self::Output {}
+}
+
+/// Blanket [`ToBounded`] impl for converting `Option<T>` to `Option<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for Option<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = Option<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        self.as_ref().map(|value| value.to_bounded_in(alloc))
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting `Result<T, E>` to `Result<T, E>: 'b`.
+impl<'b, A, T, E> ToBounded<'b, A> for Result<T, E>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+    E: ToBounded<'b, A>,
+{
+    type Output = Result<T::Output, E::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        match self {
+            Ok(value) => Ok(value.to_bounded_in(alloc)),
+            Err(err) => Err(err.to_bounded_in(alloc)),
+        }
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting `[T; const N: usize]` to `[T; const N: usize]: 'b`.
+impl<'b, A, T, const N: usize> ToBounded<'b, A> for [T; N]
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = [T::Output; N];
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        core::array::from_fn(|i| self[i].to_bounded_in(alloc))
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting tuples `(T1, T2, ...)` to `(T1, T2, ..): 'b`.
+macro_rules! tuple_to_bounded {
+    () => ();
+    ($($name:ident,)+) => {
+        tuple_to_bounded! (
+            @gen $($name,)+,
+            concat!(
+                "Blanket [`ToBounded`] impl for converting tuple `",
+                stringify!(($($name,)+)), "` to `", stringify!(($($name,)+)), ": 'b`"
+            )
+        );
+    };
+    (@gen $($name:ident,)+, $doc:expr) => {
+        #[doc = $doc]
+        impl<'b, A: ?Sized, $($name: ToBounded<'b, A>),+> ToBounded<'b, A> for ($($name,)+) {
+            type Output = ($($name::Output,)+);
+
+            #[allow(non_snake_case)]
+            fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+                let ($(ref $name,)+) = *self;
+                ($($name.to_bounded_in(alloc),)+)
+            }
+        }
+        tuple_to_bounded! {@peel $($name,)+ }
+    };
+    (@peel $name:ident, $($other:ident,)*) => {tuple_to_bounded! { $($other,)* }};
+}
+
+tuple_to_bounded! { T11, T10, T9, T8, T7, T6, T5, T4, T3, T2, T1, T0, }
+
+/// Blanket [`ToBounded`] impl for converting `Cell<T>` to `Cell<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for Cell<T>
+where
+    A: ?Sized,
+    T: Copy + ToBounded<'b, A>,
+{
+    type Output = Cell<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        Cell::new(self.get().to_bounded_in(alloc))
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting `RefCell<T>` to `RefCell<T>: 'b`.
+///
+/// The value is immutably borrowed for the duration of the conversion.
+///
+/// # Panics
+///
+/// Panics if the value is currently mutably borrowed.
+impl<'b, A, T> ToBounded<'b, A> for RefCell<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = RefCell<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        RefCell::new(self.borrow().to_bounded_in(alloc))
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting `OnceCell<T>` to `OnceCell<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for OnceCell<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = OnceCell<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        self.get().map_or_else(OnceCell::new, |value| {
+            OnceCell::from(value.to_bounded_in(alloc))
+        })
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting `Range<T>` to `Range<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for Range<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = Range<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        self.start.to_bounded_in(alloc)..self.end.to_bounded_in(alloc)
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting `RangeFrom<T>` to `RangeFrom<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for RangeFrom<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = RangeFrom<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        self.start.to_bounded_in(alloc)..
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting `RangeTo<T>` to `RangeTo<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for RangeTo<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = RangeTo<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        ..self.end.to_bounded_in(alloc)
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting `RangeInclusive<T>` to `RangeInclusive<T>: 'b`.
+///
+/// Note that the resulting range is not exhausted, even if this range has been exhausted by iteration.
+impl<'b, A, T> ToBounded<'b, A> for RangeInclusive<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = RangeInclusive<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        self.start().to_bounded_in(alloc)..=self.end().to_bounded_in(alloc)
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting `RangeToInclusive<T>` to `RangeToInclusive<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for RangeToInclusive<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = RangeToInclusive<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        ..=self.end.to_bounded_in(alloc)
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting `Bound<T>` to `Bound<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for Bound<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = Bound<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        match self {
+            Self::Included(value) => Bound::Included(value.to_bounded_in(alloc)),
+            Self::Excluded(value) => Bound::Excluded(value.to_bounded_in(alloc)),
+            Self::Unbounded => Bound::Unbounded,
+        }
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting `ControlFlow<B, C>` to `ControlFlow<B, C>: 'b`.
+impl<'b, A, B, C> ToBounded<'b, A> for ControlFlow<B, C>
+where
+    A: ?Sized,
+    B: ToBounded<'b, A>,
+    C: ToBounded<'b, A>,
+{
+    type Output = ControlFlow<B::Output, C::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        match self {
+            Self::Continue(value) => ControlFlow::Continue(value.to_bounded_in(alloc)),
+            Self::Break(value) => ControlFlow::Break(value.to_bounded_in(alloc)),
+        }
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting `Reverse<T>` to `Reverse<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for Reverse<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = Reverse<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        Reverse(self.0.to_bounded_in(alloc))
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting `Wrapping<T>` to `Wrapping<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for Wrapping<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = Wrapping<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        Wrapping(self.0.to_bounded_in(alloc))
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting `Saturating<T>` to `Saturating<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for Saturating<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = Saturating<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        Saturating(self.0.to_bounded_in(alloc))
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting `ManuallyDrop<T>` to `ManuallyDrop<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for ManuallyDrop<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = ManuallyDrop<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        ManuallyDrop::new((**self).to_bounded_in(alloc))
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting `Poll<T>` to `Poll<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for Poll<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = Poll<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        match self {
+            Self::Ready(value) => Poll::Ready(value.to_bounded_in(alloc)),
+            Self::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Blanket [`ToBounded`] impl for converting `PhantomData<T>` to `PhantomData<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for PhantomData<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = PhantomData<T::Output>;
+
+    fn to_bounded_in(&self, _: &'b A) -> Self::Output {
+        PhantomData
+    }
+}
+
+crate::__to_bounded!(clone impl[H] BuildHasherDefault<H> where H: 'static);
+
+#[cfg(feature = "alloc")]
+/// [`ToBounded`] impl for converting `Cow<'a, str>` to `Cow<'b, str>`, copying the string with the allocator.
+impl<'b, A> ToBounded<'b, A> for Cow<'_, str>
+where
+    A: BoundedAlloc<'b> + ?Sized,
+{
+    type Output = Cow<'b, str>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        alloc.alloc_str(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// [`ToBounded`] impl for converting `Cow<'a, [T]>` to `Cow<'b, [T]>`, copying the slice with the allocator.
+impl<'b, A, T> ToBounded<'b, A> for Cow<'_, [T]>
+where
+    A: BoundedAlloc<'b> + ?Sized,
+    T: Copy + 'static,
+{
+    type Output = Cow<'b, [T]>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        alloc.alloc_slice(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// [`ToBounded`] impl for converting `StaticCow<'a, str>` to `StaticCow<'b, str>`.
+///
+/// Data borrowed for the `'static` lifetime is kept without copying.
+impl<'b, A> ToBounded<'b, A> for StaticCow<'_, str>
+where
+    A: BoundedAlloc<'b> + ?Sized,
+{
+    type Output = StaticCow<'b, str>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        match self {
+            StaticCow::Static(value) => StaticCow::Static(value),
+            StaticCow::Borrowed(_) | StaticCow::Owned(_) => alloc.alloc_str(self).into(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// [`ToBounded`] impl for converting `StaticCow<'a, [T]>` to `StaticCow<'b, [T]>`.
+///
+/// Data borrowed for the `'static` lifetime is kept without copying.
+impl<'b, A, T> ToBounded<'b, A> for StaticCow<'_, [T]>
+where
+    A: BoundedAlloc<'b> + ?Sized,
+    T: Copy + ToBoundedStatic<Static = T> + 'static,
+{
+    type Output = StaticCow<'b, [T]>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        match self {
+            StaticCow::Static(value) => StaticCow::Static(value),
+            StaticCow::Borrowed(_) | StaticCow::Owned(_) => alloc.alloc_slice(self).into(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// [`ToBounded`] impl for `String`.
+impl<'b, A: ?Sized> ToBounded<'b, A> for String {
+    type Output = Self;
+
+    fn to_bounded_in(&self, _: &'b A) -> Self::Output {
+        self.clone()
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToBounded`] impl for converting `Vec<T>` to `Vec<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for Vec<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = Vec<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        self.iter()
+            .map(|value| value.to_bounded_in(alloc))
+            .collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToBounded`] impl for converting `Box<T>` to `Box<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for Box<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = Box<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        Box::new(self.as_ref().to_bounded_in(alloc))
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToBounded`] impl for converting `Pin<Box<T>>` to `Pin<Box<T>>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for Pin<Box<T>>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = Pin<Box<T::Output>>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        Box::pin(self.as_ref().get_ref().to_bounded_in(alloc))
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToBounded`] impl for converting `Rc<T>` to `Rc<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for Rc<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = Rc<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        Rc::new(self.as_ref().to_bounded_in(alloc))
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+/// Blanket [`ToBounded`] impl for converting `Arc<T>` to `Arc<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for Arc<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = Arc<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        Arc::new(self.as_ref().to_bounded_in(alloc))
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToBounded`] impl for converting `VecDeque<T>` to `VecDeque<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for VecDeque<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = VecDeque<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        self.iter()
+            .map(|value| value.to_bounded_in(alloc))
+            .collect()
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToBounded`] impl for converting `LinkedList<T>` to `LinkedList<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for LinkedList<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = LinkedList<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        self.iter()
+            .map(|value| value.to_bounded_in(alloc))
+            .collect()
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToBounded`] impl for converting `BinaryHeap<T>` to `BinaryHeap<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for BinaryHeap<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+    T::Output: Ord,
+    T::Static: Ord,
+{
+    type Output = BinaryHeap<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        self.iter()
+            .map(|value| value.to_bounded_in(alloc))
+            .collect()
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToBounded`] impl for converting `BTreeMap<K, V>` to `BTreeMap<K, V>: 'b`.
+impl<'b, A, K, V> ToBounded<'b, A> for BTreeMap<K, V>
+where
+    A: ?Sized,
+    K: ToBounded<'b, A>,
+    K::Output: Ord,
+    K::Static: Ord,
+    V: ToBounded<'b, A>,
+{
+    type Output = BTreeMap<K::Output, V::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        self.iter()
+            .map(|(key, value)| (key.to_bounded_in(alloc), value.to_bounded_in(alloc)))
+            .collect()
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToBounded`] impl for converting `BTreeSet<T>` to `BTreeSet<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for BTreeSet<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+    T::Output: Ord,
+    T::Static: Ord,
+{
+    type Output = BTreeSet<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        self.iter()
+            .map(|value| value.to_bounded_in(alloc))
+            .collect()
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToBounded`] impl for converting `HashMap<K, V, S>` to `HashMap<K, V, S>: 'b`.
+impl<'b, A, K, V, S> ToBounded<'b, A> for std::collections::HashMap<K, V, S>
+where
+    A: ?Sized,
+    K: ToBounded<'b, A>,
+    K::Output: Eq + std::hash::Hash,
+    K::Static: Eq + std::hash::Hash,
+    V: ToBounded<'b, A>,
+    S: std::hash::BuildHasher + Clone + ToBoundedStatic + 'b,
+    S::Static: std::hash::BuildHasher,
+{
+    type Output = std::collections::HashMap<K::Output, V::Output, S>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        let mut map =
+            std::collections::HashMap::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        map.extend(
+            self.iter()
+                .map(|(key, value)| (key.to_bounded_in(alloc), value.to_bounded_in(alloc))),
+        );
+        map
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToBounded`] impl for converting `HashSet<T, S>` to `HashSet<T, S>: 'b`.
+impl<'b, A, T, S> ToBounded<'b, A> for std::collections::HashSet<T, S>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+    T::Output: Eq + std::hash::Hash,
+    T::Static: Eq + std::hash::Hash,
+    S: std::hash::BuildHasher + Clone + ToBoundedStatic + 'b,
+    S::Static: std::hash::BuildHasher,
+{
+    type Output = std::collections::HashSet<T::Output, S>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        let mut set =
+            std::collections::HashSet::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        set.extend(self.iter().map(|value| value.to_bounded_in(alloc)));
+        set
+    }
+}
+
+#[cfg(feature = "std")]
+crate::__to_bounded!(clone impl[] std::collections::hash_map::RandomState);
+
+#[cfg(feature = "std")]
+/// Blanket [`ToBounded`] impl for converting `Mutex<T>` to `Mutex<T>: 'b`.
+///
+/// The lock is held for the duration of the conversion and so calling this whilst the current thread holds the lock
+/// will deadlock.
+///
+/// If the lock is poisoned the poison is ignored and the inner value is converted, the resulting `Mutex` is not
+/// poisoned.
+impl<'b, A, T> ToBounded<'b, A> for std::sync::Mutex<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = std::sync::Mutex<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        let guard = self
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        std::sync::Mutex::new(guard.to_bounded_in(alloc))
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToBounded`] impl for converting `RwLock<T>` to `RwLock<T>: 'b`.
+///
+/// A read lock is held for the duration of the conversion and so calling this whilst the current thread holds the
+/// write lock will deadlock.
+///
+/// If the lock is poisoned the poison is ignored and the inner value is converted, the resulting `RwLock` is not
+/// poisoned.
+impl<'b, A, T> ToBounded<'b, A> for std::sync::RwLock<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = std::sync::RwLock<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        let guard = self
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        std::sync::RwLock::new(guard.to_bounded_in(alloc))
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToBounded`] impl for converting `OnceLock<T>` to `OnceLock<T>: 'b`.
+impl<'b, A, T> ToBounded<'b, A> for std::sync::OnceLock<T>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+{
+    type Output = std::sync::OnceLock<T::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        self.get().map_or_else(std::sync::OnceLock::new, |value| {
+            std::sync::OnceLock::from(value.to_bounded_in(alloc))
+        })
+    }
+}
+
+#[cfg(all(feature = "std", feature = "anyhow"))]
+/// [`ToBounded`] impl for `anyhow::Error`, which captures the error and its chain as a
+/// [`StaticError`](error::StaticError).
+impl<'b, A: ?Sized> ToBounded<'b, A> for anyhow::Error {
+    type Output = Self;
+
+    fn to_bounded_in(&self, _: &'b A) -> Self::Output {
+        self.to_static()
+    }
+}
+
+#[cfg(all(feature = "std", feature = "eyre"))]
+/// [`ToBounded`] impl for `eyre::Report`, which captures the error and its chain as a
+/// [`StaticError`](error::StaticError).
+impl<'b, A: ?Sized> ToBounded<'b, A> for eyre::Report {
+    type Output = Self;
+
+    fn to_bounded_in(&self, _: &'b A) -> Self::Output {
+        self.to_static()
+    }
+}
+
+#[cfg(feature = "smol_str")]
+crate::__to_bounded!(clone impl[] smol_str::SmolStr);
+
+/// [`ToBounded`] impl for `smallvec::SmallVec`.
+#[cfg(feature = "smallvec")]
+impl<'b, A, B, T> ToBounded<'b, A> for smallvec::SmallVec<B>
+where
+    A: ?Sized,
+    B: smallvec::Array<Item = T> + ToBounded<'b, A>,
+    B::Output: smallvec::Array<Item = T::Output>,
+    <B as ToBoundedStatic>::Static: smallvec::Array<Item = T::Static>,
+    T: ToBounded<'b, A>,
+{
+    type Output = smallvec::SmallVec<B::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        self.iter()
+            .map(|value| value.to_bounded_in(alloc))
+            .collect()
+    }
+}
+
+#[cfg(feature = "smartstring")]
+crate::__to_bounded!(
+    clone impl[Mode] smartstring::SmartString<Mode> where Mode: smartstring::SmartStringMode + 'static
+);
+
+#[cfg(feature = "ahash")]
+crate::__to_bounded!(clone impl[] ahash::RandomState);
+
+#[cfg(all(feature = "ahash", feature = "std"))]
+/// Blanket [`ToBounded`] impl for converting `ahash::AHashMap<K, V, S>` to `ahash::AHashMap<K, V, S>: 'b`.
+impl<'b, A, K, V, S> ToBounded<'b, A> for ahash::AHashMap<K, V, S>
+where
+    A: ?Sized,
+    K: ToBounded<'b, A>,
+    K::Output: Eq + std::hash::Hash,
+    K::Static: Eq + std::hash::Hash,
+    V: ToBounded<'b, A>,
+    S: std::hash::BuildHasher + Clone + ToBoundedStatic + 'b,
+    S::Static: std::hash::BuildHasher,
+{
+    type Output = ahash::AHashMap<K::Output, V::Output, S>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        let mut map = ahash::AHashMap::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        map.extend(
+            self.iter()
+                .map(|(key, value)| (key.to_bounded_in(alloc), value.to_bounded_in(alloc))),
+        );
+        map
+    }
+}
+
+#[cfg(all(feature = "ahash", feature = "std"))]
+/// Blanket [`ToBounded`] impl for converting `ahash::AHashSet<T, S>` to `ahash::AHashSet<T, S>: 'b`.
+impl<'b, A, T, S> ToBounded<'b, A> for ahash::AHashSet<T, S>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+    T::Output: Eq + std::hash::Hash,
+    T::Static: Eq + std::hash::Hash,
+    S: std::hash::BuildHasher + Clone + ToBoundedStatic + 'b,
+    S::Static: std::hash::BuildHasher,
+{
+    type Output = ahash::AHashSet<T::Output, S>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        let mut set = ahash::AHashSet::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        set.extend(self.iter().map(|value| value.to_bounded_in(alloc)));
+        set
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+/// Blanket [`ToBounded`] impl for converting `hashbrown::HashMap<K, V, S>` to `hashbrown::HashMap<K, V, S>: 'b`.
+impl<'b, A, K, V, S> ToBounded<'b, A> for hashbrown::HashMap<K, V, S>
+where
+    A: ?Sized,
+    K: ToBounded<'b, A>,
+    K::Output: Eq + std::hash::Hash,
+    K::Static: Eq + std::hash::Hash,
+    V: ToBounded<'b, A>,
+    S: std::hash::BuildHasher + Clone + ToBoundedStatic + 'b,
+    S::Static: std::hash::BuildHasher,
+{
+    type Output = hashbrown::HashMap<K::Output, V::Output, S>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        let mut map =
+            hashbrown::HashMap::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        map.extend(
+            self.iter()
+                .map(|(key, value)| (key.to_bounded_in(alloc), value.to_bounded_in(alloc))),
+        );
+        map
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+/// Blanket [`ToBounded`] impl for converting `hashbrown::HashSet<T, S>` to `hashbrown::HashSet<T, S>: 'b`.
+impl<'b, A, T, S> ToBounded<'b, A> for hashbrown::HashSet<T, S>
+where
+    A: ?Sized,
+    T: ToBounded<'b, A>,
+    T::Output: Eq + std::hash::Hash,
+    T::Static: Eq + std::hash::Hash,
+    S: std::hash::BuildHasher + Clone + ToBoundedStatic + 'b,
+    S::Static: std::hash::BuildHasher,
+{
+    type Output = hashbrown::HashSet<T::Output, S>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        let mut set =
+            hashbrown::HashSet::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        set.extend(self.iter().map(|value| value.to_bounded_in(alloc)));
+        set
+    }
+}
+
+#[cfg(feature = "chrono")]
+/// Blanket [`ToBounded`] impl for converting `chrono::DateTime<Tz>` to `chrono::DateTime<Tz>: 'b`.
+impl<'b, A, Tz> ToBounded<'b, A> for chrono::DateTime<Tz>
+where
+    A: ?Sized,
+    Tz: ToBounded<'b, A> + chrono::TimeZone,
+    Tz::Output: chrono::TimeZone,
+    Tz::Static: chrono::TimeZone,
+{
+    type Output = chrono::DateTime<Tz::Output>;
+
+    fn to_bounded_in(&self, alloc: &'b A) -> Self::Output {
+        self.with_timezone(&self.timezone().to_bounded_in(alloc))
+    }
+}
+
+/// No-op [`ToStaticAs`] impl for converting `&'static str` to `&'static str`.
+impl<R: StaticRepr> ToStaticAs<R> for &'static str {
+    type Static = &'static str;
+
+    fn to_static_as(&self, _: R) -> Self::Static {
+        self
+    }
+}
+
+/// No-op [`ToStaticAs`] impl for unit type `()`.
+impl<R: StaticRepr> ToStaticAs<R> for () {
+    type Static = ();
+
+    fn to_static_as(&self, _: R) -> Self::Static {}
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `Option<T>` to `Option<T>: 'static`.
+impl<R, T> ToStaticAs<R> for Option<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = Option<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        self.as_ref().map(|value| value.to_static_as(repr))
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `Result<T, E>` to `Result<T, E>: 'static`.
+impl<R, T, E> ToStaticAs<R> for Result<T, E>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+    E: ToStaticAs<R>,
+{
+    type Static = Result<T::Static, E::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        match self {
+            Ok(value) => Ok(value.to_static_as(repr)),
+            Err(err) => Err(err.to_static_as(repr)),
+        }
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `[T; const N: usize]` to `[T; const N: usize]: 'static`.
+impl<R, T, const N: usize> ToStaticAs<R> for [T; N]
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = [T::Static; N];
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        core::array::from_fn(|i| self[i].to_static_as(repr))
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting tuples `(T1, T2, ...)` to `(T1, T2, ..): 'static`.
+macro_rules! tuple_to_static_as {
+    () => ();
+    ($($name:ident,)+) => {
+        tuple_to_static_as! (
+            @gen $($name,)+,
+            concat!(
+                "Blanket [`ToStaticAs`] impl for converting tuple `",
+                stringify!(($($name,)+)), "` to `", stringify!(($($name,)+)), ": 'static`"
+            )
+        );
+    };
+    (@gen $($name:ident,)+, $doc:expr) => {
+        #[doc = $doc]
+        impl<R: StaticRepr, $($name: ToStaticAs<R>),+> ToStaticAs<R> for ($($name,)+) {
+            type Static = ($($name::Static,)+);
+
+            #[allow(non_snake_case)]
+            fn to_static_as(&self, repr: R) -> Self::Static {
+                let ($(ref $name,)+) = *self;
+                ($($name.to_static_as(repr),)+)
+            }
+        }
+        tuple_to_static_as! {@peel $($name,)+ }
+    };
+    (@peel $name:ident, $($other:ident,)*) => {tuple_to_static_as! { $($other,)* }};
+}
+
+tuple_to_static_as! { T11, T10, T9, T8, T7, T6, T5, T4, T3, T2, T1, T0, }
+
+/// Blanket [`ToStaticAs`] impl for converting `Cell<T>` to `Cell<T>: 'static`.
+impl<R, T> ToStaticAs<R> for Cell<T>
+where
+    R: StaticRepr,
+    T: Copy + ToStaticAs<R>,
+{
+    type Static = Cell<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        Cell::new(self.get().to_static_as(repr))
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `RefCell<T>` to `RefCell<T>: 'static`.
+///
+/// The value is immutably borrowed for the duration of the conversion.
+///
+/// # Panics
+///
+/// Panics if the value is currently mutably borrowed.
+impl<R, T> ToStaticAs<R> for RefCell<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = RefCell<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        RefCell::new(self.borrow().to_static_as(repr))
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `OnceCell<T>` to `OnceCell<T>: 'static`.
+impl<R, T> ToStaticAs<R> for OnceCell<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = OnceCell<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        self.get().map_or_else(OnceCell::new, |value| {
+            OnceCell::from(value.to_static_as(repr))
+        })
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `Range<T>` to `Range<T>: 'static`.
+impl<R, T> ToStaticAs<R> for Range<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = Range<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        self.start.to_static_as(repr)..self.end.to_static_as(repr)
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `RangeFrom<T>` to `RangeFrom<T>: 'static`.
+impl<R, T> ToStaticAs<R> for RangeFrom<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = RangeFrom<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        self.start.to_static_as(repr)..
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `RangeTo<T>` to `RangeTo<T>: 'static`.
+impl<R, T> ToStaticAs<R> for RangeTo<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = RangeTo<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        ..self.end.to_static_as(repr)
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `RangeInclusive<T>` to `RangeInclusive<T>: 'static`.
+///
+/// Note that the resulting range is not exhausted, even if this range has been exhausted by iteration.
+impl<R, T> ToStaticAs<R> for RangeInclusive<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = RangeInclusive<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        self.start().to_static_as(repr)..=self.end().to_static_as(repr)
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `RangeToInclusive<T>` to `RangeToInclusive<T>: 'static`.
+impl<R, T> ToStaticAs<R> for RangeToInclusive<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = RangeToInclusive<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        ..=self.end.to_static_as(repr)
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `Bound<T>` to `Bound<T>: 'static`.
+impl<R, T> ToStaticAs<R> for Bound<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = Bound<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        match self {
+            Self::Included(value) => Bound::Included(value.to_static_as(repr)),
+            Self::Excluded(value) => Bound::Excluded(value.to_static_as(repr)),
+            Self::Unbounded => Bound::Unbounded,
+        }
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `ControlFlow<B, C>` to `ControlFlow<B, C>: 'static`.
+impl<R, B, C> ToStaticAs<R> for ControlFlow<B, C>
+where
+    R: StaticRepr,
+    B: ToStaticAs<R>,
+    C: ToStaticAs<R>,
+{
+    type Static = ControlFlow<B::Static, C::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        match self {
+            Self::Continue(value) => ControlFlow::Continue(value.to_static_as(repr)),
+            Self::Break(value) => ControlFlow::Break(value.to_static_as(repr)),
+        }
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `Reverse<T>` to `Reverse<T>: 'static`.
+impl<R, T> ToStaticAs<R> for Reverse<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = Reverse<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        Reverse(self.0.to_static_as(repr))
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `Wrapping<T>` to `Wrapping<T>: 'static`.
+impl<R, T> ToStaticAs<R> for Wrapping<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = Wrapping<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        Wrapping(self.0.to_static_as(repr))
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `Saturating<T>` to `Saturating<T>: 'static`.
+impl<R, T> ToStaticAs<R> for Saturating<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = Saturating<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        Saturating(self.0.to_static_as(repr))
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `ManuallyDrop<T>` to `ManuallyDrop<T>: 'static`.
+impl<R, T> ToStaticAs<R> for ManuallyDrop<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = ManuallyDrop<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        ManuallyDrop::new((**self).to_static_as(repr))
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `Poll<T>` to `Poll<T>: 'static`.
+impl<R, T> ToStaticAs<R> for Poll<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = Poll<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        match self {
+            Self::Ready(value) => Poll::Ready(value.to_static_as(repr)),
+            Self::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Blanket [`ToStaticAs`] impl for converting `PhantomData<T>` to `PhantomData<T>: 'static`.
+impl<R, T> ToStaticAs<R> for PhantomData<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = PhantomData<T::Static>;
+
+    fn to_static_as(&self, _: R) -> Self::Static {
+        PhantomData
+    }
+}
+
+crate::__to_static_as!(clone impl[H] BuildHasherDefault<H> where H: 'static);
+
+#[cfg(feature = "alloc")]
+/// [`ToStaticAs`] impl for converting `Cow<'a, str>` to the string representation of the policy.
+impl<R: StaticRepr> ToStaticAs<R> for Cow<'_, str> {
+    type Static = R::Str;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        repr.str(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// [`ToStaticAs`] impl for converting `StaticCow<'a, str>` to the string representation of the policy.
+impl<R: StaticRepr> ToStaticAs<R> for StaticCow<'_, str> {
+    type Static = R::Str;
+
+    fn to_static_as(&self, repr: R) -> <Self as ToStaticAs<R>>::Static {
+        repr.str(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// [`ToStaticAs`] impl for converting `String` to the string representation of the policy.
+impl<R: StaticRepr> ToStaticAs<R> for String {
+    type Static = R::Str;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        repr.str(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToStaticAs`] impl for converting `Cow<'a, [T]>` to the sequence representation of the policy.
+impl<R, T> ToStaticAs<R> for Cow<'_, [T]>
+where
+    R: StaticRepr,
+    T: Clone + ToStaticAs<R>,
+{
+    type Static = R::Slice<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        repr.slice(self.iter().map(|value| value.to_static_as(repr)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToStaticAs`] impl for converting `Vec<T>` to the sequence representation of the policy.
+impl<R, T> ToStaticAs<R> for Vec<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = R::Slice<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        repr.slice(self.iter().map(|value| value.to_static_as(repr)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToStaticAs`] impl for converting `Box<T>` to `Box<T>: 'static`.
+impl<R, T> ToStaticAs<R> for Box<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = Box<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        Box::new(self.as_ref().to_static_as(repr))
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToStaticAs`] impl for converting `Pin<Box<T>>` to `Pin<Box<T>>: 'static`.
+impl<R, T> ToStaticAs<R> for Pin<Box<T>>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = Pin<Box<T::Static>>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        Box::pin(self.as_ref().get_ref().to_static_as(repr))
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToStaticAs`] impl for converting `Rc<T>` to `Rc<T>: 'static`.
+impl<R, T> ToStaticAs<R> for Rc<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = Rc<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        Rc::new(self.as_ref().to_static_as(repr))
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+/// Blanket [`ToStaticAs`] impl for converting `Arc<T>` to `Arc<T>: 'static`.
+impl<R, T> ToStaticAs<R> for Arc<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = Arc<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        Arc::new(self.as_ref().to_static_as(repr))
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToStaticAs`] impl for converting `VecDeque<T>` to `VecDeque<T>: 'static`.
+impl<R, T> ToStaticAs<R> for VecDeque<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = VecDeque<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        self.iter().map(|value| value.to_static_as(repr)).collect()
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToStaticAs`] impl for converting `LinkedList<T>` to `LinkedList<T>: 'static`.
+impl<R, T> ToStaticAs<R> for LinkedList<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = LinkedList<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        self.iter().map(|value| value.to_static_as(repr)).collect()
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToStaticAs`] impl for converting `BinaryHeap<T>` to `BinaryHeap<T>: 'static`.
+impl<R, T> ToStaticAs<R> for BinaryHeap<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+    T::Static: Ord,
+{
+    type Static = BinaryHeap<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        self.iter().map(|value| value.to_static_as(repr)).collect()
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToStaticAs`] impl for converting `BTreeMap<K, V>` to `BTreeMap<K, V>: 'static`.
+impl<R, K, V> ToStaticAs<R> for BTreeMap<K, V>
+where
+    R: StaticRepr,
+    K: ToStaticAs<R>,
+    K::Static: Ord,
+    V: ToStaticAs<R>,
+{
+    type Static = BTreeMap<K::Static, V::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        self.iter()
+            .map(|(key, value)| (key.to_static_as(repr), value.to_static_as(repr)))
+            .collect()
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToStaticAs`] impl for converting `BTreeSet<T>` to `BTreeSet<T>: 'static`.
+impl<R, T> ToStaticAs<R> for BTreeSet<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+    T::Static: Ord,
+{
+    type Static = BTreeSet<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        self.iter().map(|value| value.to_static_as(repr)).collect()
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToStaticAs`] impl for converting `HashMap<K, V, S>` to `HashMap<K, V, S>: 'static`.
+impl<R, K, V, S> ToStaticAs<R> for std::collections::HashMap<K, V, S>
+where
+    R: StaticRepr,
+    K: ToStaticAs<R>,
+    K::Static: Eq + std::hash::Hash,
+    V: ToStaticAs<R>,
+    S: std::hash::BuildHasher + Clone + 'static,
+{
+    type Static = std::collections::HashMap<K::Static, V::Static, S>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        let mut map =
+            std::collections::HashMap::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        map.extend(
+            self.iter()
+                .map(|(key, value)| (key.to_static_as(repr), value.to_static_as(repr))),
+        );
+        map
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToStaticAs`] impl for converting `HashSet<T, S>` to `HashSet<T, S>: 'static`.
+impl<R, T, S> ToStaticAs<R> for std::collections::HashSet<T, S>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+    T::Static: Eq + std::hash::Hash,
+    S: std::hash::BuildHasher + Clone + 'static,
+{
+    type Static = std::collections::HashSet<T::Static, S>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        let mut set =
+            std::collections::HashSet::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        set.extend(self.iter().map(|value| value.to_static_as(repr)));
+        set
+    }
+}
+
+#[cfg(feature = "std")]
+crate::__to_static_as!(clone impl[] std::collections::hash_map::RandomState);
+
+#[cfg(feature = "std")]
+/// Blanket [`ToStaticAs`] impl for converting `Mutex<T>` to `Mutex<T>: 'static`.
+///
+/// The lock is held for the duration of the conversion and so calling this whilst the current thread holds the lock
+/// will deadlock.
+///
+/// If the lock is poisoned the poison is ignored and the inner value is converted, the resulting `Mutex` is not
+/// poisoned.
+impl<R, T> ToStaticAs<R> for std::sync::Mutex<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = std::sync::Mutex<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        let guard = self
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        std::sync::Mutex::new(guard.to_static_as(repr))
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToStaticAs`] impl for converting `RwLock<T>` to `RwLock<T>: 'static`.
+///
+/// A read lock is held for the duration of the conversion and so calling this whilst the current thread holds the
+/// write lock will deadlock.
+///
+/// If the lock is poisoned the poison is ignored and the inner value is converted, the resulting `RwLock` is not
+/// poisoned.
+impl<R, T> ToStaticAs<R> for std::sync::RwLock<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = std::sync::RwLock<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        let guard = self
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        std::sync::RwLock::new(guard.to_static_as(repr))
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToStaticAs`] impl for converting `OnceLock<T>` to `OnceLock<T>: 'static`.
+impl<R, T> ToStaticAs<R> for std::sync::OnceLock<T>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+{
+    type Static = std::sync::OnceLock<T::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        self.get().map_or_else(std::sync::OnceLock::new, |value| {
+            std::sync::OnceLock::from(value.to_static_as(repr))
+        })
+    }
+}
+
+#[cfg(all(feature = "std", feature = "anyhow"))]
+/// [`ToStaticAs`] impl for `anyhow::Error`, which captures the error and its chain as a
+/// [`StaticError`](error::StaticError).
+impl<R: StaticRepr> ToStaticAs<R> for anyhow::Error {
+    type Static = Self;
+
+    fn to_static_as(&self, _: R) -> Self::Static {
+        self.to_static()
+    }
+}
+
+#[cfg(all(feature = "std", feature = "eyre"))]
+/// [`ToStaticAs`] impl for `eyre::Report`, which captures the error and its chain as a
+/// [`StaticError`](error::StaticError).
+impl<R: StaticRepr> ToStaticAs<R> for eyre::Report {
+    type Static = Self;
+
+    fn to_static_as(&self, _: R) -> Self::Static {
+        self.to_static()
+    }
+}
+
+/// [`ToStaticAs`] impl for `smallvec::SmallVec`.
+#[cfg(feature = "smallvec")]
+impl<R, B, T> ToStaticAs<R> for smallvec::SmallVec<B>
+where
+    R: StaticRepr,
+    B: smallvec::Array<Item = T> + ToStaticAs<R>,
+    B::Static: smallvec::Array<Item = T::Static>,
+    T: ToStaticAs<R>,
+{
+    type Static = smallvec::SmallVec<B::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        self.iter().map(|value| value.to_static_as(repr)).collect()
+    }
+}
+
+#[cfg(feature = "smartstring")]
+/// [`ToStaticAs`] impl for converting `smartstring::SmartString` to the string representation of the policy.
+impl<R, Mode> ToStaticAs<R> for smartstring::SmartString<Mode>
+where
+    R: StaticRepr,
+    Mode: smartstring::SmartStringMode,
+{
+    type Static = R::Str;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        repr.str(self)
+    }
+}
+
+#[cfg(feature = "ahash")]
+crate::__to_static_as!(clone impl[] ahash::RandomState);
+
+#[cfg(all(feature = "ahash", feature = "std"))]
+/// Blanket [`ToStaticAs`] impl for converting `ahash::AHashMap<K, V, S>` to `ahash::AHashMap<K, V, S>: 'static`.
+impl<R, K, V, S> ToStaticAs<R> for ahash::AHashMap<K, V, S>
+where
+    R: StaticRepr,
+    K: ToStaticAs<R>,
+    K::Static: Eq + std::hash::Hash,
+    V: ToStaticAs<R>,
+    S: std::hash::BuildHasher + Clone + 'static,
+{
+    type Static = ahash::AHashMap<K::Static, V::Static, S>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        let mut map = ahash::AHashMap::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        map.extend(
+            self.iter()
+                .map(|(key, value)| (key.to_static_as(repr), value.to_static_as(repr))),
+        );
+        map
+    }
+}
+
+#[cfg(all(feature = "ahash", feature = "std"))]
+/// Blanket [`ToStaticAs`] impl for converting `ahash::AHashSet<T, S>` to `ahash::AHashSet<T, S>: 'static`.
+impl<R, T, S> ToStaticAs<R> for ahash::AHashSet<T, S>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+    T::Static: Eq + std::hash::Hash,
+    S: std::hash::BuildHasher + Clone + 'static,
+{
+    type Static = ahash::AHashSet<T::Static, S>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        let mut set = ahash::AHashSet::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        set.extend(self.iter().map(|value| value.to_static_as(repr)));
+        set
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+/// Blanket [`ToStaticAs`] impl for converting `hashbrown::HashMap<K, V, S>` to `hashbrown::HashMap<K, V, S>: 'static`.
+impl<R, K, V, S> ToStaticAs<R> for hashbrown::HashMap<K, V, S>
+where
+    R: StaticRepr,
+    K: ToStaticAs<R>,
+    K::Static: Eq + std::hash::Hash,
+    V: ToStaticAs<R>,
+    S: std::hash::BuildHasher + Clone + 'static,
+{
+    type Static = hashbrown::HashMap<K::Static, V::Static, S>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        let mut map =
+            hashbrown::HashMap::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        map.extend(
+            self.iter()
+                .map(|(key, value)| (key.to_static_as(repr), value.to_static_as(repr))),
+        );
+        map
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+/// Blanket [`ToStaticAs`] impl for converting `hashbrown::HashSet<T, S>` to `hashbrown::HashSet<T, S>: 'static`.
+impl<R, T, S> ToStaticAs<R> for hashbrown::HashSet<T, S>
+where
+    R: StaticRepr,
+    T: ToStaticAs<R>,
+    T::Static: Eq + std::hash::Hash,
+    S: std::hash::BuildHasher + Clone + 'static,
+{
+    type Static = hashbrown::HashSet<T::Static, S>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        let mut set =
+            hashbrown::HashSet::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        set.extend(self.iter().map(|value| value.to_static_as(repr)));
+        set
+    }
+}
+
+#[cfg(feature = "chrono")]
+/// Blanket [`ToStaticAs`] impl for converting `chrono::DateTime<Tz>` to `chrono::DateTime<Tz>: 'static`.
+impl<R, Tz> ToStaticAs<R> for chrono::DateTime<Tz>
+where
+    R: StaticRepr,
+    Tz: ToStaticAs<R> + chrono::TimeZone,
+    Tz::Static: chrono::TimeZone,
+{
+    type Static = chrono::DateTime<Tz::Static>;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        self.with_timezone(&self.timezone().to_static_as(repr))
+    }
+}
+
+#[cfg(feature = "smol_str")]
+/// [`ToStaticAs`] impl for converting `smol_str::SmolStr` to the string representation of the policy.
+impl<R: StaticRepr> ToStaticAs<R> for smol_str::SmolStr {
+    type Static = R::Str;
+
+    fn to_static_as(&self, repr: R) -> Self::Static {
+        repr.str(self)
+    }
+}
+
+/// No-op [`ToSendStatic`] impl for converting `&'static str` to `&'static str`.
+impl ToSendStatic for &'static str {
+    type Static = &'static str;
+
+    fn to_send_static(&self) -> Self::Static {
+        self
+    }
+}
+
+/// No-op [`ToSendStatic`] impl for unit type `()`.
+impl ToSendStatic for () {
+    type Static = ();
+
+    fn to_send_static(&self) -> Self::Static {}
+}
+
+/// Blanket [`ToSendStatic`] impl for converting `Option<T>` to `Option<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for Option<T>
+where
+    T: ToSendStatic,
+{
+    type Static = Option<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        self.as_ref().map(ToSendStatic::to_send_static)
+    }
+}
+
+/// Blanket [`ToSendStatic`] impl for converting `Result<T, E>` to `Result<T, E>: Send + Sync + 'static`.
+impl<T, E> ToSendStatic for Result<T, E>
+where
+    T: ToSendStatic,
+    E: ToSendStatic,
+{
+    type Static = Result<T::Static, E::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        match self {
+            Ok(value) => Ok(value.to_send_static()),
+            Err(err) => Err(err.to_send_static()),
+        }
+    }
+}
+
+/// Blanket [`ToSendStatic`] impl for converting `[T; const N: usize]` to `[T; const N: usize]: Send + Sync + 'static`.
+impl<T, const N: usize> ToSendStatic for [T; N]
+where
+    T: ToSendStatic,
+{
+    type Static = [T::Static; N];
+
+    fn to_send_static(&self) -> Self::Static {
+        core::array::from_fn(|i| self[i].to_send_static())
+    }
+}
+
+/// Blanket [`ToSendStatic`] impl for converting tuples `(T1, T2, ...)` to `(T1, T2, ..): Send + Sync + 'static`.
+macro_rules! tuple_to_send_static {
+    () => ();
+    ($($name:ident,)+) => {
+        tuple_to_send_static! (
+            @gen $($name,)+,
+            concat!(
+                "Blanket [`ToSendStatic`] impl for converting tuple `",
+                stringify!(($($name,)+)), "` to `", stringify!(($($name,)+)), ": Send + Sync + 'static`"
+            )
+        );
+    };
+    (@gen $($name:ident,)+, $doc:expr) => {
+        #[doc = $doc]
+        impl<$($name: ToSendStatic),+> ToSendStatic for ($($name,)+) {
+            type Static = ($($name::Static,)+);
+
+            #[allow(non_snake_case)]
+            fn to_send_static(&self) -> Self::Static {
+                let ($(ref $name,)+) = *self;
+                ($($name.to_send_static(),)+)
+            }
+        }
+        tuple_to_send_static! {@peel $($name,)+ }
+    };
+    (@peel $name:ident, $($other:ident,)*) => {tuple_to_send_static! { $($other,)* }};
+}
+
+tuple_to_send_static! { T11, T10, T9, T8, T7, T6, T5, T4, T3, T2, T1, T0, }
+
+/// [`ToSendStatic`] impls for converting a `Cell` of a primitive type to the atomic type of the same width.
+macro_rules! cell_to_atomic {
+    ($($width:literal: $ty:ty => $atomic:ident),+ $(,)?) => {
+        $(
+            #[cfg(target_has_atomic = $width)]
+            #[doc = concat!(
+                "[`ToSendStatic`] impl for converting `Cell<", stringify!($ty), ">` to `core::sync::atomic::",
+                stringify!($atomic), "`."
+            )]
+            impl ToSendStatic for Cell<$ty> {
+                type Static = core::sync::atomic::$atomic;
+
+                fn to_send_static(&self) -> Self::Static {
+                    core::sync::atomic::$atomic::new(self.get())
+                }
+            }
+        )+
+    };
+}
+
+cell_to_atomic! {
+    "8": bool => AtomicBool,
+    "8": u8 => AtomicU8,
+    "8": i8 => AtomicI8,
+    "16": u16 => AtomicU16,
+    "16": i16 => AtomicI16,
+    "32": u32 => AtomicU32,
+    "32": i32 => AtomicI32,
+    "64": u64 => AtomicU64,
+    "64": i64 => AtomicI64,
+    "ptr": usize => AtomicUsize,
+    "ptr": isize => AtomicIsize,
+}
+
+/// Blanket [`ToSendStatic`] impl for converting `Range<T>` to `Range<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for Range<T>
+where
+    T: ToSendStatic,
+{
+    type Static = Range<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        self.start.to_send_static()..self.end.to_send_static()
+    }
+}
+
+/// Blanket [`ToSendStatic`] impl for converting `RangeFrom<T>` to `RangeFrom<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for RangeFrom<T>
+where
+    T: ToSendStatic,
+{
+    type Static = RangeFrom<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        self.start.to_send_static()..
+    }
+}
+
+/// Blanket [`ToSendStatic`] impl for converting `RangeTo<T>` to `RangeTo<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for RangeTo<T>
+where
+    T: ToSendStatic,
+{
+    type Static = RangeTo<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        ..self.end.to_send_static()
+    }
+}
+
+/// Blanket [`ToSendStatic`] impl for converting `RangeInclusive<T>` to `RangeInclusive<T>: Send + Sync + 'static`.
+///
+/// Note that the resulting range is not exhausted, even if this range has been exhausted by iteration.
+impl<T> ToSendStatic for RangeInclusive<T>
+where
+    T: ToSendStatic,
+{
+    type Static = RangeInclusive<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        self.start().to_send_static()..=self.end().to_send_static()
+    }
+}
+
+/// Blanket [`ToSendStatic`] impl for converting `RangeToInclusive<T>` to `RangeToInclusive<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for RangeToInclusive<T>
+where
+    T: ToSendStatic,
+{
+    type Static = RangeToInclusive<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        ..=self.end.to_send_static()
+    }
+}
+
+/// Blanket [`ToSendStatic`] impl for converting `Bound<T>` to `Bound<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for Bound<T>
+where
+    T: ToSendStatic,
+{
+    type Static = Bound<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        match self {
+            Self::Included(value) => Bound::Included(value.to_send_static()),
+            Self::Excluded(value) => Bound::Excluded(value.to_send_static()),
+            Self::Unbounded => Bound::Unbounded,
+        }
+    }
+}
+
+/// Blanket [`ToSendStatic`] impl for converting `ControlFlow<B, C>` to `ControlFlow<B, C>: Send + Sync + 'static`.
+impl<B, C> ToSendStatic for ControlFlow<B, C>
+where
+    B: ToSendStatic,
+    C: ToSendStatic,
+{
+    type Static = ControlFlow<B::Static, C::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        match self {
+            Self::Continue(value) => ControlFlow::Continue(value.to_send_static()),
+            Self::Break(value) => ControlFlow::Break(value.to_send_static()),
+        }
+    }
+}
+
+/// Blanket [`ToSendStatic`] impl for converting `Reverse<T>` to `Reverse<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for Reverse<T>
+where
+    T: ToSendStatic,
+{
+    type Static = Reverse<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        Reverse(self.0.to_send_static())
+    }
+}
+
+/// Blanket [`ToSendStatic`] impl for converting `Wrapping<T>` to `Wrapping<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for Wrapping<T>
+where
+    T: ToSendStatic,
+{
+    type Static = Wrapping<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        Wrapping(self.0.to_send_static())
+    }
+}
+
+/// Blanket [`ToSendStatic`] impl for converting `Saturating<T>` to `Saturating<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for Saturating<T>
+where
+    T: ToSendStatic,
+{
+    type Static = Saturating<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        Saturating(self.0.to_send_static())
+    }
+}
+
+/// Blanket [`ToSendStatic`] impl for converting `ManuallyDrop<T>` to `ManuallyDrop<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for ManuallyDrop<T>
+where
+    T: ToSendStatic,
+{
+    type Static = ManuallyDrop<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        ManuallyDrop::new((**self).to_send_static())
+    }
+}
+
+/// Blanket [`ToSendStatic`] impl for converting `Poll<T>` to `Poll<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for Poll<T>
+where
+    T: ToSendStatic,
+{
+    type Static = Poll<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        match self {
+            Self::Ready(value) => Poll::Ready(value.to_send_static()),
+            Self::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Blanket [`ToSendStatic`] impl for converting `PhantomData<T>` to `PhantomData<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for PhantomData<T>
+where
+    T: ToSendStatic,
+{
+    type Static = PhantomData<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        PhantomData
+    }
+}
+
+crate::__to_send_static!([send] clone impl[H] BuildHasherDefault<H> where H: 'static);
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToSendStatic`] impl for converting `Cow<'a, T: ?Sized>` to `Cow<'static, T: ?Sized>`.
+impl<T> ToSendStatic for Cow<'_, T>
+where
+    T: 'static + ToOwned + Sync + ?Sized,
+    T::Owned: Send + Sync,
+{
+    type Static = Cow<'static, T>;
+
+    fn to_send_static(&self) -> Self::Static {
+        self.to_static()
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToSendStatic`] impl for converting `StaticCow<'a, T: ?Sized>` to `StaticCow<'static, T: ?Sized>`.
+///
+/// Data borrowed for the `'static` lifetime is kept without copying.
+impl<T> ToSendStatic for StaticCow<'_, T>
+where
+    T: 'static + ToOwnedStatic + Sync + ?Sized,
+    T::Owned: Send + Sync,
+{
+    type Static = StaticCow<'static, T>;
+
+    fn to_send_static(&self) -> <Self as ToSendStatic>::Static {
+        self.to_static()
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// [`ToSendStatic`] impl for `String`.
+impl ToSendStatic for String {
+    type Static = Self;
+
+    fn to_send_static(&self) -> Self::Static {
+        self.to_static()
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToSendStatic`] impl for converting `Vec<T>` to `Vec<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for Vec<T>
+where
+    T: ToSendStatic,
+{
+    type Static = Vec<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        record_alloc_for::<T::Static>(self.len());
+        self.iter().map(ToSendStatic::to_send_static).collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToSendStatic`] impl for converting `Box<T>` to `Box<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for Box<T>
+where
+    T: ToSendStatic,
+{
+    type Static = Box<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        record_alloc_for::<T::Static>(1);
+        Box::new(self.as_ref().to_send_static())
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Blanket [`ToSendStatic`] impl for converting `Pin<Box<T>>` to `Pin<Box<T>>: Send + Sync + 'static`.
+impl<T> ToSendStatic for Pin<Box<T>>
+where
+    T: ToSendStatic,
+{
+    type Static = Pin<Box<T::Static>>;
+
+    fn to_send_static(&self) -> Self::Static {
+        record_alloc_for::<T::Static>(1);
+        Box::pin(self.as_ref().get_ref().to_send_static())
+    }
+}
+
+/// Blanket [`ToSendStatic`] impls for converting the shared pointers `Rc<T>` and `Arc<T>` to `Arc<T>`.
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+macro_rules! shared_pointer_to_send_static {
+    ($ptr:ident) => {
+        #[doc = concat!(
+            "Blanket [`ToSendStatic`] impl for converting `", stringify!($ptr), "<T>` to `Arc<T>: Send + Sync + ",
+            "'static`."
+        )]
+        ///
+        /// Each pointer is converted to a new pointer to a copy of the shared value.
+        impl<T> ToSendStatic for $ptr<T>
+        where
+            T: ToSendStatic,
+        {
+            type Static = Arc<T::Static>;
+
+            fn to_send_static(&self) -> Self::Static {
+                record_alloc_for::<T::Static>(1);
+                Arc::new((**self).to_send_static())
+            }
+        }
+
+        #[doc = concat!("[`ToSendStatic`] impl for converting `", stringify!($ptr), "<str>` to `Arc<str>`.")]
+        impl ToSendStatic for $ptr<str> {
+            type Static = Arc<str>;
+
+            fn to_send_static(&self) -> Self::Static {
+                Arc::from(&**self)
+            }
+        }
+    };
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+shared_pointer_to_send_static!(Rc);
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+shared_pointer_to_send_static!(Arc);
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToSendStatic`] impl for converting `VecDeque<T>` to `VecDeque<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for VecDeque<T>
+where
+    T: ToSendStatic,
+{
+    type Static = VecDeque<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        record_alloc_for::<T::Static>(self.len());
+        self.iter().map(ToSendStatic::to_send_static).collect()
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToSendStatic`] impl for converting `LinkedList<T>` to `LinkedList<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for LinkedList<T>
+where
+    T: ToSendStatic,
+{
+    type Static = LinkedList<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        self.iter().map(ToSendStatic::to_send_static).collect()
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToSendStatic`] impl for converting `BinaryHeap<T>` to `BinaryHeap<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for BinaryHeap<T>
+where
+    T: ToSendStatic,
+    T::Static: Ord,
+{
+    type Static = BinaryHeap<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        record_alloc_for::<T::Static>(self.len());
+        self.iter().map(ToSendStatic::to_send_static).collect()
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToSendStatic`] impl for converting `BTreeMap<K, V>` to `BTreeMap<K, V>: Send + Sync + 'static`.
+impl<K, V> ToSendStatic for BTreeMap<K, V>
+where
+    K: ToSendStatic,
+    K::Static: Ord,
+    V: ToSendStatic,
+{
+    type Static = BTreeMap<K::Static, V::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        self.iter()
+            .map(|(key, value)| (key.to_send_static(), value.to_send_static()))
+            .collect()
+    }
+}
+
+#[cfg(feature = "collections")]
+/// Blanket [`ToSendStatic`] impl for converting `BTreeSet<T>` to `BTreeSet<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for BTreeSet<T>
+where
+    T: ToSendStatic,
+    T::Static: Ord,
+{
+    type Static = BTreeSet<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        self.iter().map(ToSendStatic::to_send_static).collect()
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToSendStatic`] impl for converting `RefCell<T>` to `Mutex<T>: Send + Sync + 'static`.
+///
+/// The value is immutably borrowed for the duration of the conversion.
+///
+/// # Panics
+///
+/// Panics if the value is currently mutably borrowed.
+impl<T> ToSendStatic for RefCell<T>
+where
+    T: ToSendStatic,
+{
+    type Static = std::sync::Mutex<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        std::sync::Mutex::new(self.borrow().to_send_static())
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToSendStatic`] impl for converting `OnceCell<T>` to `OnceLock<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for OnceCell<T>
+where
+    T: ToSendStatic,
+{
+    type Static = std::sync::OnceLock<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        self.get().map_or_else(std::sync::OnceLock::new, |value| {
+            std::sync::OnceLock::from(value.to_send_static())
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToSendStatic`] impl for converting `HashMap<K, V, S>` to `HashMap<K, V, S>: Send + Sync + 'static`.
+impl<K, V, S> ToSendStatic for std::collections::HashMap<K, V, S>
+where
+    K: ToSendStatic,
+    K::Static: Eq + std::hash::Hash,
+    V: ToSendStatic,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher + Send + Sync,
+{
+    type Static = std::collections::HashMap<K::Static, V::Static, S::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        let mut map = std::collections::HashMap::with_capacity_and_hasher(
+            self.len(),
+            self.hasher().to_static(),
+        );
+        map.extend(
+            self.iter()
+                .map(|(key, value)| (key.to_send_static(), value.to_send_static())),
+        );
+        map
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToSendStatic`] impl for converting `HashSet<T, S>` to `HashSet<T, S>: Send + Sync + 'static`.
+impl<T, S> ToSendStatic for std::collections::HashSet<T, S>
+where
+    T: ToSendStatic,
+    T::Static: Eq + std::hash::Hash,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher + Send + Sync,
+{
+    type Static = std::collections::HashSet<T::Static, S::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        let mut set = std::collections::HashSet::with_capacity_and_hasher(
+            self.len(),
+            self.hasher().to_static(),
+        );
+        set.extend(self.iter().map(ToSendStatic::to_send_static));
+        set
+    }
+}
+
+#[cfg(feature = "std")]
+crate::__to_send_static!([send] clone impl[] std::collections::hash_map::RandomState);
+
+#[cfg(feature = "std")]
+/// Blanket [`ToSendStatic`] impl for converting `Mutex<T>` to `Mutex<T>: Send + Sync + 'static`.
+///
+/// The lock is held for the duration of the conversion and so calling this whilst the current thread holds the lock
+/// will deadlock.
+///
+/// If the lock is poisoned the poison is ignored and the inner value is converted, the resulting `Mutex` is not
+/// poisoned.
+impl<T> ToSendStatic for std::sync::Mutex<T>
+where
+    T: ToSendStatic,
+{
+    type Static = std::sync::Mutex<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        let guard = self
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        std::sync::Mutex::new(guard.to_send_static())
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToSendStatic`] impl for converting `RwLock<T>` to `RwLock<T>: Send + Sync + 'static`.
+///
+/// A read lock is held for the duration of the conversion and so calling this whilst the current thread holds the
+/// write lock will deadlock.
+///
+/// If the lock is poisoned the poison is ignored and the inner value is converted, the resulting `RwLock` is not
+/// poisoned.
+impl<T> ToSendStatic for std::sync::RwLock<T>
+where
+    T: ToSendStatic,
+{
+    type Static = std::sync::RwLock<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        let guard = self
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        std::sync::RwLock::new(guard.to_send_static())
+    }
+}
+
+#[cfg(feature = "std")]
+/// Blanket [`ToSendStatic`] impl for converting `OnceLock<T>` to `OnceLock<T>: Send + Sync + 'static`.
+impl<T> ToSendStatic for std::sync::OnceLock<T>
+where
+    T: ToSendStatic,
+{
+    type Static = std::sync::OnceLock<T::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        self.get().map_or_else(std::sync::OnceLock::new, |value| {
+            std::sync::OnceLock::from(value.to_send_static())
+        })
+    }
+}
+
+#[cfg(all(feature = "std", feature = "anyhow"))]
+/// [`ToSendStatic`] impl for `anyhow::Error`, which captures the error and its chain as a
+/// [`StaticError`](error::StaticError).
+impl ToSendStatic for anyhow::Error {
+    type Static = Self;
+
+    fn to_send_static(&self) -> Self::Static {
+        self.to_static()
+    }
+}
+
+#[cfg(all(feature = "std", feature = "eyre"))]
+/// [`ToSendStatic`] impl for `eyre::Report`, which captures the error and its chain as a
+/// [`StaticError`](error::StaticError).
+impl ToSendStatic for eyre::Report {
+    type Static = Self;
+
+    fn to_send_static(&self) -> Self::Static {
+        self.to_static()
+    }
+}
+
+#[cfg(feature = "smol_str")]
+crate::__to_send_static!([send] clone impl[] smol_str::SmolStr);
+
+/// [`ToSendStatic`] impl for `smallvec::SmallVec`.
+#[cfg(feature = "smallvec")]
+impl<B, T> ToSendStatic for smallvec::SmallVec<B>
+where
+    B: smallvec::Array<Item = T> + ToSendStatic,
+    B::Static: smallvec::Array<Item = T::Static>,
+    T: ToSendStatic,
+{
+    type Static = smallvec::SmallVec<B::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        self.iter().map(ToSendStatic::to_send_static).collect()
+    }
+}
+
+#[cfg(feature = "smartstring")]
+crate::__to_send_static!(
+    [send] clone impl[Mode] smartstring::SmartString<Mode> where Mode: smartstring::SmartStringMode + 'static
+);
+
+#[cfg(feature = "ahash")]
+crate::__to_send_static!([send] clone impl[] ahash::RandomState);
+
+#[cfg(all(feature = "ahash", feature = "std"))]
+/// Blanket [`ToSendStatic`] impl for converting `ahash::AHashMap<K, V, S>` to
+/// `ahash::AHashMap<K, V, S>: Send + Sync + 'static`.
+impl<K, V, S> ToSendStatic for ahash::AHashMap<K, V, S>
+where
+    K: ToSendStatic,
+    K::Static: Eq + std::hash::Hash,
+    V: ToSendStatic,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher + Send + Sync,
+{
+    type Static = ahash::AHashMap<K::Static, V::Static, S::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        let mut map =
+            ahash::AHashMap::with_capacity_and_hasher(self.len(), self.hasher().to_static());
+        map.extend(
+            self.iter()
+                .map(|(key, value)| (key.to_send_static(), value.to_send_static())),
+        );
+        map
+    }
+}
+
+#[cfg(all(feature = "ahash", feature = "std"))]
+/// Blanket [`ToSendStatic`] impl for converting `ahash::AHashSet<T, S>` to
+/// `ahash::AHashSet<T, S>: Send + Sync + 'static`.
+impl<T, S> ToSendStatic for ahash::AHashSet<T, S>
+where
+    T: ToSendStatic,
+    T::Static: Eq + std::hash::Hash,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher + Send + Sync,
+{
+    type Static = ahash::AHashSet<T::Static, S::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        let mut set =
+            ahash::AHashSet::with_capacity_and_hasher(self.len(), self.hasher().to_static());
+        set.extend(self.iter().map(ToSendStatic::to_send_static));
+        set
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+/// Blanket [`ToSendStatic`] impl for converting `hashbrown::HashMap<K, V, S>` to
+/// `hashbrown::HashMap<K, V, S>: Send + Sync + 'static`.
+impl<K, V, S> ToSendStatic for hashbrown::HashMap<K, V, S>
+where
+    K: ToSendStatic,
+    K::Static: Eq + std::hash::Hash,
+    V: ToSendStatic,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher + Send + Sync,
+{
+    type Static = hashbrown::HashMap<K::Static, V::Static, S::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        let mut map =
+            hashbrown::HashMap::with_capacity_and_hasher(self.len(), self.hasher().to_static());
+        map.extend(
+            self.iter()
+                .map(|(key, value)| (key.to_send_static(), value.to_send_static())),
+        );
+        map
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+/// Blanket [`ToSendStatic`] impl for converting `hashbrown::HashSet<T, S>` to
+/// `hashbrown::HashSet<T, S>: Send + Sync + 'static`.
+impl<T, S> ToSendStatic for hashbrown::HashSet<T, S>
+where
+    T: ToSendStatic,
+    T::Static: Eq + std::hash::Hash,
+    S: ToBoundedStatic,
+    S::Static: std::hash::BuildHasher + Send + Sync,
+{
+    type Static = hashbrown::HashSet<T::Static, S::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        let mut set =
+            hashbrown::HashSet::with_capacity_and_hasher(self.len(), self.hasher().to_static());
+        set.extend(self.iter().map(ToSendStatic::to_send_static));
+        set
+    }
+}
+
+#[cfg(feature = "chrono")]
+/// Blanket [`ToSendStatic`] impl for converting `chrono::DateTime<Tz>` to
+/// `chrono::DateTime<Tz>: Send + Sync + 'static`.
+impl<Tz> ToSendStatic for chrono::DateTime<Tz>
+where
+    Tz: ToSendStatic + chrono::TimeZone,
+    Tz::Static: chrono::TimeZone,
+    <Tz::Static as chrono::TimeZone>::Offset: Send + Sync,
+{
+    type Static = chrono::DateTime<Tz::Static>;
+
+    fn to_send_static(&self) -> Self::Static {
+        self.with_timezone(&self.timezone().to_send_static())
+    }
+}
+
+/// Build an array by calling `f` for each index, stopping at the first error.
+fn try_array_from_fn<T, E, const N: usize>(
+    mut f: impl FnMut(usize) -> Result<T, E>,
+) -> Result<[T; N], E> {
+    let mut error = None;
+    let values: [Option<T>; N] = core::array::from_fn(|i| {
+        if error.is_some() {
+            return None;
+        }
+        f(i).map_err(|err| error = Some(err)).ok()
+    });
+    if let Some(err) = error {
+        return Err(err);
+    }
+    Ok(values.map(|value| value.expect("all array elements are converted")))
+}
+
+/// Create an empty `Vec` with room for `len` values, which is allocated with `try_reserve_exact`.
+#[cfg(feature = "alloc")]
+fn try_vec_with_capacity<T>(len: usize) -> Result<Vec<T>, TryStaticError> {
+    let mut vec = Vec::new();
+    vec.try_reserve_exact(len)?;
+    Ok(vec)
+}
+
+#[cfg(any(feature = "alloc", feature = "smallvec", feature = "smartstring"))]
+use __private::record_alloc;
+
+/// Charge the copy of the value of a `Cow` to the budget.
+///
+/// The size of the value is charged, which is all that the copy allocates if `T::Owned` needs no drop or `T` is `str`,
+/// `CStr`, `OsStr`, `Path` or a slice of primitives.  Otherwise the copy may also clone heap data which cannot be
+/// measured and so an error of kind [`ErrorKind::Unmeasurable`] is returned unless the budget is unlimited.
+#[cfg(feature = "alloc")]
+fn charge_cow<T>(value: &T, budget: &mut Budget) -> Result<(), TryStaticError>
+where
+    T: 'static + ToOwned + ?Sized,
+{
+    use core::any::TypeId;
+    const FLAT: &[fn() -> TypeId] = &[
+        TypeId::of::<str>,
+        TypeId::of::<CStr>,
+        TypeId::of::<[u8]>,
+        TypeId::of::<[u16]>,
+        TypeId::of::<[u32]>,
+        TypeId::of::<[u64]>,
+        TypeId::of::<[u128]>,
+        TypeId::of::<[usize]>,
+        TypeId::of::<[i8]>,
+        TypeId::of::<[i16]>,
+        TypeId::of::<[i32]>,
+        TypeId::of::<[i64]>,
+        TypeId::of::<[i128]>,
+        TypeId::of::<[isize]>,
+        TypeId::of::<[f32]>,
+        TypeId::of::<[f64]>,
+        TypeId::of::<[bool]>,
+        TypeId::of::<[char]>,
+        #[cfg(feature = "std")]
+        TypeId::of::<std::ffi::OsStr>,
+        #[cfg(feature = "std")]
+        TypeId::of::<std::path::Path>,
+    ];
+    let measurable = budget.is_unlimited()
+        || !core::mem::needs_drop::<T::Owned>()
+        || FLAT.iter().any(|id| id() == TypeId::of::<T>());
+    if !measurable {
+        return Err(TryStaticError::new(ErrorKind::Unmeasurable));
+    }
+    budget.charge(core::mem::size_of_val(value))
+}
+
+/// Record an allocation of `len` values of type `T` with the `instrument` collector, if enabled.
+#[cfg(any(feature = "alloc", feature = "smallvec"))]
+#[inline]
+fn record_alloc_for<T>(len: usize) {
+    record_alloc(core::mem::size_of::<T>().saturating_mul(len));
+}
+
+/// Record that a `Cow::Owned` value was reused with the `instrument` collector, if enabled.
+#[cfg(feature = "alloc")]
+#[inline]
+fn record_reuse() {
+    #[cfg(feature = "instrument")]
+    instrument::record_reuse();
+}
+
+/// Convert the values of `iter` into a collection created by `new` with the length of `iter`, charging the values to
+/// the budget before the collection is created.
+#[cfg(any(feature = "alloc", feature = "smallvec"))]
+fn extend_with_budget<'a, T, C>(
+    new: impl FnOnce(usize) -> Result<C, TryStaticError>,
+    iter: impl ExactSizeIterator<Item = &'a T>,
+    budget: &mut Budget,
+) -> Result<C, TryStaticError>
+where
+    T: ToBoundedStatic + 'a,
+    C: Extend<T::Static>,
+{
+    budget.charge_for::<T::Static>(iter.len())?;
+    record_alloc_for::<T::Static>(iter.len());
+    let mut collection = new(iter.len())?;
+    for (i, value) in iter.enumerate() {
+        let value = value
+            .to_static_with_budget(budget)
+            .map_err(|err| err.at(PathSegment::Index(i)))?;
+        collection.extend(core::iter::once(value));
+    }
+    Ok(collection)
+}
+
+/// Convert the values of `iter` into a collection created by `new` with the length of `iter`, charging the values to
+/// the budget before the collection is created.
+#[cfg(any(feature = "alloc", feature = "smallvec"))]
+fn extend_into_with_budget<T, C>(
+    new: impl FnOnce(usize) -> Result<C, TryStaticError>,
+    iter: impl ExactSizeIterator<Item = T>,
+    budget: &mut Budget,
+) -> Result<C, TryStaticError>
+where
+    T: IntoBoundedStatic,
+    C: Extend<T::Static>,
+{
+    budget.charge_for::<T::Static>(iter.len())?;
+    record_alloc_for::<T::Static>(iter.len());
+    let mut collection = new(iter.len())?;
+    for (i, value) in iter.enumerate() {
+        let value = value
+            .into_static_with_budget(budget)
+            .map_err(|err| err.at(PathSegment::Index(i)))?;
+        collection.extend(core::iter::once(value));
+    }
+    Ok(collection)
+}
+
+/// Convert the entries of `iter` into a map created by `new` with the length of `iter`, charging the entries to the
+/// budget before the map is created.
+#[cfg(any(feature = "collections", feature = "std"))]
+fn extend_entries_with_budget<'a, K, V, C>(
+    new: impl FnOnce(usize) -> Result<C, TryStaticError>,
+    iter: impl ExactSizeIterator<Item = (&'a K, &'a V)>,
+    budget: &mut Budget,
+) -> Result<C, TryStaticError>
+where
+    K: ToBoundedStatic + 'a,
+    V: ToBoundedStatic + 'a,
+    C: Extend<(K::Static, V::Static)>,
+{
+    budget.charge_for::<(K::Static, V::Static)>(iter.len())?;
+    record_alloc_for::<(K::Static, V::Static)>(iter.len());
+    let mut collection = new(iter.len())?;
+    for (i, (k, v)) in iter.enumerate() {
+        let k = k
+            .to_static_with_budget(budget)
+            .map_err(|err| err.at(PathSegment::Key(i)))?;
+        let v = v
+            .to_static_with_budget(budget)
+            .map_err(|err| err.at(PathSegment::Value(i)))?;
+        collection.extend(core::iter::once((k, v)));
+    }
+    Ok(collection)
+}
+
+/// Convert the entries of `iter` into a map created by `new` with the length of `iter`, charging the entries to the
+/// budget before the map is created.
+#[cfg(any(feature = "collections", feature = "std"))]
+fn extend_entries_into_with_budget<K, V, C>(
+    new: impl FnOnce(usize) -> Result<C, TryStaticError>,
+    iter: impl ExactSizeIterator<Item = (K, V)>,
+    budget: &mut Budget,
+) -> Result<C, TryStaticError>
+where
+    K: IntoBoundedStatic,
+    V: IntoBoundedStatic,
+    C: Extend<(K::Static, V::Static)>,
+{
+    budget.charge_for::<(K::Static, V::Static)>(iter.len())?;
+    record_alloc_for::<(K::Static, V::Static)>(iter.len());
+    let mut collection = new(iter.len())?;
+    for (i, (k, v)) in iter.enumerate() {
+        let k = k
+            .into_static_with_budget(budget)
+            .map_err(|err| err.at(PathSegment::Key(i)))?;
+        let v = v
+            .into_static_with_budget(budget)
+            .map_err(|err| err.at(PathSegment::Value(i)))?;
+        collection.extend(core::iter::once((k, v)));
+    }
+    Ok(collection)
+}
+
+#[cfg(test)]
+mod core_tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn ensure_static<T: 'static>(t: T) {
+        drop(t);
+    }
+
+    fn ensure_send<T: Send + Sync + 'static>(t: T) {
+        drop(t);
     }
 
     #[test_case(false; "bool")]
@@ -1054,393 +6580,2808 @@ mod core_tests {
         ensure_static(t.to_static());
     }
 
-    #[test_case(NonZeroUsize::new(1); "usize")]
-    #[test_case(NonZeroU8::new(1); "u8")]
-    #[test_case(NonZeroU16::new(1); "u16")]
-    #[test_case(NonZeroU32::new(1); "u32")]
-    #[test_case(NonZeroU64::new(1); "u64")]
-    #[test_case(NonZeroU128::new(1); "u128")]
-    #[test_case(NonZeroIsize::new(1); "isize")]
-    #[test_case(NonZeroI8::new(1); "i8")]
-    #[test_case(NonZeroI16::new(1); "i16")]
-    #[test_case(NonZeroI32::new(1); "i32")]
-    #[test_case(NonZeroI64::new(1); "i64")]
-    #[test_case(NonZeroI128::new(1); "i128")]
-    #[allow(clippy::needless_pass_by_value)]
-    fn test_non_zero<T: ToBoundedStatic>(t: T) {
-        ensure_static(t.to_static());
+    #[test_case(NonZeroUsize::new(1); "usize")]
+    #[test_case(NonZeroU8::new(1); "u8")]
+    #[test_case(NonZeroU16::new(1); "u16")]
+    #[test_case(NonZeroU32::new(1); "u32")]
+    #[test_case(NonZeroU64::new(1); "u64")]
+    #[test_case(NonZeroU128::new(1); "u128")]
+    #[test_case(NonZeroIsize::new(1); "isize")]
+    #[test_case(NonZeroI8::new(1); "i8")]
+    #[test_case(NonZeroI16::new(1); "i16")]
+    #[test_case(NonZeroI32::new(1); "i32")]
+    #[test_case(NonZeroI64::new(1); "i64")]
+    #[test_case(NonZeroI128::new(1); "i128")]
+    #[allow(clippy::needless_pass_by_value)]
+    fn test_non_zero<T: ToBoundedStatic>(t: T) {
+        ensure_static(t.to_static());
+    }
+
+    #[test_case(Ordering::Less; "ordering")]
+    #[test_case(RangeFull; "range full")]
+    #[test_case(PhantomPinned; "phantom pinned")]
+    #[test_case(Duration::from_secs(1); "duration")]
+    #[allow(clippy::needless_pass_by_value)]
+    fn test_copy<T: ToBoundedStatic>(t: T) {
+        ensure_static(t.to_static());
+    }
+
+    #[test_case("a".."z"; "range")]
+    #[test_case("test"..; "range from")]
+    #[test_case(.."test"; "range to")]
+    #[test_case(..="test"; "range to inclusive")]
+    #[allow(clippy::needless_pass_by_value)]
+    fn test_range<T: ToBoundedStatic + IntoBoundedStatic>(t: T) {
+        ensure_static(t.to_static());
+        ensure_static(t.into_static());
+    }
+
+    #[test]
+    fn test_range_inclusive() {
+        let value = "a"..="z";
+        let to_static = value.to_static();
+        assert_eq!(value, to_static);
+        ensure_static(to_static);
+        assert_eq!("a"..="z", value.into_static());
+    }
+
+    #[test_case(Bound::Included("test"); "included")]
+    #[test_case(Bound::Excluded("test"); "excluded")]
+    #[test_case(Bound::Unbounded; "unbounded")]
+    fn test_bound(value: Bound<&'static str>) {
+        assert_eq!(value, value.to_static());
+        assert_eq!(value, value.into_static());
+    }
+
+    #[test_case(ControlFlow::Continue("test"); "continue")]
+    #[test_case(ControlFlow::Break(0); "break")]
+    fn test_control_flow(value: ControlFlow<i32, &'static str>) {
+        assert_eq!(value, value.to_static());
+        assert_eq!(value, value.into_static());
+    }
+
+    #[test_case(Poll::Ready("test"); "ready")]
+    #[test_case(Poll::Pending; "pending")]
+    fn test_poll(value: Poll<&'static str>) {
+        assert_eq!(value, value.to_static());
+        assert_eq!(value, value.into_static());
+    }
+
+    #[test]
+    fn test_reverse() {
+        let value = Reverse("test");
+        assert_eq!(value, value.to_static());
+        assert_eq!(value, value.into_static());
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let value = Wrapping(u8::MAX);
+        assert_eq!(value, value.to_static());
+        assert_eq!(value, value.into_static());
+    }
+
+    #[test]
+    fn test_saturating() {
+        let value = Saturating(u8::MAX);
+        assert_eq!(value, value.to_static());
+        assert_eq!(value, value.into_static());
+    }
+
+    #[test]
+    fn test_manually_drop() {
+        let value = ManuallyDrop::new("test");
+        assert_eq!(value, value.to_static());
+        assert_eq!(value, value.into_static());
+    }
+
+    #[test]
+    fn test_phantom_data() {
+        let value: PhantomData<&'static str> = PhantomData;
+        ensure_static(value.to_static());
+        ensure_static(value.into_static());
+    }
+
+    #[test]
+    fn test_unit() {
+        #[allow(clippy::unit_arg)]
+        ensure_static(().to_static());
+    }
+
+    #[test]
+    fn test_str() {
+        let s = "";
+        let to_static = s.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_option_none() {
+        let value: Option<u32> = None;
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_option_some() {
+        let value: Option<u32> = Some(32);
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_result() {
+        #[derive(Clone)]
+        struct MyError;
+        #[allow(clippy::unnecessary_wraps)]
+        fn foo_ok() -> Result<(), MyError> {
+            Ok(())
+        }
+        #[allow(clippy::unnecessary_wraps)]
+        fn foo_err() -> Result<(), MyError> {
+            Err(MyError)
+        }
+        impl ToBoundedStatic for MyError {
+            type Static = Self;
+
+            fn to_static(&self) -> Self::Static {
+                self.clone()
+            }
+        }
+        let ok_result = foo_ok();
+        ensure_static(ok_result.to_static());
+        assert!(ok_result.is_ok());
+        let err_result = foo_err();
+        ensure_static(err_result.to_static());
+        assert!(err_result.is_err());
+    }
+
+    #[test]
+    fn test_array() {
+        let arr = ["test"];
+        ensure_static(arr.to_static());
+    }
+
+    #[test]
+    fn test_tuple2() {
+        let tuple = ("test", 32);
+        ensure_static(tuple.to_static());
+    }
+
+    #[test]
+    fn test_tuple11() {
+        let tuple = (
+            (),
+            '1',
+            "2",
+            3_i32,
+            4_usize,
+            5_isize,
+            6.0_f64,
+            ["7"],
+            Some(8),
+            9,
+            (10,),
+            false,
+        );
+        ensure_static(tuple.to_static());
+    }
+
+    #[test]
+    fn test_build_hasher_default() {
+        #[derive(Default)]
+        struct MyHasher;
+        impl core::hash::Hasher for MyHasher {
+            fn finish(&self) -> u64 {
+                0
+            }
+            fn write(&mut self, _bytes: &[u8]) {}
+        }
+        let value: BuildHasherDefault<MyHasher> = BuildHasherDefault::default();
+        ensure_static(value.to_static());
+        ensure_static(value.into_static());
+    }
+
+    #[test]
+    fn test_impl_copy() {
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        struct Foo(u8);
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        struct Bar<T>(T);
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        struct Baz<'a, T>(&'a str, T);
+        crate::impl_copy!(Foo);
+        crate::impl_copy!(impl[T: Copy + 'static] Bar<T>);
+        crate::impl_copy!(impl[T] Baz<'static, T> where T: Copy + 'static);
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        struct Qux<'a, T>(&'a T);
+        crate::impl_copy!(send impl['a, T,] Qux<'a, T> where 'a: 'static, T: Copy + 'static);
+        assert_eq!(Foo(1), Foo(1).to_static());
+        assert_eq!(Foo(1), Foo(1).into_static());
+        assert_eq!(Bar('a'), Bar('a').to_static());
+        assert_eq!(Bar('a'), Bar('a').into_static());
+        assert_eq!(Baz("a", 1), Baz("a", 1).to_static());
+        assert_eq!(Baz("a", 1), Baz("a", 1).into_static());
+        assert_eq!(Qux(&1), Qux(&1).to_static());
+        assert_eq!(Qux(&1), Qux(&1).to_bounded_in(&()));
+        assert_eq!(Qux(&1), Qux(&1).to_send_static());
+    }
+
+    #[test]
+    fn test_impl_copy_multi() {
+        #[derive(Copy, Clone)]
+        struct Foo;
+        #[derive(Copy, Clone)]
+        struct Bar;
+        crate::impl_copy!(Foo, Bar,);
+        ensure_static(Foo.to_static());
+        ensure_static(Bar.into_static());
+    }
+
+    #[test]
+    fn test_cell() {
+        let value = Cell::new("test");
+        let to_static = value.to_static();
+        assert_eq!(value.get(), to_static.get());
+        ensure_static(to_static);
+        ensure_static(value.into_static());
+    }
+
+    #[test]
+    fn test_ref_cell() {
+        let value = RefCell::new(["test"]);
+        let to_static = value.to_static();
+        assert_eq!(*value.borrow(), *to_static.borrow());
+        ensure_static(to_static);
+        ensure_static(value.into_static());
+    }
+
+    #[test]
+    #[should_panic(expected = "already mutably borrowed")]
+    fn test_ref_cell_mutably_borrowed() {
+        let value = RefCell::new("test");
+        let _guard = value.borrow_mut();
+        ensure_static(value.to_static());
+    }
+
+    #[test]
+    fn test_to_send_static_cell() {
+        let output = (Cell::new(true), Cell::new(1_u32), Cell::new(-1_isize)).to_send_static();
+        assert!(output.0.load(core::sync::atomic::Ordering::Relaxed));
+        assert_eq!(1, output.1.load(core::sync::atomic::Ordering::Relaxed));
+        assert_eq!(-1, output.2.load(core::sync::atomic::Ordering::Relaxed));
+        ensure_send(output);
+    }
+
+    #[test]
+    fn test_static_iterator_map_to_static() {
+        let value = [Some("a"), None, Some("c")];
+        let mut iter = value.iter().map_to_static();
+        assert_eq!((3, Some(3)), iter.size_hint());
+        assert_eq!(Some(Some("a")), iter.next());
+        assert_eq!(Some(Some("c")), iter.next_back());
+        assert_eq!(1, iter.len());
+        assert_eq!(Some(None), iter.next());
+        assert_eq!(None, iter.next());
+    }
+
+    #[test]
+    fn test_static_iterator_map_into_static() {
+        let iter = (1_u32..4).map_into_static();
+        assert_eq!(3, iter.len());
+        assert_eq!(321, iter.rev().fold(0, |acc, item| acc * 10 + item));
+    }
+
+    #[test]
+    fn test_once_cell_empty() {
+        let value: OnceCell<&str> = OnceCell::new();
+        let to_static = value.to_static();
+        assert!(to_static.get().is_none());
+        ensure_static(to_static);
+        ensure_static(value.into_static());
+    }
+
+    #[test]
+    fn test_once_cell_set() {
+        let value = OnceCell::from("test");
+        let to_static = value.to_static();
+        assert_eq!(Some(&"test"), to_static.get());
+        ensure_static(to_static);
+        ensure_static(value.into_static());
+    }
+
+    /// A test type whose budgeted conversion fails if it is not valid.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Fallible(pub bool);
+
+    impl Fallible {
+        fn check(self) -> Result<Self, TryStaticError> {
+            if self.0 {
+                Ok(self)
+            } else {
+                Err(TryStaticError::new(ErrorKind::Custom("invalid")))
+            }
+        }
+    }
+
+    impl ToBoundedStatic for Fallible {
+        type Static = Self;
+
+        fn to_static(&self) -> Self::Static {
+            self.check().expect("valid")
+        }
+
+        fn to_static_with_budget(&self, _: &mut Budget) -> Result<Self::Static, TryStaticError> {
+            self.check()
+        }
+    }
+
+    impl IntoBoundedStatic for Fallible {
+        type Static = Self;
+
+        fn into_static(self) -> Self::Static {
+            self.to_static()
+        }
+
+        fn into_static_with_budget(self, _: &mut Budget) -> Result<Self::Static, TryStaticError> {
+            self.check()
+        }
+    }
+
+    #[test]
+    fn test_try_primitive() {
+        assert_eq!(Ok(1), 1_u8.try_to_static());
+        assert_eq!(Ok("test"), "test".try_into_static());
+        ensure_static(().try_to_static());
+    }
+
+    #[test]
+    fn test_try_option() {
+        assert_eq!(
+            Ok(Some(Fallible(true))),
+            Some(Fallible(true)).try_to_static()
+        );
+        assert_eq!(Ok(None), None::<Fallible>.try_into_static());
+        let err = Some(Fallible(false)).try_to_static().unwrap_err();
+        assert_eq!(&ErrorKind::Custom("invalid"), err.kind());
+    }
+
+    #[test]
+    fn test_try_array() {
+        let value = [Fallible(true), Fallible(true)];
+        assert_eq!(Ok(value), value.try_to_static());
+        assert_eq!(Ok(value), value.try_into_static());
+        let value = [Fallible(true), Fallible(false)];
+        let err = value.try_to_static().unwrap_err();
+        assert_eq!(&ErrorKind::Custom("invalid"), err.kind());
+        assert!(value.try_into_static().is_err());
+    }
+
+    #[test]
+    fn test_try_tuple() {
+        let value = (1, "test", Fallible(true));
+        assert_eq!(Ok(value), value.try_to_static());
+        assert_eq!(Ok(value), value.try_into_static());
+        let value = (1, "test", Fallible(false));
+        assert!(value.try_to_static().is_err());
+        assert!(value.try_into_static().is_err());
+    }
+
+    #[test]
+    fn test_try_result() {
+        let value: Result<Fallible, Fallible> = Err(Fallible(true));
+        assert_eq!(Ok(value), value.try_to_static());
+        let value: Result<Fallible, Fallible> = Ok(Fallible(false));
+        assert!(value.try_into_static().is_err());
+    }
+
+    #[test]
+    fn test_try_ref_cell() {
+        let value = RefCell::new(Fallible(true));
+        assert_eq!(Fallible(true), value.try_to_static().unwrap().into_inner());
+        assert_eq!(
+            Fallible(true),
+            value.try_into_static().unwrap().into_inner()
+        );
+    }
+
+    #[test]
+    fn test_try_ref_cell_mutably_borrowed() {
+        let value = RefCell::new("test");
+        let _guard = value.borrow_mut();
+        let err = value.try_to_static().unwrap_err();
+        assert_eq!(&ErrorKind::Borrowed, err.kind());
+    }
+
+    #[test]
+    fn test_try_range() {
+        let value = Fallible(true)..Fallible(false);
+        let err = value.try_to_static().unwrap_err();
+        assert_eq!(&ErrorKind::Custom("invalid"), err.kind());
+        assert!((Fallible(true)..=Fallible(true)).try_into_static().is_ok());
+    }
+
+    #[test]
+    fn test_with_limit_no_alloc() {
+        let value = (1, "test", Some([1, 2, 3]), 0..10);
+        assert_eq!(Ok(value.clone()), value.to_static_with_limit(0));
+        assert_eq!(Ok(value.clone()), value.into_static_with_limit(0));
+    }
+
+    #[test]
+    fn test_budget_charge() {
+        let mut budget = Budget::new(10);
+        assert_eq!(Ok(()), budget.charge(4));
+        assert_eq!(Ok(()), budget.charge_for::<u16>(3));
+        assert_eq!(10, budget.used());
+        let err = budget.charge(1).unwrap_err();
+        assert_eq!(&ErrorKind::LimitExceeded, err.kind());
+        assert_eq!(10, budget.used());
+        assert_eq!(0, budget.remaining());
+        assert!(Budget::new(usize::MAX - 1)
+            .charge_for::<u64>(usize::MAX)
+            .is_err());
+    }
+
+    #[test]
+    fn test_static_cost_no_alloc() {
+        let value = (1, "test", Some([1, 2, 3]), 0..10, RefCell::new('a'));
+        assert_eq!(CostReport::new(), value.static_cost());
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg(test)]
+mod alloc_tests {
+    use super::*;
+    use crate::core_tests::Fallible;
+    use alloc::string::ToString;
+
+    fn ensure_static<T: 'static>(t: T) {
+        drop(t);
+    }
+
+    fn ensure_send<T: Send + Sync + 'static>(t: T) {
+        drop(t);
+    }
+
+    #[test]
+    fn test_string() {
+        let s = String::new();
+        let to_static = s.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_cow_borrowed_str() {
+        let s = String::new();
+        let to_static = Cow::from(&s).to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_cow_owned_string() {
+        let s = String::new();
+        let to_static = Cow::from(s).to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_c_string() {
+        let value = CString::new("test").unwrap();
+        let to_static = value.to_static();
+        assert_eq!(value, to_static);
+        ensure_static(to_static);
+        ensure_static(value.into_static());
+    }
+
+    #[test]
+    fn test_box_c_str() {
+        let value = CString::new("test").unwrap().into_boxed_c_str();
+        let to_static = value.to_static();
+        assert_eq!(value, to_static);
+        ensure_static(to_static);
+        ensure_static(value.into_static());
+    }
+
+    #[test]
+    fn test_cow_c_str() {
+        let s = CString::new("test").unwrap();
+        let value = Cow::from(s.as_c_str());
+        ensure_static(value.to_static());
+        ensure_static(value.into_static());
+    }
+
+    #[test]
+    fn test_cow_to_static() {
+        let s = String::new();
+        let s_cow: Cow<'_, str> = Cow::Borrowed(&s);
+        let s1_cow_owned: Cow<'_, str> = s_cow.to_static();
+        let s2_cow_owned: Cow<'_, str> = Cow::Owned(s_cow.into_owned());
+        assert_eq!(s1_cow_owned, s2_cow_owned);
+    }
+
+    #[test]
+    fn test_cow_into_static() {
+        let s = String::new();
+        let s_cow: Cow<'_, str> = Cow::Borrowed(&s);
+        let s1_cow_owned: Cow<'_, str> = s_cow.clone().into_static();
+        let s2_cow_owned: Cow<'_, str> = Cow::Owned(s_cow.into_owned());
+        assert_eq!(s1_cow_owned, s2_cow_owned);
+    }
+
+    #[test]
+    fn test_option_none() {
+        let value: Option<Cow<'_, str>> = None;
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_option_some() {
+        let s = String::new();
+        let value = Some(Cow::from(&s));
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_array() {
+        let arr = ["test"];
+        ensure_static(arr.to_static());
+    }
+
+    #[test]
+    fn test_array_into() {
+        let s = String::new();
+        let arr = [Cow::from(&s)];
+        ensure_static(arr.into_static());
+    }
+
+    #[test]
+    fn test_vec1() {
+        let s = String::new();
+        let value = alloc::vec![Cow::from(&s)];
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_vec2() {
+        let s = String::new();
+        let value = alloc::vec![Cow::from(&s), Cow::from(s.as_str())];
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_box() {
+        let s = String::new();
+        let value = Box::new(s);
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_box_cow() {
+        let s = String::new();
+        let value = Box::new(Cow::from(&s));
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_box_vec_cow() {
+        let s = String::new();
+        let value = Box::new(alloc::vec![Cow::from(&s)]);
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_vec_box_cow() {
+        let s = String::new();
+        let value = alloc::vec![Box::new(Cow::from(&s))];
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_vec_into_static_identity() {
+        let value = alloc::vec![String::from("test")];
+        let ptr = value.as_ptr();
+        let into_static = value.into_static();
+        assert_eq!(ptr, into_static.as_ptr());
+        let value = alloc::vec![1_u64, 2, 3];
+        let ptr = value.as_ptr();
+        let into_static = value.into_static();
+        assert_eq!(ptr, into_static.as_ptr());
+        assert_eq!(alloc::vec![1, 2, 3], into_static);
+    }
+
+    #[test]
+    fn test_string_to_static_into() {
+        let mut dst = String::with_capacity(16);
+        let ptr = dst.as_ptr();
+        String::from("test").to_static_into(&mut dst);
+        assert_eq!("test", dst);
+        assert_eq!(ptr, dst.as_ptr());
+    }
+
+    #[test]
+    fn test_static_cow_static() {
+        let value: StaticCow<'_, str> = StaticCow::Static("static");
+        let to_static = value.to_static();
+        assert!(to_static.is_static());
+        assert!(core::ptr::eq("static".as_ptr(), to_static.as_ptr()));
+        let into_static = value.into_static();
+        assert!(into_static.is_static());
+        ensure_static(into_static);
+    }
+
+    #[test]
+    fn test_static_cow_borrowed() {
+        let s = String::from("borrowed");
+        let value = StaticCow::from(s.as_str());
+        let to_static = value.to_static();
+        assert!(to_static.is_owned());
+        assert_eq!("borrowed", to_static);
+        let into_static = value.into_static();
+        assert!(into_static.is_owned());
+        ensure_static(into_static);
+    }
+
+    #[test]
+    fn test_static_cow_owned() {
+        let value = StaticCow::from(String::from("owned"));
+        let ptr = value.as_ptr();
+        let to_static = value.to_static();
+        assert_eq!("owned", to_static);
+        let into_static = value.into_static();
+        assert!(core::ptr::eq(ptr, into_static.as_ptr()));
+        ensure_static(into_static);
+    }
+
+    #[test]
+    fn test_static_cow_cost() {
+        let s = String::from("borrowed");
+        let value = (
+            StaticCow::Static("static"),
+            StaticCow::from(s.as_str()),
+            StaticCow::from(String::from("owned")),
+        );
+        let report = value.static_cost();
+        assert_eq!(1, report.borrowed());
+        assert_eq!(1, report.owned());
+        assert_eq!(13, report.bytes());
+    }
+
+    #[test]
+    fn test_static_cow_with_limit() {
+        let value: StaticCow<'_, str> = StaticCow::Static("static");
+        assert!(value.to_static_with_limit(0).unwrap().is_static());
+        let s = String::from("borrowed");
+        let value = StaticCow::from(s.as_str());
+        assert!(value.to_static_with_limit(7).is_err());
+        assert!(value.into_static_with_limit(8).is_ok());
+    }
+
+    #[test]
+    fn test_static_cow_eq_hash() {
+        let s = String::from("test");
+        let values = [
+            StaticCow::Static("test"),
+            StaticCow::from(s.as_str()),
+            StaticCow::from(String::from("test")),
+        ];
+        assert!(values.iter().all(|value| value == &values[0]));
+        assert_eq!(Cow::from("test"), Cow::from(values[1].clone()));
+        assert!(values[0] < StaticCow::Static("tesu"));
+    }
+
+    #[test]
+    fn test_rc() {
+        let s = String::from("test");
+        let shared = Rc::new(Cow::from(&s));
+        let value = alloc::vec![shared.clone(), shared];
+        let to_static = value.to_static();
+        assert!(!Rc::ptr_eq(&to_static[0], &to_static[1]));
+        let into_static = value.into_static();
+        assert_eq!("test", *into_static[0]);
+        ensure_static(into_static);
+    }
+
+    #[test]
+    fn test_rc_to_static_with() {
+        let s = String::from("test");
+        let shared = Rc::new(Cow::from(&s));
+        let other = Rc::new(Cow::from(&s));
+        let value = (
+            alloc::vec![shared.clone(), other.clone()],
+            Some(Box::new(shared)),
+            [other],
+        );
+        let mut converter = StaticConverter::new();
+        let to_static = converter.convert(&value);
+        assert_eq!(2, converter.len());
+        assert!(Rc::ptr_eq(&to_static.0[0], to_static.1.as_ref().unwrap()));
+        assert!(Rc::ptr_eq(&to_static.0[1], &to_static.2[0]));
+        assert!(!Rc::ptr_eq(&to_static.0[0], &to_static.0[1]));
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_rc_to_static_with_nested() {
+        let s = String::from("test");
+        let leaf = Rc::new(Cow::from(&s));
+        let node = Rc::new(alloc::vec![leaf.clone(), leaf]);
+        let value = alloc::vec![node.clone(), node];
+        let to_static = StaticConverter::new().convert(&value);
+        assert!(Rc::ptr_eq(&to_static[0], &to_static[1]));
+        assert!(Rc::ptr_eq(&to_static[0][0], &to_static[0][1]));
+    }
+
+    #[test]
+    fn test_rc_to_static_into() {
+        let s = String::from("test");
+        let mut dst = Rc::new(Cow::Owned(String::with_capacity(16)));
+        let ptr = Rc::as_ptr(&dst);
+        Rc::new(Cow::from(&s)).to_static_into(&mut dst);
+        assert_eq!(ptr, Rc::as_ptr(&dst));
+        assert_eq!("test", *dst);
+        let shared = dst.clone();
+        Rc::new(Cow::from(&s)).to_static_into(&mut dst);
+        assert!(!Rc::ptr_eq(&shared, &dst));
+    }
+
+    #[test]
+    fn test_arc_to_static_with() {
+        let s = String::from("test");
+        let shared = Arc::new(Cow::from(&s));
+        let value = alloc::vec![shared.clone(), shared];
+        let to_static = StaticConverter::new().convert(&value);
+        assert!(Arc::ptr_eq(&to_static[0], &to_static[1]));
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_rc_str_dedup() {
+        let value: Vec<Rc<str>> = alloc::vec![Rc::from("test"), Rc::from("test")];
+        let to_static = value.to_static();
+        assert!(Rc::ptr_eq(&value[0], &to_static[0]));
+        let to_static = StaticConverter::new().convert(&value);
+        assert!(!Rc::ptr_eq(&to_static[0], &to_static[1]));
+        let to_static = StaticConverter::new().dedup_strings().convert(&value);
+        assert!(Rc::ptr_eq(&to_static[0], &to_static[1]));
+        let value: Vec<Arc<str>> = alloc::vec![Arc::from("test"), Arc::from("test")];
+        let to_static = StaticConverter::new().dedup_strings().convert(&value);
+        assert!(Arc::ptr_eq(&to_static[0], &to_static[1]));
+    }
+
+    #[test]
+    fn test_cow_to_static_into() {
+        let s = String::from("test");
+        let mut dst: Cow<'static, str> = Cow::Owned(String::with_capacity(16));
+        let ptr = dst.as_ptr();
+        Cow::from(&s).to_static_into(&mut dst);
+        assert_eq!("test", dst);
+        assert_eq!(ptr, dst.as_ptr());
+        let mut dst = Cow::Borrowed("static");
+        Cow::from(&s).to_static_into(&mut dst);
+        assert!(matches!(dst, Cow::Owned(_)));
+        assert_eq!("test", dst);
+    }
+
+    #[test]
+    fn test_vec_to_static_into() {
+        let s = String::from("test");
+        let mut dst: Vec<Cow<'static, str>> = (0..3)
+            .map(|_| Cow::Owned(String::with_capacity(16)))
+            .collect();
+        let ptr = dst.as_ptr();
+        let value_ptr = dst[0].as_ptr();
+        alloc::vec![Cow::from(&s), Cow::from(&s)].to_static_into(&mut dst);
+        assert_eq!(alloc::vec![Cow::from(&s), Cow::from(&s)], dst);
+        assert_eq!(ptr, dst.as_ptr());
+        assert_eq!(value_ptr, dst[0].as_ptr());
+        alloc::vec![Cow::from(&s); 4].to_static_into(&mut dst);
+        assert_eq!(alloc::vec![Cow::from(&s); 4], dst);
+    }
+
+    #[test]
+    fn test_option_to_static_into() {
+        let s = String::from("test");
+        let mut dst = Some(String::with_capacity(16));
+        let ptr = dst.as_ref().unwrap().as_ptr();
+        Some(s.clone()).to_static_into(&mut dst);
+        assert_eq!(Some(s.clone()), dst);
+        assert_eq!(ptr, dst.as_ref().unwrap().as_ptr());
+        None::<String>.to_static_into(&mut dst);
+        assert_eq!(None, dst);
+        Some(s.clone()).to_static_into(&mut dst);
+        assert_eq!(Some(s), dst);
+    }
+
+    #[test]
+    fn test_vec_to_static_copy() {
+        let value = alloc::vec![1_u64, 2, 3];
+        let to_static = value.to_static();
+        assert_eq!(value, to_static);
+        assert_ne!(value.as_ptr(), to_static.as_ptr());
+    }
+
+    #[test]
+    fn test_box_into_static_identity() {
+        let value = Box::new(String::from("test"));
+        let ptr: *const String = &*value;
+        let into_static = value.into_static();
+        assert!(core::ptr::eq(ptr, &*into_static));
+        let value = Box::pin(0_u64);
+        let ptr: *const u64 = &*value;
+        let into_static = value.into_static();
+        assert!(core::ptr::eq(ptr, &*into_static));
+    }
+
+    #[test]
+    fn test_box_vec_into_static_identity() {
+        let value = Box::new(alloc::vec![String::from("test")]);
+        let ptr = value.as_ptr();
+        let into_static = value.into_static();
+        assert_eq!(ptr, into_static.as_ptr());
+    }
+
+    #[test]
+    fn test_pin_box() {
+        let s = String::from("test");
+        let value = Box::pin(Cow::from(&s));
+        let to_static = value.to_static();
+        assert_eq!(value, to_static);
+        ensure_static(to_static);
+        ensure_static(value.into_static());
+    }
+
+    #[test]
+    fn test_cow_box() {
+        let s = String::new();
+        let boxed = Box::new(s);
+        let value = Cow::Borrowed(&boxed);
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_cow_struct() {
+        #[derive(Copy, Clone)]
+        struct Foo {}
+        impl ToBoundedStatic for Foo {
+            type Static = Self;
+
+            fn to_static(&self) -> Self::Static {
+                *self
+            }
+        }
+        let foo = Foo {};
+        let value = Cow::Borrowed(&foo);
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_cow_struct_of_cow() {
+        #[derive(Clone)]
+        struct Foo<'a> {
+            foo: Cow<'a, str>,
+        }
+        impl ToBoundedStatic for Foo<'_> {
+            type Static = Foo<'static>;
+
+            fn to_static(&self) -> Self::Static {
+                Foo {
+                    foo: self.foo.to_static(),
+                }
+            }
+        }
+        let s = String::new();
+        let foo = Foo { foo: Cow::from(&s) };
+        let value = Cow::Borrowed(&foo);
+        // TODO need to `into_owned()` here
+        let to_static = value.into_owned().to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_cow_cow() {
+        let s = String::new();
+        let value1: Cow<'_, str> = Cow::Borrowed(&s);
+        let value2: Cow<'_, Cow<'_, str>> = Cow::Borrowed(&value1);
+        // TODO need to `into_owned()` here
+        let to_static = value2.into_owned().to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_ref_cell_vec_cow() {
+        let s = String::from("test");
+        let value = RefCell::new(alloc::vec![Cow::from(&s)]);
+        let to_static = value.to_static();
+        assert_eq!(*value.borrow(), *to_static.borrow());
+        ensure_static(to_static);
+        ensure_static(value.into_static());
+    }
+
+    #[test]
+    fn test_impl_clone() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Foo(String);
+        #[derive(Debug, Clone, PartialEq)]
+        struct Bar<T>(Vec<T>);
+        crate::impl_clone!(Foo);
+        crate::impl_clone!(send impl[T] Bar<T> where T: Clone + 'static);
+        #[derive(Debug, Clone, PartialEq)]
+        struct Baz<'a>(&'a str, String);
+        crate::impl_clone!(impl['a] Baz<'a> where 'a: 'static);
+        let foo = Foo(String::from("foo"));
+        assert_eq!(foo, foo.to_static());
+        assert_eq!(foo, foo.clone().into_static());
+        let bar = Bar(alloc::vec![String::from("bar")]);
+        assert_eq!(bar, bar.to_static());
+        assert_eq!(bar, bar.clone().into_static());
+        assert_eq!(bar, bar.to_send_static());
+        let baz = Baz("baz", String::from("baz"));
+        assert_eq!(baz, baz.to_static());
+        assert_eq!(baz, baz.to_bounded_in(&()));
+        assert_eq!(baz, baz.to_static_as(crate::repr::Compact));
+    }
+
+    #[test]
+    fn test_impl_clone_heap() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Foo(String);
+        #[derive(Debug, Clone, PartialEq)]
+        struct Bar<T>(Vec<T>);
+        crate::impl_clone!(heap(|foo| foo.0.len()) send Foo);
+        crate::impl_clone!(
+            send heap(|bar| bar.0.len() * core::mem::size_of::<T>()) impl[T] Bar<T> where T: Copy + 'static
+        );
+        let foo = Foo(String::from("foo"));
+        assert_eq!(3, foo.static_cost().owned_bytes());
+        let mut budget = Budget::new(3);
+        assert_eq!(foo, foo.to_static_with_budget(&mut budget).unwrap());
+        assert_eq!(3, budget.used());
+        let err = foo.to_static_with_limit(2).unwrap_err();
+        assert_eq!(&ErrorKind::LimitExceeded, err.kind());
+        let bar = Bar(alloc::vec![1_u32, 2]);
+        assert_eq!(8, bar.static_cost().bytes());
+        assert!(bar.to_static_with_limit(7).is_err());
+        assert_eq!(bar, bar.to_static_with_limit(8).unwrap());
+        assert_eq!(bar, bar.clone().into_static_with_limit(0).unwrap());
+        assert_eq!(foo, foo.to_send_static());
+        assert_eq!(bar, bar.to_send_static());
+    }
+
+    #[test]
+    fn test_impl_clone_not_send() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Foo(Rc<str>);
+        crate::impl_clone!(Foo);
+        let foo = Foo(Rc::from("foo"));
+        assert_eq!(foo, foo.to_static());
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "ptr")]
+    fn test_to_send_static_rc() {
+        let s = String::from("test");
+        let value = (
+            Rc::new(alloc::vec![Cow::from(&s)]),
+            Rc::<str>::from("rc"),
+            Arc::new(Box::new(1_u8)),
+        );
+        let output = value.to_send_static();
+        drop(value);
+        drop(s);
+        let rc_str: &Arc<str> = &output.1;
+        assert_eq!(["test"], output.0.as_slice());
+        assert_eq!("rc", &**rc_str);
+        assert_eq!(1, **output.2);
+        ensure_send(output);
+    }
+
+    #[test]
+    fn test_to_send_static_static_cow() {
+        let s = String::from("test");
+        let value = [StaticCow::Borrowed(s.as_str()), StaticCow::Static("static")];
+        let output = value.to_send_static();
+        drop(s);
+        assert!(output[0].is_owned());
+        assert!(output[1].is_static());
+        ensure_send(output);
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "ptr")]
+    fn test_to_send_static_wrappers() {
+        let value = (
+            Rc::<str>::from("start")..Rc::<str>::from("end"),
+            Bound::Included(Rc::<str>::from("bound")),
+            Reverse(Rc::new(1_u8)),
+            Poll::Ready(Rc::new(2_u8)),
+            ManuallyDrop::new(Rc::new(3_u8)),
+            Pin::new(Box::new(Rc::new(4_u8))),
+            (Wrapping(5_u8), PhantomData::<Rc<u8>>),
+        );
+        let output = value.to_send_static();
+        assert_eq!(("start", "end"), (&*output.0.start, &*output.0.end));
+        assert_eq!(Bound::Included(Arc::<str>::from("bound")), output.1);
+        assert_eq!(Reverse(Arc::new(1)), output.2);
+        assert_eq!(Poll::Ready(Arc::new(2)), output.3);
+        assert_eq!(3, **output.4);
+        assert_eq!(4, **output.5);
+        assert_eq!(Wrapping(5), output.6 .0);
+        ensure_send(output);
+    }
+
+    #[test]
+    fn test_static_iterator() {
+        let s = String::from("a b c");
+        let words: Vec<Cow<'_, str>> = s.split(' ').map(Cow::from).collect();
+        let to_static: Vec<Cow<'static, str>> = words.iter().map_to_static().rev().collect();
+        let into_static: Vec<Cow<'static, str>> =
+            words.into_iter().map_into_static().skip(1).collect();
+        drop(s);
+        assert_eq!(alloc::vec!["c", "b", "a"], to_static);
+        assert_eq!(alloc::vec!["b", "c"], into_static);
+        ensure_static(to_static);
+        ensure_static(into_static);
+    }
+
+    #[test]
+    fn test_static_iterator_collect_static() {
+        let s = String::from("a b a");
+        let words: Vec<Cow<'static, str>> = s.split(' ').map(Cow::from).collect_static();
+        let mut extended = alloc::vec![Cow::Borrowed("z")];
+        s.split(' ').map(Cow::from).extend_static(&mut extended);
+        drop(s);
+        assert_eq!(alloc::vec!["a", "b", "a"], words);
+        assert_eq!(alloc::vec!["z", "a", "b", "a"], extended);
+        ensure_static(words);
+        ensure_static(extended);
+    }
+
+    #[test]
+    fn test_impl_into_owned() {
+        #[derive(Debug, PartialEq)]
+        struct Foo<'a, T>(Cow<'a, str>, T);
+        impl<T: Clone> Foo<'_, T> {
+            fn to_owned(&self) -> Foo<'static, T> {
+                Foo(Cow::Owned(String::from(self.0.as_ref())), self.1.clone())
+            }
+            fn into_owned(self) -> Foo<'static, T> {
+                Foo(Cow::Owned(self.0.into_owned()), self.1)
+            }
+        }
+        #[derive(Debug, PartialEq)]
+        struct Bar<'a>(Cow<'a, str>);
+        impl Bar<'_> {
+            fn to_owned(&self) -> Bar<'static> {
+                Bar(Cow::Owned(String::from(self.0.as_ref())))
+            }
+            fn into_owned(self) -> Bar<'static> {
+                Bar(Cow::Owned(self.0.into_owned()))
+            }
+        }
+        crate::impl_into_owned!(impl['a, T] Foo<'a, T> => Foo<'static, T> where T: Clone + 'static);
+        crate::impl_into_owned!(send Bar<'_> => Bar<'static>);
+        let s = String::from("test");
+        let foo = Foo(Cow::from(&s), 1);
+        let to_static = foo.to_static();
+        assert_eq!(foo, to_static);
+        ensure_static(to_static);
+        ensure_static(foo.into_static());
+        let bar = Bar(Cow::from(&s));
+        let to_static = bar.to_static();
+        assert_eq!(bar, to_static);
+        ensure_static(to_static);
+        assert_eq!(bar, bar.to_bounded_in(&()));
+        assert_eq!(bar, bar.to_static_as(crate::repr::Compact));
+        assert_eq!(bar, bar.to_send_static());
+        ensure_static(bar.into_static());
+    }
+
+    #[test]
+    fn test_impl_into_owned_heap() {
+        #[derive(Debug, PartialEq)]
+        struct Foo<'a>(Cow<'a, str>);
+        impl Foo<'_> {
+            fn to_owned(&self) -> Foo<'static> {
+                Foo(Cow::Owned(String::from(self.0.as_ref())))
+            }
+            fn into_owned(self) -> Foo<'static> {
+                Foo(Cow::Owned(self.0.into_owned()))
+            }
+        }
+        crate::impl_into_owned!(heap(|foo| foo.0.len()) Foo<'_> => Foo<'static>);
+        let s = String::from("test");
+        let foo = Foo(Cow::from(&s));
+        assert_eq!(4, foo.static_cost().owned_bytes());
+        assert_eq!(foo, foo.to_static_with_limit(4).unwrap());
+        let err = foo.to_static_with_limit(3).unwrap_err();
+        assert_eq!(&ErrorKind::LimitExceeded, err.kind());
+        let err = Foo(Cow::from(&s)).into_static_with_limit(3).unwrap_err();
+        assert_eq!(&ErrorKind::LimitExceeded, err.kind());
+        ensure_static(foo.into_static_with_limit(4).unwrap());
+    }
+
+    #[test]
+    fn test_struct_cow_borrowed_str() {
+        struct Foo<'a> {
+            foo: Cow<'a, str>,
+        }
+        impl ToBoundedStatic for Foo<'_> {
+            type Static = Foo<'static>;
+
+            fn to_static(&self) -> Self::Static {
+                Foo {
+                    foo: self.foo.to_static(),
+                }
+            }
+        }
+        let s = String::new();
+        let foo = Foo { foo: Cow::from(&s) };
+        let to_static = foo.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_struct_cow_owned_string() {
+        struct Foo<'a> {
+            foo: Cow<'a, str>,
+        }
+        impl ToBoundedStatic for Foo<'_> {
+            type Static = Foo<'static>;
+
+            fn to_static(&self) -> Self::Static {
+                Foo {
+                    foo: self.foo.to_static(),
+                }
+            }
+        }
+        let s = String::new();
+        let foo = Foo { foo: Cow::from(s) };
+        let to_static = foo.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_struct_multi() {
+        #[derive(Clone)]
+        struct Foo<'a> {
+            bar: Cow<'a, str>,
+            baz: Vec<Cow<'a, str>>,
+        }
+        impl ToBoundedStatic for Foo<'_> {
+            type Static = Foo<'static>;
+
+            fn to_static(&self) -> Self::Static {
+                Foo {
+                    bar: self.bar.to_static(),
+                    baz: self.baz.to_static(),
+                }
+            }
+        }
+        let s = String::new();
+        let foo = Foo {
+            bar: Cow::from(&s),
+            baz: alloc::vec![Cow::from(&s)],
+        };
+        let to_static = foo.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_struct_mixed() {
+        struct Foo<'a> {
+            prim: u64,
+            borrowed_str: &'static str,
+            owned_str: String,
+            cow_str: Cow<'a, str>,
+        }
+        impl ToBoundedStatic for Foo<'_> {
+            type Static = Foo<'static>;
+
+            fn to_static(&self) -> Self::Static {
+                Foo {
+                    prim: self.prim.to_static(),
+                    borrowed_str: self.borrowed_str.to_static(),
+                    owned_str: self.owned_str.to_static(),
+                    cow_str: self.cow_str.to_static(),
+                }
+            }
+        }
+        let s = String::new();
+        let foo = Foo {
+            prim: 0,
+            borrowed_str: "",
+            owned_str: s.clone(),
+            cow_str: Cow::from(&s),
+        };
+        let to_static = foo.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_try_cow() {
+        let s = String::from("test");
+        let value = Cow::from(&s);
+        let to_static = value.try_to_static();
+        assert_eq!(Ok(Cow::Borrowed("test")), to_static);
+        ensure_static(to_static);
+        ensure_static(value.try_into_static());
+    }
+
+    #[test]
+    fn test_try_cow_slice() {
+        let value = alloc::vec![String::from("a"), String::from("b")];
+        let borrowed = Cow::from(value.as_slice());
+        let to_static = borrowed.try_to_static().unwrap();
+        let into_static = borrowed.try_into_static().unwrap();
+        drop(value);
+        assert!(matches!(to_static, Cow::Owned(_)));
+        assert_eq!(to_static, into_static);
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_try_static_cow() {
+        let s = String::from("test");
+        let value = [StaticCow::Borrowed(s.as_str()), StaticCow::Static("static")];
+        let to_static = value.try_to_static().unwrap();
+        let into_static = value.try_into_static().unwrap();
+        drop(s);
+        assert!(to_static[0].is_owned());
+        assert!(to_static[1].is_static());
+        assert_eq!(to_static, into_static);
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_try_box() {
+        let s = String::from("test");
+        let value = Box::new(Cow::from(&s));
+        let to_static = value.try_to_static();
+        assert_eq!(Ok(Box::new(Cow::Borrowed("test"))), to_static);
+        ensure_static(to_static);
+        ensure_static(Box::into_pin(value).try_into_static());
+    }
+
+    #[test]
+    fn test_try_box_error() {
+        let value = Box::new(alloc::vec![Fallible(false)]);
+        let err = value.try_to_static().unwrap_err();
+        assert_eq!("invalid at [0]", err.to_string());
+        assert_eq!(err, value.try_into_static().unwrap_err());
+    }
+
+    #[test]
+    fn test_try_vec() {
+        let value = alloc::vec![Fallible(true), Fallible(true)];
+        assert_eq!(Ok(value.clone()), value.try_to_static());
+        assert_eq!(Ok(value.clone()), value.try_into_static());
+    }
+
+    #[test]
+    fn test_try_vec_error() {
+        let value = alloc::vec![Fallible(true), Fallible(false)];
+        let err = value.try_to_static().unwrap_err();
+        assert_eq!(&ErrorKind::Custom("invalid"), err.kind());
+        assert_eq!(
+            alloc::vec![&PathSegment::Index(1)],
+            err.path().collect::<Vec<_>>()
+        );
+        assert_eq!(err, value.try_into_static().unwrap_err());
+    }
+
+    #[test]
+    fn test_try_nested_error() {
+        let value = (
+            0,
+            Some(Box::new(alloc::vec![
+                alloc::vec![Fallible(true)],
+                alloc::vec![Fallible(true), Fallible(false)],
+            ])),
+        );
+        let err = value.try_to_static().unwrap_err();
+        assert_eq!(
+            alloc::vec![
+                &PathSegment::Index(1),
+                &PathSegment::Index(1),
+                &PathSegment::Index(1)
+            ],
+            err.path().collect::<Vec<_>>()
+        );
+        assert_eq!("invalid at [1][1][1]", err.to_string());
+    }
+
+    #[test]
+    fn test_try_result_error() {
+        let value: Result<u8, Vec<Fallible>> = Err(alloc::vec![Fallible(false)]);
+        let err = value.try_to_static().unwrap_err();
+        assert_eq!("invalid at ::Err[0]", err.to_string());
+    }
+
+    #[test]
+    fn test_try_string() {
+        let value = String::from("test");
+        let to_static = value.try_to_static();
+        assert_eq!(Ok(value.clone()), to_static);
+        ensure_static(to_static);
+        assert_eq!(Ok(value.clone()), value.try_into_static());
+    }
+
+    #[test]
+    fn test_try_alloc_error() {
+        let alloc_err = Vec::<u8>::new().try_reserve(usize::MAX).unwrap_err();
+        let err = TryStaticError::wrap(alloc_err.clone(), PathSegment::Index(0));
+        assert_eq!(&ErrorKind::Alloc(alloc_err.clone()), err.kind());
+        assert_eq!(alloc::format!("{alloc_err} at [0]"), err.to_string());
+    }
+
+    #[test]
+    fn test_try_error_display() {
+        let err = TryStaticError::new(ErrorKind::Poisoned)
+            .at(PathSegment::Value(3))
+            .at(PathSegment::Field("map"))
+            .at(PathSegment::Variant("Foo"));
+        assert_eq!("lock poisoned at ::Foo.map[value 3]", err.to_string());
+        let err = TryStaticError::from(ErrorKind::Borrowed);
+        assert_eq!("value mutably borrowed", err.to_string());
+    }
+
+    #[test]
+    fn test_string_with_limit() {
+        let value = String::from("test");
+        assert_eq!(Ok(value.clone()), value.to_static_with_limit(4));
+        let err = value.to_static_with_limit(3).unwrap_err();
+        assert_eq!(&ErrorKind::LimitExceeded, err.kind());
+        assert_eq!("memory budget exceeded", err.to_string());
+        assert_eq!(Ok(value.clone()), value.into_static_with_limit(0));
+    }
+
+    #[test]
+    fn test_cow_with_limit() {
+        let s = String::from("test");
+        let value = Cow::from(&s);
+        assert!(value.to_static_with_limit(3).is_err());
+        assert!(value.clone().into_static_with_limit(3).is_err());
+        ensure_static(value.into_static_with_limit(4).unwrap());
+        let value: Cow<'_, str> = Cow::Owned(s.clone());
+        assert!(value.to_static_with_limit(3).is_err());
+        ensure_static(value.into_static_with_limit(0).unwrap());
+    }
+
+    #[test]
+    fn test_vec_with_budget() {
+        let s = String::from("test");
+        let value = alloc::vec![Cow::from(&s), Cow::from(&s)];
+        let mut budget = Budget::new(usize::MAX);
+        let to_static = value.to_static_with_budget(&mut budget).unwrap();
+        ensure_static(to_static);
+        assert_eq!(2 * core::mem::size_of::<Cow<'_, str>>() + 8, budget.used());
+        let mut budget = Budget::new(usize::MAX);
+        let into_static = value.into_static_with_budget(&mut budget).unwrap();
+        ensure_static(into_static);
+        assert_eq!(2 * core::mem::size_of::<Cow<'_, str>>() + 8, budget.used());
+    }
+
+    #[test]
+    fn test_cow_slice_with_budget() {
+        let value = [String::from("a"), String::from("bbbb")];
+        let expected = 2 * core::mem::size_of::<String>() + 5;
+        let mut budget = Budget::new(1024);
+        let err = Cow::from(&value[..])
+            .to_static_with_budget(&mut budget)
+            .unwrap_err();
+        assert_eq!(&ErrorKind::Unmeasurable, err.kind());
+        let err = Cow::from(&value[..])
+            .into_static_with_budget(&mut budget)
+            .unwrap_err();
+        assert_eq!(&ErrorKind::Unmeasurable, err.kind());
+        assert_eq!(0, budget.used());
+        let bytes = [1_u32, 2, 3];
+        let mut budget = Budget::new(1024);
+        ensure_static(
+            Cow::from(&bytes[..])
+                .to_static_with_budget(&mut budget)
+                .unwrap(),
+        );
+        assert_eq!(12, budget.used());
+        let mut budget = Budget::new(usize::MAX);
+        ensure_static(
+            StaticCow::Borrowed(&value[..])
+                .to_static_with_budget(&mut budget)
+                .unwrap(),
+        );
+        assert_eq!(expected, budget.used());
+        let mut budget = Budget::new(usize::MAX);
+        ensure_static(
+            StaticCow::Borrowed(&value[..])
+                .into_static_with_budget(&mut budget)
+                .unwrap(),
+        );
+        assert_eq!(expected, budget.used());
+        let err = StaticCow::Borrowed(&value[..])
+            .to_static_with_limit(expected - 1)
+            .unwrap_err();
+        assert_eq!(&ErrorKind::LimitExceeded, err.kind());
+        assert_eq!(
+            alloc::vec![&PathSegment::Index(1)],
+            err.path().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_cow_slice_of_clone() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Item(u32);
+
+        let value = [Item(0), Item(1)];
+        let to_static = Cow::from(&value[..]).to_static();
+        assert_eq!(value[..], *to_static);
+        ensure_static(to_static);
+        let into_static = Cow::from(&value[..]).into_static();
+        assert_eq!(value[..], *into_static);
+        ensure_static(into_static);
+        let err = Cow::from(&value[..])
+            .to_static_with_limit(usize::MAX - 1)
+            .unwrap_err();
+        assert_eq!(&ErrorKind::Unmeasurable, err.kind());
+        assert!(Cow::from(&value[..]).try_to_static().is_ok());
+    }
+
+    #[test]
+    fn test_vec_with_limit_error() {
+        let value = alloc::vec![String::from("a"), String::from("bbbb")];
+        let limit = 2 * core::mem::size_of::<String>() + 4;
+        let err = value.to_static_with_limit(limit).unwrap_err();
+        assert_eq!(&ErrorKind::LimitExceeded, err.kind());
+        assert_eq!(
+            alloc::vec![&PathSegment::Index(1)],
+            err.path().collect::<Vec<_>>()
+        );
+        assert!(value.to_static_with_limit(limit + 1).is_ok());
+    }
+
+    #[test]
+    fn test_nested_with_limit_error() {
+        let value: Result<u8, Option<Box<(u8, String)>>> =
+            Err(Some(Box::new((0, String::from("test")))));
+        let limit = core::mem::size_of::<(u8, String)>() + 3;
+        let err = value.to_static_with_limit(limit).unwrap_err();
+        assert_eq!("memory budget exceeded at ::Err[1]", err.to_string());
+        assert!(value.into_static_with_limit(limit + 1).is_ok());
+    }
+
+    #[test]
+    fn test_static_cost_cow() {
+        let s = String::from("test");
+        let report = Cow::from(&s).static_cost();
+        assert_eq!((1, 4, 0, 0, 4), cost_tuple(&report));
+        assert!(report.is_borrowed());
+        let report = Cow::<str>::Owned(s).static_cost();
+        assert_eq!((0, 0, 1, 4, 4), cost_tuple(&report));
+        assert!(!report.is_borrowed());
+    }
+
+    #[test]
+    fn test_static_cost_matches_budget() {
+        let s = String::from("borrowed");
+        let value = (
+            alloc::vec![Cow::from(&s), Cow::Owned(String::from("owned"))],
+            Some(Box::new(String::from("boxed"))),
+            Ok::<_, u8>("static"),
+        );
+        let report = value.static_cost();
+        assert_eq!((1, 8, 2, 10, report.bytes()), cost_tuple(&report));
+        let mut budget = Budget::new(usize::MAX);
+        ensure_static(value.to_static_with_budget(&mut budget).unwrap());
+        assert_eq!(budget.used(), report.bytes());
+    }
+
+    #[test]
+    fn test_to_bounded_heap() {
+        let s = String::from("test");
+        let value = (Cow::from(&s), Some(alloc::vec![Cow::from(&s[..2])]), 1_u8);
+        let output: (Cow<'static, str>, _, _) = value.to_bounded_in(&Heap);
+        drop(s);
+        assert!(matches!(output.0, Cow::Owned(_)));
+        assert_eq!("test", output.0);
+        assert_eq!(Some(alloc::vec![Cow::from("te")]), output.1);
+        assert_eq!(1, output.2);
+    }
+
+    #[test]
+    fn test_to_bounded_heap_matches_to_static() {
+        let s = String::from("test");
+        let value = Box::new([Cow::from(&s), Cow::Owned(String::from("owned"))]);
+        assert_eq!(value.to_static(), value.to_bounded_in(&Heap));
+    }
+
+    #[test]
+    fn test_to_bounded_lifetime() {
+        fn rebind<'b>(value: &Vec<Cow<'_, str>>, alloc: &'b Heap) -> Vec<Cow<'b, str>> {
+            value.to_bounded_in(alloc)
+        }
+        let heap = Heap;
+        let output = {
+            let s = String::from("test");
+            rebind(&alloc::vec![Cow::from(&s)], &heap)
+        };
+        assert_eq!(alloc::vec!["test"], output);
+    }
+
+    #[test]
+    fn test_to_bounded_static_cow() {
+        let s = String::from("test");
+        let value = [StaticCow::Static("static"), StaticCow::Borrowed(s.as_str())];
+        let output = value.to_bounded_in(&Heap);
+        assert!(output[0].is_static());
+        assert!(output[1].is_owned());
+        let value = StaticCow::Borrowed(&[1_u8, 2][..]);
+        assert_eq!(&[1, 2], &*value.to_bounded_in(&Heap));
+    }
+
+    #[test]
+    fn test_to_bounded_custom_alloc() {
+        struct Prefix(String);
+        impl<'b> BoundedAlloc<'b> for Prefix {
+            fn alloc_str(&'b self, value: &str) -> Cow<'b, str> {
+                if self.0.starts_with(value) {
+                    Cow::Borrowed(&self.0[..value.len()])
+                } else {
+                    Cow::Owned(value.to_owned())
+                }
+            }
+        }
+        let alloc = Prefix(String::from("test value"));
+        let s = String::from("test");
+        let output = (Cow::from(&s), Cow::from(&[1_u8][..])).to_bounded_in(&alloc);
+        drop(s);
+        assert!(matches!(output.0, Cow::Borrowed("test")));
+        assert!(matches!(output.1, Cow::Owned(_)));
+    }
+
+    #[test]
+    fn test_to_bounded_wrappers() {
+        let s = String::from("test");
+        let value = (
+            RefCell::new(Cow::from(&s)),
+            Cow::from(&s)..Cow::from(&s),
+            Bound::Included(Cow::from(&s)),
+            Reverse(Cow::from(&s)),
+            ManuallyDrop::new(Cow::from(&s)),
+            Poll::Ready(Cow::from(&s)),
+            ControlFlow::<Cow<'_, str>, Cow<'_, str>>::Break(Cow::from(&s)),
+            PhantomData::<Cow<'_, str>>,
+            Pin::new(Box::new(Cow::from(&s))),
+        );
+        let output = value.to_bounded_in(&Heap);
+        drop(value);
+        drop(s);
+        assert_eq!("test", *output.0.borrow());
+        assert_eq!(Cow::from("test")..Cow::from("test"), output.1);
+        assert_eq!(Bound::Included(Cow::from("test")), output.2);
+        assert_eq!(Reverse(Cow::from("test")), output.3);
+        assert_eq!("test", *output.4);
+        assert_eq!(Poll::Ready(Cow::from("test")), output.5);
+        assert_eq!(ControlFlow::Break(Cow::from("test")), output.6);
+        assert_eq!("test", *output.8.as_ref());
+        let value = (
+            Cell::new(1_u8),
+            Wrapping(2_u8),
+            Saturating(3_u8),
+            4_u8..=5,
+            BuildHasherDefault::<()>::default(),
+        );
+        let output = value.to_bounded_in(&Heap);
+        assert_eq!(
+            (1, Wrapping(2), Saturating(3), 4..=5),
+            (output.0.get(), output.1, output.2, output.3)
+        );
+    }
+
+    #[test]
+    fn test_to_static_as_owned() {
+        let s = String::from("test");
+        let mut items = Vec::with_capacity(8);
+        items.push(Cow::from(&s));
+        let output: Vec<String> = items.to_static_as(repr::Owned);
+        drop(items);
+        drop(s);
+        assert_eq!(output, ["test"]);
+        assert_eq!(1, output.capacity());
+        ensure_static(output);
+    }
+
+    #[test]
+    fn test_to_static_as_compact() {
+        let s = String::from("test");
+        let value = (
+            Cow::from(&s),
+            Some(Cow::from(&[1_u8, 2][..])),
+            Box::new(String::from("boxed")),
+            [1_u32, 2],
+        );
+        let output = value.to_static_as(repr::Compact);
+        drop(value);
+        drop(s);
+        assert_eq!(Box::<str>::from("test"), output.0);
+        assert_eq!(Some(&[1_u8, 2][..]), output.1.as_deref());
+        assert_eq!("boxed", &**output.2);
+        assert_eq!([1, 2], output.3);
+        ensure_static(output);
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "ptr")]
+    fn test_to_static_as_shared() {
+        let s = String::from("test");
+        let value = alloc::vec![Cow::from(&s), Cow::from("other")];
+        let output: Arc<[Arc<str>]> = value.to_static_as(repr::Shared);
+        drop(value);
+        drop(s);
+        let first = Arc::clone(&output[0]);
+        assert_eq!(2, Arc::strong_count(&first));
+        assert_eq!(["test", "other"], [&*output[0], &*output[1]]);
+        ensure_static(output);
+    }
+
+    #[test]
+    fn test_to_static_as_static_cow() {
+        let s = String::from("test");
+        let value = [StaticCow::Borrowed(s.as_str()), StaticCow::Static("static")];
+        let output = value.to_static_as(repr::Compact);
+        drop(s);
+        assert_eq!(["test", "static"], [&*output[0], &*output[1]]);
+    }
+
+    #[test]
+    fn test_to_static_as_wrappers() {
+        let s = String::from("test");
+        let value = (
+            RefCell::new(Cow::from(&s)),
+            Cow::from(&s)..Cow::from(&s),
+            Bound::Excluded(Cow::from(&s)),
+            Reverse(Cow::from(&s)),
+            Poll::Ready(Cow::from(&s)),
+            Rc::new(Cow::from(&s)),
+            Pin::new(Box::new(Cow::from(&s))),
+            (Cell::new(1_u8), Wrapping(2_u8), PhantomData::<Cow<'_, str>>),
+        );
+        let output = value.to_static_as(repr::Compact);
+        drop(value);
+        drop(s);
+        assert_eq!("test", &**output.0.borrow());
+        assert_eq!(Box::<str>::from("test")..Box::from("test"), output.1);
+        assert_eq!(Bound::Excluded(Box::from("test")), output.2);
+        assert_eq!(Reverse(Box::from("test")), output.3);
+        assert_eq!(Poll::Ready(Box::from("test")), output.4);
+        assert_eq!("test", &**output.5);
+        assert_eq!("test", &**output.6);
+        assert_eq!((1, Wrapping(2)), (output.7 .0.get(), output.7 .1));
+        ensure_static(output);
+    }
+
+    #[test]
+    fn test_leak_static() {
+        let s = String::from("test");
+        let leaked: &'static Vec<Cow<'static, str>> = Vec::from([Cow::from(&s)]).leak_static();
+        drop(s);
+        assert_eq!(leaked, &["test"]);
+        ensure_static(leaked);
+    }
+
+    fn cost_tuple(report: &CostReport) -> (usize, usize, usize, usize, usize) {
+        (
+            report.borrowed(),
+            report.borrowed_bytes(),
+            report.owned(),
+            report.owned_bytes(),
+            report.bytes(),
+        )
+    }
+}
+
+#[cfg(feature = "collections")]
+#[cfg(test)]
+mod collections_tests {
+    use super::*;
+    use crate::core_tests::Fallible;
+
+    fn ensure_static<T: 'static>(t: T) {
+        drop(t);
+    }
+
+    #[test]
+    fn test_binary_heap() {
+        let s = String::new();
+        let value = BinaryHeap::from([Cow::from(&s)]);
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_btree_map() {
+        let k = String::from("key");
+        let v = String::from("value");
+        let value = BTreeMap::from([(Cow::from(&k), Cow::from(&v))]);
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_btree_set() {
+        let s = String::new();
+        let value = BTreeSet::from([Cow::from(&s)]);
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_linked_list() {
+        let s = String::new();
+        let value = LinkedList::from([Cow::from(&s)]);
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_vec_deque() {
+        let s = String::new();
+        let value = VecDeque::from([Cow::from(&s)]);
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_vec_deque_into_static_identity() {
+        let value = VecDeque::from([String::from("test")]);
+        let ptr: *const String = &value[0];
+        let into_static = value.into_static();
+        assert!(core::ptr::eq(ptr, &into_static[0]));
+    }
+
+    #[test]
+    fn test_binary_heap_into_static_identity() {
+        let value = BinaryHeap::from([1_u64, 2, 3]);
+        let ptr: *const u64 = value.peek().unwrap();
+        let into_static = value.into_static();
+        assert!(core::ptr::eq(ptr, into_static.peek().unwrap()));
+    }
+
+    #[test]
+    fn test_vec_deque_to_static_into() {
+        let s = String::from("test");
+        let mut dst: VecDeque<Cow<'static, str>> =
+            VecDeque::from([Cow::Owned(String::with_capacity(16))]);
+        let value_ptr = dst[0].as_ptr();
+        VecDeque::from([Cow::from(&s), Cow::from(&s)]).to_static_into(&mut dst);
+        assert_eq!(VecDeque::from([Cow::from(&s), Cow::from(&s)]), dst);
+        assert_eq!(value_ptr, dst[0].as_ptr());
+    }
+
+    #[test]
+    fn test_binary_heap_to_static_into() {
+        let mut dst = BinaryHeap::with_capacity(16);
+        BinaryHeap::from([1_u64, 3, 2]).to_static_into(&mut dst);
+        assert_eq!(alloc::vec![1, 2, 3], dst.into_sorted_vec());
+    }
+
+    #[test]
+    fn test_try_btree_map() {
+        let k = String::from("key");
+        let value = BTreeMap::from([(Cow::from(&k), Fallible(true))]);
+        let to_static = value.try_to_static();
+        ensure_static(to_static.clone());
+        assert_eq!(Ok(value.clone()), to_static);
+        assert_eq!(Ok(value.clone()), value.try_into_static());
+    }
+
+    #[test]
+    fn test_try_btree_map_error() {
+        let value = BTreeMap::from([(1, Fallible(true)), (2, Fallible(false))]);
+        let err = value.try_to_static().unwrap_err();
+        assert_eq!(
+            alloc::vec![&PathSegment::Value(1)],
+            err.path().collect::<Vec<_>>()
+        );
+        let value = BTreeMap::from([(Fallible(false), 1)]);
+        let err = value.try_into_static().unwrap_err();
+        assert_eq!(
+            alloc::vec![&PathSegment::Key(0)],
+            err.path().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_try_vec_deque_error() {
+        let value = VecDeque::from([Fallible(true), Fallible(false)]);
+        let err = value.try_to_static().unwrap_err();
+        assert_eq!(
+            alloc::vec![&PathSegment::Index(1)],
+            err.path().collect::<Vec<_>>()
+        );
+        assert!(value.try_into_static().is_err());
+    }
+
+    #[test]
+    fn test_btree_map_with_limit_error() {
+        let value = BTreeMap::from([(1, String::from("a")), (2, String::from("bbbb"))]);
+        let limit = 2 * core::mem::size_of::<(i32, String)>() + 4;
+        let err = value.to_static_with_limit(limit).unwrap_err();
+        assert_eq!(&ErrorKind::LimitExceeded, err.kind());
+        assert_eq!(
+            alloc::vec![&PathSegment::Value(1)],
+            err.path().collect::<Vec<_>>()
+        );
+        assert_eq!(Ok(value.clone()), value.into_static_with_limit(limit));
+    }
+
+    #[test]
+    fn test_vec_deque_with_limit() {
+        let value = VecDeque::from([String::from("test")]);
+        let limit = core::mem::size_of::<String>();
+        assert!(value.to_static_with_limit(limit).is_err());
+        assert_eq!(Ok(value.clone()), value.to_static_with_limit(limit + 4));
+    }
+
+    #[test]
+    fn test_static_cost_btree_map() {
+        let k = String::from("key");
+        let value = BTreeMap::from([(Cow::from(&k), String::from("value"))]);
+        let report = value.static_cost();
+        assert_eq!(1, report.borrowed());
+        assert_eq!(1, report.owned());
+        assert_eq!(
+            core::mem::size_of::<(Cow<'_, str>, String)>() + 8,
+            report.bytes()
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod std_tests {
+    use core::any::Any;
+
+    use super::*;
+    use crate::core_tests::Fallible;
+    use std::string::ToString;
+    use test_case::test_case;
+
+    fn ensure_static<T: 'static>(t: T) {
+        drop(t);
+    }
+
+    fn ensure_send<T: Send + Sync + 'static>(t: T) {
+        drop(t);
+    }
+
+    #[test]
+    fn test_hash_map_to_static_with() {
+        let s = String::from("test");
+        let shared = Rc::new(Cow::from(&s));
+        let value = std::collections::HashMap::from([(1, shared.clone()), (2, shared)]);
+        let to_static = StaticConverter::new().convert(&value);
+        assert!(Rc::ptr_eq(&to_static[&1], &to_static[&2]));
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_static_cow_hash() {
+        let s = String::from("test");
+        let set: std::collections::HashSet<_> = [
+            StaticCow::Static("test"),
+            StaticCow::from(s.as_str()),
+            StaticCow::from(String::from("test")),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(1, set.len());
+        ensure_static(set.into_static());
+    }
+
+    #[test]
+    fn test_hashmap1() {
+        let k = String::from("key");
+        let v = String::from("value");
+        let value = std::collections::HashMap::from([(Cow::from(&k), Cow::from(&v))]);
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_hashmap2() {
+        let k = "key";
+        let v = String::from("value");
+        let value = std::collections::HashMap::from([(k, Cow::from(&v))]);
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_hashmap3() {
+        let k = String::from("key");
+        let v = 0i16;
+        let value = std::collections::HashMap::from([(Cow::from(&k), v)]);
+        let to_static = value.to_static();
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_hashset() {
+        let value = String::from("data");
+        let value = std::collections::HashSet::from([(Cow::from(&value))]);
+        let to_static = value.to_static();
+        ensure_static(to_static);
     }
 
     #[test]
-    fn test_unit() {
-        #[allow(clippy::unit_arg)]
-        ensure_static(().to_static());
+    fn test_hash_map_into_static_identity() {
+        let value = std::collections::HashMap::from([(String::from("key"), 0_u64)]);
+        let ptr: *const u64 = &value["key"];
+        let into_static = value.into_static();
+        assert!(core::ptr::eq(ptr, &into_static["key"]));
     }
 
     #[test]
-    fn test_str() {
-        let s = "";
-        let to_static = s.to_static();
+    fn test_hash_map_to_static_copy() {
+        let value = std::collections::HashMap::from([("key", 0_u64), ("other", 1)]);
+        let to_static = value.to_static();
+        assert_eq!(value, to_static);
+        assert!(!core::ptr::eq(&value["key"], &to_static["key"]));
+    }
+
+    /// A `BuildHasher` which counts the number of values hashed with it, and with any clone of it.
+    #[derive(Clone, Default)]
+    pub struct CountingState(pub Rc<Cell<usize>>);
+
+    impl std::hash::BuildHasher for CountingState {
+        type Hasher = std::collections::hash_map::DefaultHasher;
+
+        fn build_hasher(&self) -> Self::Hasher {
+            self.0.set(self.0.get() + 1);
+            std::collections::hash_map::DefaultHasher::default()
+        }
+    }
+
+    crate::impl_build_hasher!(CountingState);
+
+    #[test]
+    fn test_hash_map_to_static_clone() {
+        let mut value = std::collections::HashMap::with_hasher(CountingState::default());
+        value.insert(String::from("key"), String::from("value"));
+        value.insert(String::from("other"), String::from("data"));
+        let hashed = value.hasher().0.get();
+        let to_static = value.to_static();
+        assert_eq!(hashed, value.hasher().0.get());
+        assert_eq!(value, to_static);
+    }
+
+    #[test]
+    fn test_hash_set_to_static_clone() {
+        let mut value = std::collections::HashSet::with_hasher(CountingState::default());
+        value.insert(String::from("key"));
+        value.insert(String::from("other"));
+        let hashed = value.hasher().0.get();
+        let to_static = value.to_static();
+        assert_eq!(hashed, value.hasher().0.get());
+        assert_eq!(value, to_static);
+    }
+
+    #[test]
+    fn test_hash_set_into_static_identity() {
+        let value = std::collections::HashSet::from([String::from("data")]);
+        let ptr: *const String = value.iter().next().unwrap();
+        let into_static = value.into_static();
+        assert!(core::ptr::eq(ptr, into_static.iter().next().unwrap()));
+    }
+
+    #[test]
+    fn test_hash_set_to_static_copy() {
+        let value = std::collections::HashSet::from([0_u64, 1, 2]);
+        let to_static = value.to_static();
+        assert_eq!(value, to_static);
+    }
+
+    #[test]
+    fn test_hash_map_to_static_into() {
+        let k = String::from("key");
+        let mut dst = std::collections::HashMap::with_capacity(16);
+        dst.insert(Cow::from("other"), 1);
+        let capacity = dst.capacity();
+        std::collections::HashMap::from([(Cow::from(&k), 0)]).to_static_into(&mut dst);
+        assert_eq!(std::collections::HashMap::from([(Cow::from(&k), 0)]), dst);
+        assert_eq!(capacity, dst.capacity());
+    }
+
+    #[test]
+    fn test_mutex() {
+        let k = String::from("key");
+        let value = std::sync::Mutex::new(std::collections::HashMap::from([(Cow::from(&k), 0)]));
+        let to_static = value.to_static();
+        assert_eq!(*value.lock().unwrap(), *to_static.lock().unwrap());
         ensure_static(to_static);
+        ensure_static(value.into_static());
     }
 
     #[test]
-    fn test_option_none() {
-        let value: Option<u32> = None;
+    fn test_mutex_poisoned() {
+        let s = String::from("test");
+        let value = std::sync::Mutex::new(Cow::from(&s));
+        let _ = std::panic::catch_unwind(|| {
+            let _guard = value.lock().unwrap();
+            panic!("poison");
+        });
+        assert!(value.is_poisoned());
+        let to_static = value.to_static();
+        assert!(!to_static.is_poisoned());
+        assert_eq!("test", *to_static.lock().unwrap());
+        let into_static = value.into_static();
+        assert!(!into_static.is_poisoned());
+        assert_eq!("test", *into_static.lock().unwrap());
+    }
+
+    #[test]
+    fn test_rw_lock() {
+        let s = String::from("test");
+        let value = std::sync::RwLock::new(alloc::vec![Cow::from(&s)]);
         let to_static = value.to_static();
+        assert_eq!(*value.read().unwrap(), *to_static.read().unwrap());
         ensure_static(to_static);
+        ensure_static(value.into_static());
     }
 
     #[test]
-    fn test_option_some() {
-        let value: Option<u32> = Some(32);
+    fn test_rw_lock_poisoned() {
+        let s = String::from("test");
+        let value = std::sync::RwLock::new(Cow::from(&s));
+        let _ = std::panic::catch_unwind(|| {
+            let _guard = value.write().unwrap();
+            panic!("poison");
+        });
+        assert!(value.is_poisoned());
+        let to_static = value.to_static();
+        assert!(!to_static.is_poisoned());
+        assert_eq!("test", *to_static.read().unwrap());
+        let into_static = value.into_static();
+        assert!(!into_static.is_poisoned());
+        assert_eq!("test", *into_static.read().unwrap());
+    }
+
+    #[test]
+    fn test_once_lock() {
+        let s = String::from("test");
+        let value = std::sync::OnceLock::from(Cow::from(&s));
         let to_static = value.to_static();
+        assert_eq!(value.get(), to_static.get());
         ensure_static(to_static);
+        ensure_static(value.into_static());
+        let empty: std::sync::OnceLock<Cow<'_, str>> = std::sync::OnceLock::new();
+        assert!(empty.to_static().get().is_none());
     }
 
     #[test]
-    fn test_result() {
-        #[derive(Clone)]
-        struct MyError;
-        #[allow(clippy::unnecessary_wraps)]
-        fn foo_ok() -> Result<(), MyError> {
-            Ok(())
-        }
-        #[allow(clippy::unnecessary_wraps)]
-        fn foo_err() -> Result<(), MyError> {
-            Err(MyError)
+    fn test_to_send_static_ref_cell() {
+        let s = String::from("test");
+        let value = Rc::new(RefCell::new(alloc::vec![Cow::from(&s)]));
+        let output = value.to_send_static();
+        drop(value);
+        drop(s);
+        std::thread::spawn(move || {
+            output.lock().unwrap().push(Cow::from("other"));
+            assert_eq!(["test", "other"], output.lock().unwrap().as_slice());
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[test]
+    fn test_to_send_static_once_cell() {
+        let value = OnceCell::from(Rc::<str>::from("test"));
+        let output = value.to_send_static();
+        assert_eq!(Some("test"), output.get().map(AsRef::as_ref));
+        ensure_send(output);
+        let empty: OnceCell<Rc<str>> = OnceCell::new();
+        assert!(empty.to_send_static().get().is_none());
+    }
+
+    #[test]
+    fn test_to_send_static_hash_map() {
+        let s = String::from("test");
+        let value = std::collections::HashMap::from([(Cow::from(&s), Rc::new(Cell::new(1_u8)))]);
+        let output = value.to_send_static();
+        drop(value);
+        drop(s);
+        let count = output
+            .get("test")
+            .map(|count| count.load(core::sync::atomic::Ordering::Relaxed));
+        assert_eq!(Some(1), count);
+        ensure_send(output);
+    }
+
+    #[test]
+    fn test_to_send_static_sync() {
+        let value = (
+            std::sync::Mutex::new(alloc::vec![Rc::<str>::from("mutex")]),
+            std::sync::RwLock::new(Rc::new(1_u8)),
+            std::sync::OnceLock::from(Rc::<str>::from("once")),
+            std::collections::hash_map::RandomState::new(),
+        );
+        let output = value.to_send_static();
+        assert_eq!("mutex", &*output.0.lock().unwrap()[0]);
+        assert_eq!(1, **output.1.read().unwrap());
+        assert_eq!(Some("once"), output.2.get().map(AsRef::as_ref));
+        ensure_send(output);
+    }
+
+    #[test]
+    fn test_to_send_static_boxed_error() {
+        let value: Box<dyn std::error::Error> = Box::from("error");
+        let output = value.to_send_static();
+        assert_eq!("error", output.to_string());
+        ensure_send(output);
+    }
+
+    #[derive(Debug)]
+    struct ParseError<'a> {
+        input: &'a str,
+        source: Option<std::io::Error>,
+    }
+
+    impl core::fmt::Display for ParseError<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "invalid input: {}", self.input)
         }
-        impl ToBoundedStatic for MyError {
-            type Static = Self;
+    }
 
-            fn to_static(&self) -> Self::Static {
-                self.clone()
-            }
+    impl std::error::Error for ParseError<'_> {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            self.source
+                .as_ref()
+                .map(|source| source as &(dyn std::error::Error + 'static))
         }
-        let ok_result = foo_ok();
-        ensure_static(ok_result.to_static());
-        assert!(ok_result.is_ok());
-        let err_result = foo_err();
-        ensure_static(err_result.to_static());
-        assert!(err_result.is_err());
     }
 
     #[test]
-    fn test_array() {
-        let arr = ["test"];
-        ensure_static(arr.to_static());
+    fn test_static_error() {
+        let s = String::from("[1, 2");
+        let source = std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "eof");
+        let err = error::StaticError::new(&ParseError {
+            input: &s,
+            source: Some(source),
+        });
+        drop(s);
+        assert_eq!("invalid input: [1, 2", err.message());
+        assert_eq!("invalid input: [1, 2", std::format!("{err}"));
+        assert_eq!("invalid input: [1, 2: eof", std::format!("{err:#}"));
+        assert!(err
+            .debug_message()
+            .starts_with("ParseError { input: \"[1, 2\""));
+        assert_eq!(std::format!("{err:?}"), err.debug_message());
+        let messages: Vec<_> = err.chain().map(error::StaticError::message).collect();
+        assert_eq!(alloc::vec!["invalid input: [1, 2", "eof"], messages);
+        let source = std::error::Error::source(&err).map(std::string::ToString::to_string);
+        assert_eq!(Some(String::from("eof")), source);
+        assert_eq!(err, err.to_static());
+        ensure_send(err);
     }
 
     #[test]
-    fn test_tuple2() {
-        let tuple = ("test", 32);
-        ensure_static(tuple.to_static());
+    fn test_spawn_static() {
+        let method = String::from("GET");
+        let path = String::from("/index.html");
+        let value = (Cow::from(&method), alloc::vec![Cow::from(&path)]);
+        let handle = thread::spawn_static(&value, |(method, paths)| {
+            ensure_static((method.clone(), paths.clone()));
+            std::format!("{method} {}", paths[0])
+        });
+        drop(value);
+        drop(method);
+        drop(path);
+        assert_eq!("GET /index.html", handle.join().unwrap());
     }
 
     #[test]
-    fn test_tuple11() {
-        let tuple = (
-            (),
-            '1',
-            "2",
-            3_i32,
-            4_usize,
-            5_isize,
-            6.0_f64,
-            ["7"],
-            Some(8),
-            9,
-            (10,),
-            false,
+    fn test_spawn_static_builder() {
+        let s = String::from("value");
+        let value = Cow::from(&s);
+        let handle = thread::Builder::new()
+            .name(String::from("worker"))
+            .stack_size(64 * 1024)
+            .spawn_static(&value, |value| {
+                let name = std::thread::current().name().map(String::from);
+                (value, name)
+            })
+            .unwrap();
+        drop(value);
+        drop(s);
+        let (value, name) = handle.join().unwrap();
+        assert_eq!("value", value);
+        assert_eq!(Some(String::from("worker")), name);
+    }
+
+    #[test]
+    fn test_box_dyn_error() {
+        let s = String::from("[1, 2");
+        let value: Box<dyn std::error::Error + '_> = Box::new(ParseError {
+            input: &s,
+            source: None,
+        });
+        let to_static = value.to_static();
+        let into_static = value.into_static();
+        drop(s);
+        assert_eq!("invalid input: [1, 2", std::format!("{to_static}"));
+        assert_eq!("invalid input: [1, 2", std::format!("{into_static}"));
+        assert!(to_static.source().is_none());
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_box_dyn_error_send_sync() {
+        let s = String::from("[1, 2");
+        let source = std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "eof");
+        let value: Box<dyn std::error::Error + Send + Sync + '_> = Box::new(ParseError {
+            input: &s,
+            source: Some(source),
+        });
+        let output = value.try_into_static().unwrap();
+        drop(s);
+        assert_eq!("invalid input: [1, 2", std::format!("{output}"));
+        assert_eq!(
+            Some(String::from("eof")),
+            output.source().map(std::string::ToString::to_string)
         );
-        ensure_static(tuple.to_static());
+        ensure_send(output);
     }
-}
 
-#[cfg(feature = "alloc")]
-#[cfg(test)]
-mod alloc_tests {
-    use super::*;
+    #[test]
+    fn test_box_dyn_error_with_budget() {
+        let value: Box<dyn std::error::Error + Send + Sync> = Box::new(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "eof",
+        ));
+        let captured = error::StaticError::new(&*value);
+        let bytes = core::mem::size_of::<error::StaticError>()
+            + captured.message().len()
+            + captured.debug_message().len();
+        let report = value.static_cost();
+        assert_eq!((1, bytes), (report.owned(), report.owned_bytes()));
+        assert!(value
+            .to_static_with_budget(&mut Budget::new(bytes - 1))
+            .is_err());
+        let mut budget = Budget::new(bytes);
+        let output = value.into_static_with_budget(&mut budget).unwrap();
+        assert_eq!(bytes, budget.used());
+        assert!(output.downcast_ref::<std::io::Error>().is_none());
+        assert!(output.downcast_ref::<error::StaticError>().is_some());
+    }
 
-    fn ensure_static<T: 'static>(t: T) {
-        drop(t);
+    #[test]
+    fn test_static_error_with_budget() {
+        let captured = error::StaticError::new(&std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "eof",
+        ));
+        let bytes = captured.message().len() + captured.debug_message().len();
+        assert_eq!(bytes, captured.static_cost().owned_bytes());
+        assert!(captured.to_static_with_limit(bytes - 1).is_err());
+        assert_eq!(captured, captured.to_static_with_limit(bytes).unwrap());
+    }
+
+    #[test_case(std::time::Instant::now(); "instant")]
+    #[test_case(std::time::SystemTime::now(); "system time")]
+    #[test_case(std::net::IpAddr::from([127, 0, 0, 1]); "ip addr")]
+    #[test_case(std::net::Ipv4Addr::LOCALHOST; "ipv4 addr")]
+    #[test_case(std::net::Ipv6Addr::LOCALHOST; "ipv6 addr")]
+    #[test_case(std::net::SocketAddr::from(([127, 0, 0, 1], 80)); "socket addr")]
+    #[test_case(std::net::SocketAddrV4::new(std::net::Ipv4Addr::LOCALHOST, 80); "socket addr v4")]
+    #[test_case(std::net::SocketAddrV6::new(std::net::Ipv6Addr::LOCALHOST, 80, 0, 0); "socket addr v6")]
+    #[allow(clippy::needless_pass_by_value)]
+    fn test_copy<T: ToBoundedStatic + IntoBoundedStatic>(t: T) {
+        ensure_static(t.to_static());
+        ensure_static(t.into_static());
     }
 
     #[test]
-    fn test_string() {
-        let s = String::new();
-        let to_static = s.to_static();
+    fn test_path_buf() {
+        let value = std::path::PathBuf::from("/tmp/test");
+        let to_static = value.to_static();
+        assert_eq!(value, to_static);
         ensure_static(to_static);
+        ensure_static(value.into_static());
     }
 
     #[test]
-    fn test_cow_borrowed_str() {
-        let s = String::new();
-        let to_static = Cow::from(&s).to_static();
+    fn test_box_path() {
+        let value = std::path::PathBuf::from("/tmp/test").into_boxed_path();
+        let to_static = value.to_static();
+        assert_eq!(value, to_static);
+        ensure_static(to_static);
+        ensure_static(value.into_static());
+    }
+
+    #[test]
+    fn test_cow_path() {
+        let s = String::from("/tmp/test");
+        let value = Cow::from(std::path::Path::new(&s));
+        ensure_static(value.to_static());
+        ensure_static(value.into_static());
+    }
+
+    #[test]
+    fn test_os_string() {
+        let value = std::ffi::OsString::from("test");
+        let to_static = value.to_static();
+        assert_eq!(value, to_static);
+        ensure_static(to_static);
+        ensure_static(value.into_static());
+    }
+
+    #[test]
+    fn test_box_os_str() {
+        let value = std::ffi::OsString::from("test").into_boxed_os_str();
+        let to_static = value.to_static();
+        assert_eq!(value, to_static);
+        ensure_static(to_static);
+        ensure_static(value.into_static());
+    }
+
+    #[test]
+    fn test_cow_os_str() {
+        let s = String::from("test");
+        let value = Cow::from(std::ffi::OsStr::new(&s));
+        ensure_static(value.to_static());
+        ensure_static(value.into_static());
+    }
+
+    #[test]
+    fn test_build_hasher_default_hashmap() {
+        type State = core::hash::BuildHasherDefault<std::collections::hash_map::DefaultHasher>;
+        let k = String::from("key");
+        let value = std::collections::HashMap::<_, _, State>::from_iter([(Cow::from(&k), 0)]);
+        let to_static = value.to_static();
+        assert_eq!(value, to_static);
+        ensure_static(to_static);
+        let value = std::collections::HashSet::<_, State>::from_iter([Cow::from(&k)]);
+        let into_static = value.clone().into_static();
+        assert_eq!(value, into_static);
+        ensure_static(into_static);
+    }
+
+    #[test]
+    fn test_impl_build_hasher() {
+        #[derive(Clone, Default)]
+        struct MyState;
+
+        impl std::hash::BuildHasher for MyState {
+            type Hasher = std::collections::hash_map::DefaultHasher;
+
+            fn build_hasher(&self) -> Self::Hasher {
+                std::collections::hash_map::DefaultHasher::default()
+            }
+        }
+
+        crate::impl_build_hasher!(MyState);
+
+        let k = String::from("key");
+        let value = std::collections::HashMap::<_, _, MyState>::from_iter([(Cow::from(&k), 0)]);
+        let to_static = value.to_static();
+        assert_eq!(value, to_static);
+        ensure_static(to_static);
+        let into_static = value.clone().into_static();
+        assert_eq!(value, into_static);
+        ensure_static(into_static);
+        let try_to_static = value.try_to_static().unwrap();
+        assert_eq!(value, try_to_static);
+        ensure_static(try_to_static);
+    }
+
+    #[test]
+    fn test_custom_random_state() {
+        #[derive(Clone, Default)]
+        struct RandomState;
+
+        impl std::hash::BuildHasher for RandomState {
+            type Hasher = std::collections::hash_map::DefaultHasher;
+
+            fn build_hasher(&self) -> Self::Hasher {
+                std::collections::hash_map::DefaultHasher::default()
+            }
+        }
+
+        impl ToBoundedStatic for RandomState {
+            type Static = Self;
+
+            fn to_static(&self) -> Self::Static {
+                self.clone()
+            }
+        }
+
+        let k = "key";
+        let v = 0i16;
+        let value = std::collections::HashMap::<_, _, RandomState>::from_iter([(k, v)]);
+        let to_static = value.to_static();
+        assert_eq!(value.type_id(), to_static.type_id());
+        ensure_static(to_static);
+        let value = std::collections::HashSet::<_, RandomState>::from_iter([k]);
+        let to_static = value.to_static();
+        assert_eq!(value.type_id(), to_static.type_id());
         ensure_static(to_static);
     }
+    #[test]
+    fn test_try_hash_map_error() {
+        let value = std::collections::HashMap::from([(1, Fallible(false))]);
+        let err = value.try_to_static().unwrap_err();
+        assert_eq!(
+            alloc::vec![&PathSegment::Value(0)],
+            err.path().collect::<Vec<_>>()
+        );
+        assert!(value.try_into_static().is_err());
+        let s = String::from("test");
+        let value = std::collections::HashMap::from([(Cow::from(&s), Fallible(true))]);
+        ensure_static(value.try_to_static().unwrap());
+        ensure_static(value.try_into_static().unwrap());
+    }
+
+    #[test]
+    fn test_try_mutex() {
+        let value = std::sync::Mutex::new(alloc::vec![Fallible(true)]);
+        let to_static = value.try_to_static().unwrap();
+        assert_eq!(*value.lock().unwrap(), *to_static.lock().unwrap());
+        ensure_static(value.try_into_static().unwrap());
+    }
+
+    #[test]
+    fn test_try_mutex_poisoned() {
+        let s = String::from("test");
+        let value = std::sync::Mutex::new(Cow::from(&s));
+        let _ = std::panic::catch_unwind(|| {
+            let _guard = value.lock().unwrap();
+            panic!("poison");
+        });
+        let err = value.try_to_static().unwrap_err();
+        assert_eq!(&ErrorKind::Poisoned, err.kind());
+        let err = value.try_into_static().unwrap_err();
+        assert_eq!(&ErrorKind::Poisoned, err.kind());
+    }
 
     #[test]
-    fn test_cow_owned_string() {
-        let s = String::new();
-        let to_static = Cow::from(s).to_static();
-        ensure_static(to_static);
+    fn test_try_rw_lock_poisoned() {
+        let value = std::sync::RwLock::new(1);
+        let _ = std::panic::catch_unwind(|| {
+            let _guard = value.write().unwrap();
+            panic!("poison");
+        });
+        let err = value.try_to_static().unwrap_err();
+        assert_eq!(&ErrorKind::Poisoned, err.kind());
+        assert_eq!("lock poisoned", err.to_string());
     }
 
     #[test]
-    fn test_cow_to_static() {
-        let s = String::new();
-        let s_cow: Cow<'_, str> = Cow::Borrowed(&s);
-        let s1_cow_owned: Cow<'_, str> = s_cow.to_static();
-        let s2_cow_owned: Cow<'_, str> = Cow::Owned(s_cow.into_owned());
-        assert_eq!(s1_cow_owned, s2_cow_owned);
+    fn test_hash_map_with_limit_error() {
+        let value = std::collections::HashMap::from([(String::from("key"), 0)]);
+        let limit = core::mem::size_of::<(String, i32)>() + 2;
+        let err = value.to_static_with_limit(limit).unwrap_err();
+        assert_eq!(
+            alloc::vec![&PathSegment::Key(0)],
+            err.path().collect::<Vec<_>>()
+        );
+        assert_eq!(Ok(value.clone()), value.to_static_with_limit(limit + 1));
     }
 
     #[test]
-    fn test_cow_into_static() {
-        let s = String::new();
-        let s_cow: Cow<'_, str> = Cow::Borrowed(&s);
-        let s1_cow_owned: Cow<'_, str> = s_cow.clone().into_static();
-        let s2_cow_owned: Cow<'_, str> = Cow::Owned(s_cow.into_owned());
-        assert_eq!(s1_cow_owned, s2_cow_owned);
+    fn test_path_buf_with_limit() {
+        let value = std::path::PathBuf::from("/tmp");
+        assert!(value.to_static_with_limit(3).is_err());
+        assert_eq!(Ok(value.clone()), value.to_static_with_limit(4));
+        assert_eq!(Ok(value.clone()), value.into_static_with_limit(0));
     }
 
     #[test]
-    fn test_option_none() {
-        let value: Option<Cow<'_, str>> = None;
-        let to_static = value.to_static();
-        ensure_static(to_static);
+    fn test_mutex_with_limit() {
+        let value = std::sync::Mutex::new(String::from("test"));
+        assert!(value.to_static_with_limit(3).is_err());
+        let to_static = value.to_static_with_limit(4).unwrap();
+        assert_eq!(*value.lock().unwrap(), *to_static.lock().unwrap());
     }
 
     #[test]
-    fn test_option_some() {
-        let s = String::new();
-        let value = Some(Cow::from(&s));
-        let to_static = value.to_static();
-        ensure_static(to_static);
+    fn test_static_cost_hash_map() {
+        let k = String::from("key");
+        let value =
+            std::collections::HashMap::from([(Cow::from(&k), std::path::PathBuf::from("/tmp"))]);
+        let report = value.static_cost();
+        assert_eq!((1, 3), (report.borrowed(), report.borrowed_bytes()));
+        assert_eq!((1, 4), (report.owned(), report.owned_bytes()));
+        let mut budget = Budget::new(usize::MAX);
+        ensure_static(value.to_static_with_budget(&mut budget).unwrap());
+        assert_eq!(budget.used(), report.bytes());
     }
 
     #[test]
-    fn test_array() {
-        let arr = ["test"];
-        ensure_static(arr.to_static());
+    fn test_to_bounded_hash_map() {
+        let s = String::from("test");
+        let value = std::collections::HashMap::from([(Cow::from(&s), Cow::from(&s))]);
+        let output: std::collections::HashMap<Cow<'_, str>, Cow<'_, str>> =
+            value.to_bounded_in(&Heap);
+        drop(value);
+        drop(s);
+        assert_eq!(Some(&Cow::from("test")), output.get("test"));
     }
 
     #[test]
-    fn test_array_into() {
-        let s = String::new();
-        let arr = [Cow::from(&s)];
-        ensure_static(arr.into_static());
+    fn test_to_bounded_sync() {
+        let s = String::from("test");
+        let value = (
+            std::sync::Mutex::new(Cow::from(&s)),
+            std::sync::RwLock::new(Cow::from(&s)),
+            std::sync::OnceLock::from(Cow::from(&s)),
+            std::collections::hash_map::RandomState::new(),
+        );
+        let output = value.to_bounded_in(&Heap);
+        drop(value);
+        drop(s);
+        assert_eq!("test", *output.0.lock().unwrap());
+        assert_eq!("test", *output.1.read().unwrap());
+        assert_eq!(Some(&Cow::from("test")), output.2.get());
     }
 
     #[test]
-    fn test_vec1() {
-        let s = String::new();
-        let value = alloc::vec![Cow::from(&s)];
-        let to_static = value.to_static();
-        ensure_static(to_static);
+    fn test_to_bounded_boxed_error() {
+        let value: Box<dyn std::error::Error + Send + Sync> = Box::from("error");
+        let output = value.to_bounded_in(&Heap);
+        assert_eq!("error", output.to_string());
     }
 
     #[test]
-    fn test_vec2() {
-        let s = String::new();
-        let value = alloc::vec![Cow::from(&s), Cow::from(s.as_str())];
-        let to_static = value.to_static();
-        ensure_static(to_static);
+    fn test_to_static_as_sync() {
+        let s = String::from("test");
+        let value = (
+            std::sync::Mutex::new(Cow::from(&s)),
+            std::sync::RwLock::new(alloc::vec![Cow::from(&s)]),
+            std::sync::OnceLock::from(Cow::from(&s)),
+        );
+        let output = value.to_static_as(repr::Compact);
+        drop(value);
+        drop(s);
+        assert_eq!("test", &**output.0.lock().unwrap());
+        assert_eq!(["test"], [&*output.1.read().unwrap()[0]]);
+        assert_eq!(Some("test"), output.2.get().map(AsRef::as_ref));
+        let value: Box<dyn std::error::Error> = Box::from("error");
+        assert_eq!("error", value.to_static_as(repr::Owned).to_string());
     }
 
     #[test]
-    fn test_box() {
-        let s = String::new();
-        let value = Box::new(s);
-        let to_static = value.to_static();
-        ensure_static(to_static);
+    fn test_to_static_as_hash_map() {
+        let s = String::from("test");
+        let value = std::collections::HashMap::from([(Cow::from(&s), alloc::vec![1_u8, 2])]);
+        let output = value.to_static_as(repr::Compact);
+        drop(value);
+        drop(s);
+        assert_eq!(Some(&[1_u8, 2][..]), output.get("test").map(AsRef::as_ref));
+        ensure_static(output);
     }
 
     #[test]
-    fn test_box_cow() {
-        let s = String::new();
-        let value = Box::new(Cow::from(&s));
-        let to_static = value.to_static();
-        ensure_static(to_static);
+    fn test_static_arena() {
+        let arena = StaticArena::with_chunk_len(2);
+        let s = String::from("test");
+        let values: Vec<_> = (0..3).map(|_| arena.promote(Cow::from(&s))).collect();
+        drop(s);
+        assert!(values.iter().all(StaticRef::is_leaked));
+        assert_eq!(values[0], values[2]);
+        let value: &'static Cow<'static, str> = values[1].as_static().unwrap();
+        assert_eq!("test", value);
+        assert_eq!(3, arena.promoted());
+        let chunk = core::mem::size_of::<std::sync::OnceLock<Cow<'static, str>>>() * 2;
+        assert_eq!(chunk * 2, arena.leaked_bytes());
     }
 
     #[test]
-    fn test_box_vec_cow() {
-        let s = String::new();
-        let value = Box::new(alloc::vec![Cow::from(&s)]);
-        let to_static = value.to_static();
-        ensure_static(to_static);
+    fn test_static_arena_types() {
+        let arena = StaticArena::with_chunk_len(4);
+        let s = String::from("test");
+        assert_eq!("test", *arena.promote(Cow::from(&s)));
+        assert_eq!(1, *arena.promote(1_u8));
+        let value = arena.promote_ref(&Some(Cow::from(&s)));
+        assert_eq!(Some("test"), value.as_deref());
+        assert_eq!(3, arena.promoted());
     }
 
     #[test]
-    fn test_vec_box_cow() {
-        let s = String::new();
-        let value = alloc::vec![Box::new(Cow::from(&s))];
-        let to_static = value.to_static();
-        ensure_static(to_static);
+    fn test_static_arena_disabled() {
+        let arena = StaticArena::new();
+        arena.set_enabled(false);
+        let s = String::from("test");
+        let value = arena.promote(Cow::from(&s));
+        drop(s);
+        assert!(!value.is_leaked());
+        assert_eq!(None, value.as_static());
+        assert_eq!("test", *value.to_static());
+        assert_eq!((0, 0), (arena.promoted(), arena.leaked_bytes()));
+        ensure_static(value);
     }
 
     #[test]
-    fn test_cow_box() {
-        let s = String::new();
-        let boxed = Box::new(s);
-        let value = Cow::Borrowed(&boxed);
-        let to_static = value.to_static();
-        ensure_static(to_static);
+    #[allow(clippy::needless_collect)]
+    fn test_static_arena_threads() {
+        static ARENA: StaticArena = StaticArena::new();
+        let handles: Vec<_> = (0..4)
+            .map(|i| std::thread::spawn(move || ARENA.promote(Cow::from(i.to_string()))))
+            .collect();
+        let mut values: Vec<&'static str> = handles
+            .into_iter()
+            .map(|h| h.join().unwrap().as_static().unwrap().as_ref())
+            .collect();
+        values.sort_unstable();
+        assert_eq!(["0", "1", "2", "3"], values[..]);
+        assert_eq!(4, ARENA.promoted());
     }
 
     #[test]
-    fn test_cow_struct() {
-        #[derive(Copy, Clone)]
-        struct Foo {}
-        impl ToBoundedStatic for Foo {
-            type Static = Self;
-
-            fn to_static(&self) -> Self::Static {
-                *self
-            }
-        }
-        let foo = Foo {};
-        let value = Cow::Borrowed(&foo);
-        let to_static = value.to_static();
-        ensure_static(to_static);
+    fn test_static_cost_locks() {
+        let s = String::from("test");
+        let value = (
+            std::sync::Mutex::new(Cow::from(&s)),
+            std::sync::RwLock::new(Cow::from(&s)),
+        );
+        assert_eq!(2, value.static_cost().borrowed());
+        let value = RefCell::new(Cow::from(&s));
+        let _guard = value.borrow_mut();
+        assert_eq!(CostReport::new(), value.static_cost());
     }
+}
 
-    #[test]
-    fn test_cow_struct_of_cow() {
-        #[derive(Clone)]
-        struct Foo<'a> {
-            foo: Cow<'a, str>,
-        }
-        impl ToBoundedStatic for Foo<'_> {
-            type Static = Foo<'static>;
+#[cfg(feature = "yoke")]
+#[cfg(test)]
+mod yoke_tests {
+    use super::*;
+    use core::marker::PhantomData;
 
-            fn to_static(&self) -> Self::Static {
-                Foo {
-                    foo: self.foo.to_static(),
-                }
-            }
-        }
-        let s = String::new();
-        let foo = Foo { foo: Cow::from(&s) };
-        let value = Cow::Borrowed(&foo);
-        // TODO need to `into_owned()` here
-        let to_static = value.into_owned().to_static();
-        ensure_static(to_static);
+    fn ensure_static<T: 'static>(t: T) {
+        drop(t);
     }
 
-    #[test]
-    fn test_cow_cow() {
-        let s = String::new();
-        let value1: Cow<'_, str> = Cow::Borrowed(&s);
-        let value2: Cow<'_, Cow<'_, str>> = Cow::Borrowed(&value1);
-        // TODO need to `into_owned()` here
-        let to_static = value2.into_owned().to_static();
-        ensure_static(to_static);
+    fn words(text: &str) -> [Cow<'_, str>; 2] {
+        let mut words = text.split(' ').map(Cow::Borrowed);
+        [
+            words.next().unwrap_or_default(),
+            words.next().unwrap_or_default(),
+        ]
     }
 
     #[test]
-    fn test_struct_cow_borrowed_str() {
-        struct Foo<'a> {
-            foo: Cow<'a, str>,
-        }
-        impl ToBoundedStatic for Foo<'_> {
-            type Static = Foo<'static>;
+    fn test_attached() {
+        let attached =
+            Attached::<String, [Cow<'static, str>; 2]>::attach(String::from("hello world"), words);
+        assert_eq!(["hello", "world"], *attached.get());
+        assert!(attached
+            .get()
+            .iter()
+            .all(|word| matches!(word, Cow::Borrowed(_))));
+        assert_eq!("hello world", attached.owner());
+        ensure_static(attached.into_static());
+    }
 
-            fn to_static(&self) -> Self::Static {
-                Foo {
-                    foo: self.foo.to_static(),
-                }
-            }
-        }
-        let s = String::new();
-        let foo = Foo { foo: Cow::from(&s) };
-        let to_static = foo.to_static();
-        ensure_static(to_static);
+    #[test]
+    fn test_attached_map() {
+        let attached: Attached<Vec<u8>, Cow<'static, [u8]>> =
+            Attached::attach(Vec::from(*b"bytes"), |bytes: &[u8]| Cow::Borrowed(bytes));
+        let mapped: Attached<Vec<u8>, Cow<'static, [u8]>> = attached.map(|bytes, _| match bytes {
+            Cow::Borrowed(bytes) => Cow::Borrowed(&bytes[1..]),
+            Cow::Owned(bytes) => Cow::Owned(bytes[1..].to_vec()),
+        });
+        assert_eq!(b"ytes", &**mapped.get());
+        assert_eq!(b"bytes", mapped.into_owner().as_slice());
     }
 
     #[test]
-    fn test_struct_cow_owned_string() {
-        struct Foo<'a> {
-            foo: Cow<'a, str>,
-        }
-        impl ToBoundedStatic for Foo<'_> {
-            type Static = Foo<'static>;
+    fn test_attached_project() {
+        let attached =
+            Attached::<Rc<str>, [Cow<'static, str>; 2]>::attach(Rc::from("hello world"), words);
+        let second: Attached<Rc<str>, &'static str> = attached.project(
+            |words: &[Cow<'_, str>; 2], _: PhantomData<&()>| match &words[1] {
+                Cow::Borrowed(word) => *word,
+                Cow::Owned(_) => "",
+            },
+        );
+        assert_eq!("world", *second.get());
+        assert_eq!(2, Rc::strong_count(second.owner()));
+        let cloned = second.to_static();
+        assert_eq!("world", *cloned.get());
+        assert_eq!(3, Rc::strong_count(cloned.owner()));
+    }
 
-            fn to_static(&self) -> Self::Static {
-                Foo {
-                    foo: self.foo.to_static(),
-                }
-            }
-        }
-        let s = String::new();
-        let foo = Foo { foo: Cow::from(s) };
-        let to_static = foo.to_static();
-        ensure_static(to_static);
+    #[test]
+    fn test_attached_detach() {
+        let attached: Attached<Arc<str>, Cow<'static, str>> =
+            Attached::attach(Arc::from("detached"), |text: &str| Cow::Borrowed(text));
+        let detached: Cow<'static, str> = attached.detach();
+        drop(attached);
+        assert!(matches!(detached, Cow::Owned(_)));
+        assert_eq!("detached", detached);
     }
 
     #[test]
-    fn test_struct_multi() {
-        #[derive(Clone)]
-        struct Foo<'a> {
-            bar: Cow<'a, str>,
-            baz: Vec<Cow<'a, str>>,
-        }
-        impl ToBoundedStatic for Foo<'_> {
-            type Static = Foo<'static>;
+    fn test_attached_try_attach() {
+        let attached: Result<Attached<String, &'static str>, &str> =
+            Attached::try_attach(String::from("value"), |text: &str| {
+                text.strip_prefix('v').ok_or("no prefix")
+            });
+        assert_eq!(
+            Ok("alue"),
+            attached.as_ref().map(|attached| *attached.get())
+        );
+        let attached: Result<Attached<String, &'static str>, &str> =
+            Attached::try_attach(String::from("value"), |text: &str| {
+                text.strip_prefix('x').ok_or("no prefix")
+            });
+        assert_eq!(
+            Err(&"no prefix"),
+            attached.as_ref().map(|attached| *attached.get())
+        );
+    }
 
-            fn to_static(&self) -> Self::Static {
-                Foo {
-                    bar: self.bar.to_static(),
-                    baz: self.baz.to_static(),
-                }
-            }
-        }
-        let s = String::new();
-        let foo = Foo {
-            bar: Cow::from(&s),
-            baz: alloc::vec![Cow::from(&s)],
-        };
-        let to_static = foo.to_static();
-        ensure_static(to_static);
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_attached_thread() {
+        let attached: Attached<Arc<str>, Cow<'static, str>> =
+            Attached::attach(Arc::from("shared"), |text: &str| Cow::Borrowed(text));
+        let format = std::format!("{attached:?}");
+        std::thread::spawn(move || assert_eq!("shared", attached.get()))
+            .join()
+            .unwrap();
+        assert_eq!(r#"Attached { owner: "shared", view: "shared" }"#, format);
     }
+}
+
+#[cfg(feature = "bumpalo")]
+#[cfg(test)]
+mod bumpalo_tests {
+    use super::*;
 
     #[test]
-    fn test_struct_mixed() {
-        struct Foo<'a> {
-            prim: u64,
-            borrowed_str: &'static str,
-            owned_str: String,
-            cow_str: Cow<'a, str>,
-        }
-        impl ToBoundedStatic for Foo<'_> {
-            type Static = Foo<'static>;
+    fn test_bump() {
+        let bump = bumpalo::Bump::new();
+        let s = String::from("test");
+        let value = (
+            Cow::from(&s),
+            Cow::from(&[1_u8, 2][..]),
+            String::from("owned"),
+        );
+        let output = value.to_bounded_in(&bump);
+        drop(value);
+        drop(s);
+        assert!(matches!(output.0, Cow::Borrowed("test")));
+        assert!(matches!(output.1, Cow::Borrowed(&[1, 2])));
+        assert_eq!("owned", output.2);
+        assert!(bump.allocated_bytes() > 0);
+    }
 
-            fn to_static(&self) -> Self::Static {
-                Foo {
-                    prim: self.prim.to_static(),
-                    borrowed_str: self.borrowed_str.to_static(),
-                    owned_str: self.owned_str.to_static(),
-                    cow_str: self.cow_str.to_static(),
-                }
-            }
-        }
-        let s = String::new();
-        let foo = Foo {
-            prim: 0,
-            borrowed_str: "",
-            owned_str: s.clone(),
-            cow_str: Cow::from(&s),
-        };
-        let to_static = foo.to_static();
-        ensure_static(to_static);
+    #[test]
+    fn test_bump_static_cow() {
+        let bump = bumpalo::Bump::new();
+        let s = String::from("test");
+        let value = alloc::vec![StaticCow::Static("static"), StaticCow::Owned(s)];
+        let output = value.to_bounded_in(&bump);
+        assert!(output[0].is_static());
+        assert!(output[1].is_borrowed());
     }
 }
 
-#[cfg(feature = "collections")]
+#[cfg(all(feature = "std", feature = "anyhow"))]
 #[cfg(test)]
-mod collections_tests {
+mod anyhow_tests {
     use super::*;
 
     fn ensure_static<T: 'static>(t: T) {
@@ -1448,52 +9389,60 @@ mod collections_tests {
     }
 
     #[test]
-    fn test_binary_heap() {
-        let s = String::new();
-        let value = BinaryHeap::from([Cow::from(&s)]);
+    fn test_anyhow_error() {
+        let value = anyhow::anyhow!("eof").context("invalid input");
         let to_static = value.to_static();
+        assert_eq!("invalid input", std::format!("{to_static}"));
+        assert_eq!("invalid input: eof", std::format!("{to_static:#}"));
+        assert_eq!(2, to_static.chain().count());
         ensure_static(to_static);
+        ensure_static(value.into_static());
     }
 
     #[test]
-    fn test_btree_map() {
-        let k = String::from("key");
-        let v = String::from("value");
-        let value = BTreeMap::from([(Cow::from(&k), Cow::from(&v))]);
-        let to_static = value.to_static();
-        ensure_static(to_static);
+    fn test_anyhow_error_to_bounded() {
+        let value = anyhow::anyhow!("eof").context("invalid input");
+        let output = value.to_bounded_in(&Heap);
+        assert_eq!("invalid input: eof", std::format!("{output:#}"));
     }
 
     #[test]
-    fn test_btree_set() {
-        let s = String::new();
-        let value = BTreeSet::from([Cow::from(&s)]);
-        let to_static = value.to_static();
-        ensure_static(to_static);
+    fn test_anyhow_error_with_budget() {
+        let value = anyhow::anyhow!("eof").context("invalid input");
+        let report = value.static_cost();
+        let mut budget = Budget::new(usize::MAX);
+        ensure_static(value.to_static_with_budget(&mut budget).unwrap());
+        assert_eq!(budget.used(), report.bytes());
+        assert!(report.bytes() > 2 * core::mem::size_of::<error::StaticError>());
+        assert!(value.to_static_with_budget(&mut Budget::new(1)).is_err());
     }
+}
 
-    #[test]
-    fn test_linked_list() {
-        let s = String::new();
-        let value = LinkedList::from([Cow::from(&s)]);
-        let to_static = value.to_static();
-        ensure_static(to_static);
+#[cfg(all(feature = "std", feature = "eyre"))]
+#[cfg(test)]
+mod eyre_tests {
+    use super::*;
+
+    fn ensure_static<T: 'static>(t: T) {
+        drop(t);
     }
 
     #[test]
-    fn test_vec_deque() {
-        let s = String::new();
-        let value = VecDeque::from([Cow::from(&s)]);
+    fn test_eyre_report() {
+        // the `auto-install` feature of eyre is not enabled and so the default handler must be installed
+        let _ = eyre::set_hook(Box::new(eyre::DefaultHandler::default_with));
+        let value = eyre::eyre!("eof").wrap_err("invalid input");
         let to_static = value.to_static();
+        assert_eq!("invalid input", std::format!("{to_static}"));
+        assert_eq!(2, to_static.chain().count());
         ensure_static(to_static);
+        ensure_static(value.into_static());
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "rustc-hash")]
 #[cfg(test)]
-mod std_tests {
-    use core::any::Any;
-
+mod rustc_hash_tests {
     use super::*;
 
     fn ensure_static<T: 'static>(t: T) {
@@ -1501,70 +9450,70 @@ mod std_tests {
     }
 
     #[test]
-    fn test_hashmap1() {
+    fn test_fx_build_hasher() {
+        ensure_static(rustc_hash::FxBuildHasher.to_static());
+        ensure_static(rustc_hash::FxBuildHasher.into_static());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_fx_hashmap() {
         let k = String::from("key");
         let v = String::from("value");
-        let value = std::collections::HashMap::from([(Cow::from(&k), Cow::from(&v))]);
+        let value = rustc_hash::FxHashMap::from_iter([(Cow::from(&k), Cow::from(&v))]);
         let to_static = value.to_static();
+        assert_eq!(value, to_static);
         ensure_static(to_static);
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn test_hashmap2() {
-        let k = "key";
-        let v = String::from("value");
-        let value = std::collections::HashMap::from([(k, Cow::from(&v))]);
+    fn test_fx_hashset() {
+        let s = String::from("data");
+        let value = rustc_hash::FxHashSet::from_iter([Cow::from(&s)]);
         let to_static = value.to_static();
+        assert_eq!(value, to_static);
         ensure_static(to_static);
     }
+}
+
+#[cfg(feature = "foldhash")]
+#[cfg(test)]
+mod foldhash_tests {
+    use super::*;
+
+    fn ensure_static<T: 'static>(t: T) {
+        drop(t);
+    }
 
     #[test]
-    fn test_hashmap3() {
-        let k = String::from("key");
-        let v = 0i16;
-        let value = std::collections::HashMap::from([(Cow::from(&k), v)]);
-        let to_static = value.to_static();
-        ensure_static(to_static);
+    fn test_foldhash_states() {
+        ensure_static(foldhash::fast::RandomState::default().to_static());
+        ensure_static(foldhash::fast::SeedableRandomState::default().to_static());
+        ensure_static(foldhash::fast::FixedState::default().to_static());
+        ensure_static(foldhash::quality::RandomState::default().to_static());
+        ensure_static(foldhash::quality::SeedableRandomState::default().to_static());
+        ensure_static(foldhash::quality::FixedState::default().to_static());
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn test_hashset() {
-        let value = String::from("data");
-        let value = std::collections::HashSet::from([(Cow::from(&value))]);
+    fn test_foldhash_hashmap() {
+        let k = String::from("key");
+        let v = String::from("value");
+        let value = foldhash::HashMap::from_iter([(Cow::from(&k), Cow::from(&v))]);
         let to_static = value.to_static();
+        assert_eq!(value, to_static);
         ensure_static(to_static);
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn test_custom_random_state() {
-        #[derive(Clone, Default)]
-        struct RandomState;
-
-        impl std::hash::BuildHasher for RandomState {
-            type Hasher = std::collections::hash_map::DefaultHasher;
-
-            fn build_hasher(&self) -> Self::Hasher {
-                std::collections::hash_map::DefaultHasher::default()
-            }
-        }
-
-        impl ToBoundedStatic for RandomState {
-            type Static = Self;
-
-            fn to_static(&self) -> Self::Static {
-                self.clone()
-            }
-        }
-
-        let k = "key";
-        let v = 0i16;
-        let value = std::collections::HashMap::<_, _, RandomState>::from_iter([(k, v)]);
-        let to_static = value.to_static();
-        assert_eq!(value.type_id(), to_static.type_id());
-        ensure_static(to_static);
-        let value = std::collections::HashSet::<_, RandomState>::from_iter([k]);
+    fn test_foldhash_hashset() {
+        let s = String::from("data");
+        let value = foldhash::HashSet::from_iter([Cow::from(&s)]);
         let to_static = value.to_static();
-        assert_eq!(value.type_id(), to_static.type_id());
+        assert_eq!(value, to_static);
         ensure_static(to_static);
     }
 }
@@ -1583,6 +9532,17 @@ mod smol_str_tests {
         ensure_static(smol_str::SmolStr::new("smol").to_static());
         ensure_static(smol_str::SmolStr::new("smol").into_static());
     }
+
+    #[test]
+    fn test_to_static_as_inline() {
+        let s = String::from("test");
+        let output = (Cow::from(&s), smol_str::SmolStr::new("smol")).to_static_as(repr::Inline);
+        drop(s);
+        assert!(!output.0.is_heap_allocated());
+        assert_eq!(("test", "smol"), (output.0.as_str(), output.1.as_str()));
+        let output: Box<str> = smol_str::SmolStr::new("smol").to_static_as(repr::Compact);
+        assert_eq!("smol", &*output);
+    }
 }
 
 #[cfg(feature = "smallvec")]
@@ -1618,6 +9578,28 @@ mod smallvec_tests {
         ensure_static(small_vec.to_static());
         ensure_static(small_vec.into_static());
     }
+
+    #[test]
+    fn test_smallvec_to_bounded() {
+        let x = String::from("foo");
+        let small_vec: smallvec::SmallVec<[Cow<'_, str>; 2]> = smallvec::smallvec![Cow::from(&x)];
+        let output = small_vec.to_bounded_in(&Heap);
+        drop(small_vec);
+        drop(x);
+        assert_eq!(["foo"], output.as_slice());
+    }
+
+    #[cfg(target_has_atomic = "ptr")]
+    #[test]
+    fn test_smallvec_to_send_static() {
+        fn ensure_send<T: Send + Sync + 'static>(t: T) {
+            drop(t);
+        }
+        let small_vec: smallvec::SmallVec<[Rc<str>; 2]> = smallvec::smallvec![Rc::from("foo")];
+        let output = small_vec.to_send_static();
+        assert_eq!("foo", &*output[0]);
+        ensure_send(output);
+    }
 }
 
 #[cfg(feature = "smartstring")]
@@ -1636,6 +9618,13 @@ mod smartstring_tests {
         ensure_static(string.to_static());
         ensure_static(string.into_static());
     }
+
+    #[test]
+    fn test_smartstring_to_static_as() {
+        let string = String::from("test");
+        let output: Box<str> = string.to_static_as(repr::Compact);
+        assert_eq!("test", &*output);
+    }
 }
 
 #[cfg(feature = "ahash")]
@@ -1670,6 +9659,206 @@ mod ahash_tests {
         let to_static = value.to_static();
         ensure_static(to_static);
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ahash_ahashmap_into_static_identity() {
+        let value = ahash::AHashMap::from([(String::from("key"), 0_u64)]);
+        let ptr: *const u64 = &value["key"];
+        let into_static = value.into_static();
+        assert!(core::ptr::eq(ptr, &into_static["key"]));
+        let value = ahash::AHashMap::from([(0_u64, 'a')]);
+        assert_eq!(value, value.to_static());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ahash_ahashset_into_static_identity() {
+        let value = ahash::AHashSet::from([String::from("data")]);
+        let ptr: *const String = value.iter().next().unwrap();
+        let into_static = value.into_static();
+        assert!(core::ptr::eq(ptr, into_static.iter().next().unwrap()));
+        let value = ahash::AHashSet::from([0_u64]);
+        assert_eq!(value, value.to_static());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ahash_to_bounded() {
+        let s = String::from("test");
+        let value = (
+            ahash::AHashMap::from([(Cow::from(&s), Cow::from(&s))]),
+            ahash::AHashSet::from([Cow::from(&s)]),
+        );
+        let output = value.to_bounded_in(&Heap);
+        drop(value);
+        drop(s);
+        assert_eq!(Some(&Cow::from("test")), output.0.get("test"));
+        assert!(output.1.contains("test"));
+    }
+
+    #[cfg(all(feature = "std", target_has_atomic = "ptr"))]
+    #[test]
+    fn test_ahash_to_send_static() {
+        let value = (
+            ahash::AHashMap::from([(String::from("key"), Rc::new(1_u8))]),
+            ahash::AHashSet::from([Rc::<str>::from("value")]),
+        );
+        let output = value.to_send_static();
+        std::thread::spawn(move || {
+            assert_eq!(Some(&Arc::new(1)), output.0.get("key"));
+            assert!(output.1.contains("value"));
+        })
+        .join()
+        .unwrap();
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+#[cfg(test)]
+mod hashbrown_tests {
+    use super::*;
+    use crate::std_tests::CountingState;
+
+    fn ensure_static<T: 'static>(t: T) {
+        drop(t);
+    }
+
+    fn cow_map(
+        keys: &[String],
+        hasher: CountingState,
+    ) -> hashbrown::HashMap<Cow<'_, str>, usize, CountingState> {
+        let mut value = hashbrown::HashMap::with_hasher(hasher);
+        value.extend(keys.iter().enumerate().map(|(i, k)| (Cow::from(k), i)));
+        value.insert(Cow::Owned(String::from("owned")), keys.len());
+        value
+    }
+
+    fn keys() -> Vec<String> {
+        (0..1000).map(|i| std::format!("key{i}")).collect()
+    }
+
+    #[test]
+    fn test_hashbrown_map_to_static_rebind() {
+        let keys = keys();
+        let hasher = CountingState::default();
+        let value = cow_map(&keys, hasher.clone());
+        let before = hasher.0.get();
+        let to_static = value.to_static();
+        assert_eq!(before, hasher.0.get());
+        assert_eq!(value, to_static);
+        drop(value);
+        drop(keys);
+        assert!(to_static.keys().all(|k| matches!(k, Cow::Owned(_))));
+        assert_eq!(Some(&999), to_static.get("key999"));
+        assert_eq!(Some(&1000), to_static.get("owned"));
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_hashbrown_map_into_static_rebind() {
+        let keys = keys();
+        let hasher = CountingState::default();
+        let value = cow_map(&keys, hasher.clone());
+        let expected = value.clone();
+        let before = hasher.0.get();
+        let into_static = value.into_static();
+        assert_eq!(before, hasher.0.get());
+        assert_eq!(expected, into_static);
+        drop(expected);
+        drop(keys);
+        assert!(into_static.keys().all(|k| matches!(k, Cow::Owned(_))));
+        assert_eq!(Some(&0), into_static.get("key0"));
+        ensure_static(into_static);
+    }
+
+    #[test]
+    fn test_hashbrown_set_rebind() {
+        let keys = keys();
+        let hasher = CountingState::default();
+        let mut value = hashbrown::HashSet::with_hasher(hasher.clone());
+        value.extend(keys.iter().map(Cow::from));
+        let before = hasher.0.get();
+        let to_static = value.to_static();
+        let into_static = value.clone().into_static();
+        assert_eq!(before, hasher.0.get());
+        assert_eq!(value, to_static);
+        assert_eq!(value, into_static);
+        drop(value);
+        drop(keys);
+        assert!(to_static.contains("key1") && into_static.contains("key1"));
+        ensure_static((to_static, into_static));
+    }
+
+    #[test]
+    fn test_hashbrown_map_rehash() {
+        let k = String::from("key");
+        let v = String::from("value");
+        let hasher = CountingState::default();
+        let mut value = hashbrown::HashMap::with_hasher(hasher.clone());
+        value.insert(Cow::from(&k), Cow::from(&v));
+        let before = hasher.0.get();
+        let to_static = value.to_static();
+        assert_eq!(before + 1, hasher.0.get());
+        assert_eq!(value, to_static);
+        ensure_static(to_static);
+    }
+
+    #[test]
+    fn test_hashbrown_map_into_static_identity() {
+        let value = hashbrown::HashMap::<_, _, std::collections::hash_map::RandomState>::from_iter(
+            [(String::from("key"), 0_u64)],
+        );
+        let ptr: *const u64 = &value["key"];
+        let into_static = value.into_static();
+        assert!(core::ptr::eq(ptr, &into_static["key"]));
+    }
+
+    #[test]
+    fn test_hashbrown_with_limit() {
+        let k = String::from("key");
+        let value = hashbrown::HashMap::<_, _, std::collections::hash_map::RandomState>::from_iter(
+            [(Cow::from(&k), 0_u64)],
+        );
+        let limit = core::mem::size_of::<(Cow<'_, str>, u64)>() + 3;
+        assert!(value.to_static_with_limit(limit - 1).is_err());
+        assert_eq!(Ok(value.clone()), value.to_static_with_limit(limit));
+        assert_eq!(Ok(value.clone()), value.into_static_with_limit(limit));
+    }
+
+    #[test]
+    fn test_hashbrown_to_bounded() {
+        let s = String::from("test");
+        let value = (
+            hashbrown::HashMap::<_, _, std::collections::hash_map::RandomState>::from_iter([(
+                Cow::from(&s),
+                Cow::from(&s),
+            )]),
+            hashbrown::HashSet::<_, std::collections::hash_map::RandomState>::from_iter([
+                Cow::from(&s),
+            ]),
+        );
+        let output = value.to_bounded_in(&Heap);
+        drop(value);
+        drop(s);
+        assert_eq!(Some(&Cow::from("test")), output.0.get("test"));
+        assert!(output.1.contains("test"));
+    }
+
+    #[cfg(feature = "instrument")]
+    #[test]
+    fn test_hashbrown_instrument_rebind() {
+        let k = String::from("key");
+        let value = hashbrown::HashMap::<_, _, std::collections::hash_map::RandomState>::from_iter(
+            [(Cow::from(&k), 0_u64)],
+        );
+        let (_, report) = instrument::collect(|| value.to_static());
+        assert_eq!(2, report.total().allocations());
+        assert_eq!(
+            core::mem::size_of::<(Cow<'_, str>, u64)>() as u64 + 3,
+            report.total().bytes()
+        );
+    }
 }
 
 #[cfg(feature = "chrono")]
@@ -1689,6 +9878,12 @@ mod chrono_tests {
         ensure_static(to_static);
     }
 
+    #[test]
+    fn test_chrono_datetime_to_bounded() {
+        let value = chrono::Utc::now();
+        assert_eq!(value, value.to_bounded_in(&Heap));
+    }
+
     #[test]
     fn test_chrono_datetime_with_custom_tz() {
         use chrono::{
@@ -1843,3 +10038,325 @@ mod chrono_clock_tests {
         ensure_static(to_static);
     }
 }
+
+#[cfg(feature = "instrument")]
+#[cfg(test)]
+mod instrument_tests {
+    use super::*;
+
+    #[test]
+    fn test_instrument_string() {
+        let value = String::from("test");
+        let (to_static, report) = instrument::collect(|| value.to_static());
+        assert_eq!(value, to_static);
+        assert_eq!(1, report.total().allocations());
+        assert_eq!(4, report.total().bytes());
+        assert_eq!(0, report.total().reused());
+    }
+
+    #[test]
+    fn test_instrument_cow() {
+        let s = String::from("test");
+        let value = alloc::vec![Cow::from(&s), Cow::Owned(String::from("owned"))];
+        let (_, report) = instrument::collect(|| value.clone().into_static());
+        assert_eq!(2, report.total().allocations());
+        assert_eq!(
+            2 * core::mem::size_of::<Cow<'_, str>>() as u64 + 4,
+            report.total().bytes()
+        );
+        assert_eq!(1, report.total().reused());
+        let (_, report) = instrument::collect(|| value.to_static());
+        assert_eq!(3, report.total().allocations());
+        assert_eq!(0, report.total().reused());
+    }
+
+    #[test]
+    fn test_instrument_static_cow() {
+        let s = String::from("test");
+        let value = alloc::vec![StaticCow::Static("static"), StaticCow::from(s.as_str())];
+        let (_, report) = instrument::collect(|| value.into_static());
+        assert_eq!(2, report.total().allocations());
+        assert_eq!(
+            2 * core::mem::size_of::<StaticCow<'_, str>>() as u64 + 4,
+            report.total().bytes()
+        );
+    }
+
+    #[test]
+    fn test_instrument_no_alloc() {
+        let value = (1, "test", Some([1, 2, 3]), String::new());
+        let (_, report) = instrument::collect(|| value.to_static());
+        assert_eq!(instrument::Report::default(), report);
+    }
+
+    #[test]
+    fn test_instrument_identity() {
+        let value = alloc::vec![String::from("test")];
+        let (_, report) = instrument::collect(|| value.into_static());
+        assert_eq!(instrument::Report::default(), report);
+        let value = alloc::vec![1_u64, 2, 3];
+        let (_, report) = instrument::collect(|| value.to_static());
+        assert_eq!(1, report.total().allocations());
+        assert_eq!(24, report.total().bytes());
+    }
+
+    /// A `Copy` value which records a conversion with the `instrument` collector each time it is converted.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    struct Counted(u64);
+
+    impl ToBoundedStatic for Counted {
+        type Static = Self;
+
+        fn to_static(&self) -> Self::Static {
+            let _guard = crate::__private::enter("Counted");
+            *self
+        }
+
+        crate::__to_static_fast_path!(copy);
+    }
+
+    impl IntoBoundedStatic for Counted {
+        type Static = Self;
+
+        fn into_static(self) -> Self::Static {
+            let _guard = crate::__private::enter("Counted");
+            self
+        }
+
+        crate::__into_static_fast_path!();
+    }
+
+    #[test]
+    fn test_instrument_counted() {
+        let value = [Counted(1), Counted(2), Counted(3)];
+        let (_, report) = instrument::collect(|| value.to_static());
+        assert_eq!(3, report.total().conversions());
+        let (_, report) = instrument::collect(|| value.into_static());
+        assert_eq!(3, report.total().conversions());
+    }
+
+    #[test]
+    fn test_instrument_vec_no_per_element_work() {
+        let value = alloc::vec![Counted(1), Counted(2), Counted(3)];
+        let (to_static, report) = instrument::collect(|| value.to_static());
+        assert_eq!(value, to_static);
+        assert_eq!(0, report.total().conversions());
+        assert_eq!(1, report.total().allocations());
+        let (into_static, report) = instrument::collect(|| value.clone().into_static());
+        assert_eq!(value, into_static);
+        assert_eq!(instrument::Report::default(), report);
+        let value = alloc::vec![String::from("a"), String::from("b")];
+        let (_, report) = instrument::collect(|| value.into_static());
+        assert_eq!(instrument::Report::default(), report);
+    }
+
+    #[test]
+    #[cfg(feature = "collections")]
+    fn test_instrument_vec_deque_no_per_element_work() {
+        let value = VecDeque::from([Counted(1), Counted(2), Counted(3)]);
+        let (into_static, report) = instrument::collect(|| value.clone().into_static());
+        assert_eq!(value, into_static);
+        assert_eq!(instrument::Report::default(), report);
+        let value = VecDeque::from([1_u64, 2, 3]);
+        let (_, report) = instrument::collect(|| value.into_static());
+        assert_eq!(instrument::Report::default(), report);
+    }
+
+    #[test]
+    #[cfg(feature = "collections")]
+    fn test_instrument_binary_heap_no_per_element_work() {
+        let value = BinaryHeap::from([Counted(1), Counted(2), Counted(3)]);
+        let (into_static, report) = instrument::collect(|| value.clone().into_static());
+        assert_eq!(value.into_sorted_vec(), into_static.into_sorted_vec());
+        assert_eq!(instrument::Report::default(), report);
+        let value = BinaryHeap::from([1_u64, 2, 3]);
+        let (_, report) = instrument::collect(|| value.into_static());
+        assert_eq!(instrument::Report::default(), report);
+    }
+
+    #[test]
+    fn test_instrument_hash_map_clone() {
+        let value = std::collections::HashMap::from([(String::from("key"), String::from("value"))]);
+        let (_, report) = instrument::collect(|| value.to_static());
+        assert_eq!(3, report.total().allocations());
+        assert_eq!(
+            core::mem::size_of::<(String, String)>() as u64 + 8,
+            report.total().bytes()
+        );
+    }
+
+    #[test]
+    fn test_instrument_with_budget() {
+        let value = alloc::vec![String::from("test")];
+        let (_, report) = instrument::collect(|| value.to_static_with_limit(usize::MAX));
+        assert_eq!(2, report.total().allocations());
+    }
+
+    #[test]
+    fn test_instrument_thread_local() {
+        let _ = instrument::take();
+        let value = String::from("test");
+        let (_, inner) = instrument::collect(|| value.to_static());
+        let _ = value.to_static();
+        let outer = instrument::take();
+        assert_eq!(1, inner.total().allocations());
+        assert_eq!(2, outer.total().allocations());
+        assert_eq!(instrument::Report::default(), instrument::snapshot());
+        std::thread::spawn(move || {
+            let _ = value.to_static();
+        })
+        .join()
+        .unwrap();
+        assert_eq!(instrument::Report::default(), instrument::snapshot());
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+#[cfg(feature = "alloc")]
+mod serde_tests {
+    use super::*;
+    use serde_test::{assert_de_tokens, assert_ser_tokens, Token};
+
+    #[test]
+    fn test_static_cow_serialize() {
+        let s = String::from("borrowed");
+        assert_ser_tokens(
+            &StaticCow::<'_, str>::Static("static"),
+            &[Token::Str("static")],
+        );
+        assert_ser_tokens(&StaticCow::from(s.as_str()), &[Token::Str("borrowed")]);
+        assert_ser_tokens(
+            &StaticCow::<'_, str>::from(String::from("owned")),
+            &[Token::Str("owned")],
+        );
+    }
+
+    #[test]
+    fn test_static_cow_deserialize() {
+        assert_de_tokens(
+            &StaticCow::<'_, str>::from(String::from("owned")),
+            &[Token::Str("owned")],
+        );
+        assert_de_tokens(
+            &StaticCow::<'_, [u8]>::Owned(alloc::vec![1, 2]),
+            &[
+                Token::Seq { len: Some(2) },
+                Token::U8(1),
+                Token::U8(2),
+                Token::SeqEnd,
+            ],
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "intern")]
+mod intern_tests {
+    use super::*;
+    use crate::intern::{DefaultInterner, Interner};
+
+    fn ensure_static<T: 'static>(t: T) {
+        drop(t);
+    }
+
+    #[test]
+    fn test_intern_str() {
+        let s = String::from("intern_str");
+        let first = s.as_str().to_static_interned();
+        let second = Cow::from(&s).to_static_interned();
+        assert_eq!("intern_str", first);
+        assert!(matches!(second, Cow::Borrowed(_)));
+        assert!(core::ptr::eq(first, second.as_ref()));
+        ensure_static(second);
+    }
+
+    #[test]
+    fn test_intern_bytes() {
+        let b = alloc::vec![1, 2, 3];
+        let first = b.as_slice().to_static_interned();
+        let second = Cow::from(b.as_slice()).to_static_interned();
+        assert!(core::ptr::eq(first, second.as_ref()));
+        ensure_static(second);
+    }
+
+    #[test]
+    fn test_intern_static_cow() {
+        let s = String::from("intern_static_cow");
+        let value = StaticCow::from(s.as_str()).to_static_interned();
+        assert!(value.is_static());
+        let value: StaticCow<'_, str> = StaticCow::Static("static");
+        assert!(core::ptr::eq("static", &*value.to_static_interned()));
+    }
+
+    #[test]
+    fn test_intern_containers() {
+        let s = String::from("intern_containers");
+        let value = alloc::vec![Some(Cow::from(&s)), None];
+        let interned = value.to_static_interned();
+        assert_eq!(value, interned);
+        ensure_static(interned);
+    }
+
+    #[test]
+    fn test_default_interner_stats() {
+        let interner: &'static DefaultInterner = Box::leak(Box::default());
+        let first = interner.intern_str("test");
+        let second = interner.intern_str(&String::from("test"));
+        assert!(core::ptr::eq(first, second));
+        let _ = interner.intern_bytes(&[1, 2]);
+        let stats = interner.stats();
+        assert_eq!(2, stats.values());
+        assert_eq!(6, stats.bytes());
+        assert_eq!(3, stats.lookups());
+        assert_eq!(1, stats.hits());
+        assert_eq!(2, stats.misses());
+    }
+
+    #[test]
+    #[allow(clippy::needless_collect)]
+    fn test_default_interner_threads() {
+        let interner: &'static DefaultInterner = Box::leak(Box::default());
+        let handles: Vec<_> = (0..4)
+            .map(|_| std::thread::spawn(move || interner.intern_str("test")))
+            .collect();
+        let values: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert!(values.iter().all(|value| core::ptr::eq(*value, values[0])));
+        assert_eq!(1, interner.stats().values());
+        assert_eq!(4, interner.stats().lookups());
+    }
+
+    #[test]
+    fn test_set_interner() {
+        let _ = intern::interner();
+        let interner: &'static DefaultInterner = Box::leak(Box::default());
+        assert!(intern::set_interner(interner).is_err());
+    }
+
+    #[cfg(feature = "lasso")]
+    #[test]
+    fn test_lasso_interner() {
+        let interner: &'static intern::LassoInterner = Box::leak(Box::default());
+        let first = interner.intern_str("test");
+        let second = interner.intern_str(&String::from("test"));
+        assert!(core::ptr::eq(first, second));
+        assert_eq!(1, interner.rodeo().len());
+        let stats = interner.stats();
+        assert_eq!(1, stats.values());
+        assert_eq!(2, stats.lookups());
+        assert_eq!(1, stats.hits());
+    }
+
+    #[cfg(feature = "ustr")]
+    #[test]
+    fn test_ustr_interner() {
+        let interner: &'static intern::UstrInterner = Box::leak(Box::default());
+        let first = interner.intern_str("bounded_static_ustr_test");
+        let second = interner.intern_str(&String::from("bounded_static_ustr_test"));
+        assert!(core::ptr::eq(first, second));
+        let stats = interner.stats();
+        assert!(stats.values() >= 1);
+        assert_eq!(2, stats.lookups());
+        assert_eq!(1, stats.hits());
+    }
+}
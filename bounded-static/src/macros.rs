@@ -0,0 +1,604 @@
+/// Implement no-op [`ToBoundedStatic`](crate::ToBoundedStatic) and [`IntoBoundedStatic`](crate::IntoBoundedStatic)
+/// for one or more `Copy` types.
+///
+/// The generated impls are identical to those provided by this crate for primitive types, `to_static()` copies the
+/// value and `into_static()` returns it unchanged.  The type must be bounded by `'static`.
+///
+/// No-op [`ToBounded`](crate::ToBounded) and [`ToStaticAs`](crate::ToStaticAs) impls are also generated for every
+/// lifetime, allocator and representation.  With a leading `send` clause a no-op [`ToSendStatic`](crate::ToSendStatic)
+/// impl is generated too, which requires the type to be `Send + Sync`.
+///
+/// Generic parameters may be supplied in brackets after the `impl` keyword, optionally followed by a `where` clause.
+///
+/// # Examples
+///
+/// ```rust
+/// # use bounded_static::{IntoBoundedStatic, ToBoundedStatic, ToSendStatic};
+/// #[derive(Copy, Clone)]
+/// struct Id(u64);
+///
+/// #[derive(Copy, Clone)]
+/// struct Pair<T>(T, T);
+///
+/// #[derive(Copy, Clone)]
+/// struct Wrapper<T>(T);
+///
+/// bounded_static::impl_copy!(send Id);
+/// bounded_static::impl_copy!(impl[T: Copy + 'static] Pair<T>);
+/// bounded_static::impl_copy!(impl[T] Wrapper<T> where T: Copy + 'static);
+///
+/// fn ensure_static<T: 'static>(_: T) {}
+///
+/// ensure_static(Id(0).to_send_static());
+/// ensure_static(Pair(1, 2).into_static());
+/// ensure_static(Wrapper('a').to_static());
+/// ```
+#[macro_export]
+macro_rules! impl_copy {
+    (@send $send:tt impl [$($gen:tt)*] $ty:ty $(where $($wc:tt)+)?) => {
+        /// No-op `ToBoundedStatic` impl for this `Copy` type.
+        impl<$($gen)*> $crate::ToBoundedStatic for $ty $(where $($wc)+)? {
+            type Static = Self;
+
+            fn to_static(&self) -> Self::Static {
+                *self
+            }
+
+            $crate::__to_static_fast_path!(copy);
+        }
+        /// No-op `IntoBoundedStatic` impl for this `Copy` type.
+        impl<$($gen)*> $crate::IntoBoundedStatic for $ty $(where $($wc)+)? {
+            type Static = Self;
+
+            fn into_static(self) -> Self::Static {
+                self
+            }
+
+            $crate::__into_static_fast_path!();
+        }
+        $crate::__to_bounded!(copy impl[$($gen)*] $ty $(where $($wc)+)?);
+        $crate::__to_static_as!(copy impl[$($gen)*] $ty $(where $($wc)+)?);
+        $crate::__to_send_static!($send copy impl[$($gen)*] $ty $(where $($wc)+)?);
+    };
+    (@send $send:tt $($ty:ty),+ $(,)?) => {
+        $(
+            $crate::impl_copy!(@send $send impl[] $ty);
+        )+
+    };
+    (send $($rest:tt)+) => {
+        $crate::impl_copy!(@send [send] $($rest)+);
+    };
+    (impl [$($gen:tt)*] $ty:ty $(where $($wc:tt)+)?) => {
+        $crate::impl_copy!(@send [] impl[$($gen)*] $ty $(where $($wc)+)?);
+    };
+    ($($ty:ty),+ $(,)?) => {
+        $crate::impl_copy!(@send [] $($ty),+);
+    };
+}
+
+/// Implement [`ToBoundedStatic`](crate::ToBoundedStatic) and no-op [`IntoBoundedStatic`](crate::IntoBoundedStatic)
+/// for one or more `'static + Clone` types.
+///
+/// The generated impls are identical to those provided by this crate for types such as `String`, `to_static()` clones
+/// the value and `into_static()` returns it unchanged.  The type must be bounded by `'static`.
+///
+/// [`ToBounded`](crate::ToBounded) and [`ToStaticAs`](crate::ToStaticAs) impls which clone the value are also
+/// generated for every lifetime, allocator and representation.  With a `send` clause a
+/// [`ToSendStatic`](crate::ToSendStatic) impl which clones the value is generated too, which requires the type to be
+/// `Send + Sync`.
+///
+/// Generic parameters may be supplied in brackets after the `impl` keyword, optionally followed by a `where` clause.
+/// The `heap` and `send` clauses are given before them, in either order.
+///
+/// A type which owns heap data must give the number of heap bytes which a clone allocates with a
+/// `heap(|value| len)` clause.  `to_static_with_budget()` then charges `len` bytes to the [`Budget`](crate::Budget),
+/// `add_static_cost()` reports an owned leaf of `len` bytes and the allocation is recorded by the `instrument`
+/// feature.  Without it the type is taken to own no heap data, and nothing is charged or reported.
+///
+/// # Examples
+///
+/// ```rust
+/// # use bounded_static::{IntoBoundedStatic, StaticCost, ToBoundedStatic, ToSendStatic};
+/// #[derive(Clone)]
+/// struct Level(u8);
+///
+/// #[derive(Clone)]
+/// struct Name(String);
+///
+/// #[derive(Clone)]
+/// struct Names<T>(Vec<T>);
+///
+/// bounded_static::impl_clone!(Level);
+/// bounded_static::impl_clone!(heap(|name| name.0.len()) send Name);
+/// bounded_static::impl_clone!(
+///     heap(|names| names.0.len() * std::mem::size_of::<T>()) impl[T] Names<T> where T: Copy + 'static
+/// );
+///
+/// fn ensure_static<T: 'static>(_: T) {}
+///
+/// ensure_static(Level(0).to_static());
+/// ensure_static(Names(vec![0_u8]).into_static());
+/// let name = Name(String::from("name"));
+/// assert_eq!(4, name.static_cost().bytes());
+/// assert!(name.to_static_with_limit(3).is_err());
+/// ensure_static(name.to_send_static());
+/// ```
+#[macro_export]
+macro_rules! impl_clone {
+    (@opts [] $send:tt heap(|$value:ident| $len:expr) $($rest:tt)+) => {
+        $crate::impl_clone!(@opts [|$value| $len] $send $($rest)+);
+    };
+    (@opts $heap:tt [] send $($rest:tt)+) => {
+        $crate::impl_clone!(@opts $heap [send] $($rest)+);
+    };
+    (@opts $heap:tt $send:tt impl [$($gen:tt)*] $ty:ty $(where $($wc:tt)+)?) => {
+        /// `ToBoundedStatic` impl for this `'static + Clone` type.
+        impl<$($gen)*> $crate::ToBoundedStatic for $ty $(where $($wc)+)? {
+            type Static = Self;
+
+            $crate::__to_static_heap!($heap ::core::clone::Clone::clone);
+
+            fn to_static_into(&self, dst: &mut Self::Static) {
+                ::core::clone::Clone::clone_from(dst, self);
+            }
+
+            $crate::__to_static_fast_path!(clone);
+        }
+        /// No-op `IntoBoundedStatic` impl for this `'static + Clone` type.
+        impl<$($gen)*> $crate::IntoBoundedStatic for $ty $(where $($wc)+)? {
+            type Static = Self;
+
+            fn into_static(self) -> Self::Static {
+                self
+            }
+
+            $crate::__into_static_fast_path!();
+        }
+        $crate::__to_bounded!(clone impl[$($gen)*] $ty $(where $($wc)+)?);
+        $crate::__to_static_as!(clone impl[$($gen)*] $ty $(where $($wc)+)?);
+        $crate::__to_send_static!($send clone impl[$($gen)*] $ty $(where $($wc)+)?);
+    };
+    (@opts $heap:tt $send:tt $($ty:ty),+ $(,)?) => {
+        $(
+            $crate::impl_clone!(@opts $heap $send impl[] $ty);
+        )+
+    };
+    (heap $($rest:tt)+) => {
+        $crate::impl_clone!(@opts [] [] heap $($rest)+);
+    };
+    (send $($rest:tt)+) => {
+        $crate::impl_clone!(@opts [] [] send $($rest)+);
+    };
+    (impl [$($gen:tt)*] $ty:ty $(where $($wc:tt)+)?) => {
+        $crate::impl_clone!(@opts [] [] impl[$($gen)*] $ty $(where $($wc)+)?);
+    };
+    ($($ty:ty),+ $(,)?) => {
+        $crate::impl_clone!(@opts [] [] $($ty),+);
+    };
+}
+
+/// Implement [`ToBoundedStatic`](crate::ToBoundedStatic) and [`IntoBoundedStatic`](crate::IntoBoundedStatic) for
+/// `to_owned()` / `into_owned()` types.
+///
+/// The type must provide inherent `to_owned(&self)` and `into_owned(self)` methods.  This is a common pattern for
+/// types which borrow data, such as `Foo<'a>`, where `to_owned()` and `into_owned()` produce a `Foo<'static>`.  The
+/// target type is given after `=>` and `to_static()` and `into_static()` delegate to `to_owned()` and `into_owned()`
+/// respectively.
+///
+/// [`ToBounded`](crate::ToBounded) and [`ToStaticAs`](crate::ToStaticAs) impls which delegate to `to_owned()` are also
+/// generated for every lifetime, allocator and representation, and with a `send` clause a
+/// [`ToSendStatic`](crate::ToSendStatic) impl which requires the target type to be `Send + Sync`.  The target type is `'static` and so it outlives any
+/// lifetime, and `to_owned()` chooses its own representation of the borrowed data.  Unlike [`impl_copy`] and
+/// [`impl_clone`] the conversion is not an identity, and so collections of such a type are converted element by
+/// element.
+///
+/// Generic parameters may be supplied in brackets after the `impl` keyword, optionally followed by a `where` clause.
+/// The `heap` and `send` clauses are given before them, in either order.
+///
+/// A type which holds heap data must give the number of heap bytes which `to_owned()` allocates with a
+/// `heap(|value| len)` clause, as for [`impl_clone`].  `to_static_with_budget()` and `into_static_with_budget()` then
+/// charge `len` bytes to the [`Budget`](crate::Budget), an upper bound for `into_owned()` which only copies borrowed
+/// data, and `add_static_cost()` reports an owned leaf of `len` bytes.  Without it nothing is charged or reported.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use bounded_static::{IntoBoundedStatic, ToBoundedStatic, ToSendStatic};
+/// struct Foo<'a>(Cow<'a, str>);
+///
+/// impl Foo<'_> {
+///     fn to_owned(&self) -> Foo<'static> {
+///         Foo(Cow::Owned(self.0.to_string()))
+///     }
+///
+///     fn into_owned(self) -> Foo<'static> {
+///         Foo(Cow::Owned(self.0.into_owned()))
+///     }
+/// }
+///
+/// bounded_static::impl_into_owned!(heap(|foo| foo.0.len()) send Foo<'_> => Foo<'static>);
+///
+/// fn ensure_static<T: 'static>(_: T) {}
+///
+/// let value = String::from("value");
+/// ensure_static(Foo(Cow::from(&value)).to_static());
+/// ensure_static(Foo(Cow::from(&value)).into_static());
+/// assert!(Foo(Cow::from(&value)).to_static_with_limit(4).is_err());
+/// ensure_static(Foo(Cow::from(&value)).to_send_static());
+/// ```
+///
+/// With generic parameters:
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use bounded_static::ToBoundedStatic;
+/// struct Bar<'a, T>(Cow<'a, str>, T);
+///
+/// impl<T: Clone> Bar<'_, T> {
+///     fn to_owned(&self) -> Bar<'static, T> {
+///         Bar(Cow::Owned(self.0.to_string()), self.1.clone())
+///     }
+///
+///     fn into_owned(self) -> Bar<'static, T> {
+///         Bar(Cow::Owned(self.0.into_owned()), self.1)
+///     }
+/// }
+///
+/// bounded_static::impl_into_owned!(impl['a, T] Bar<'a, T> => Bar<'static, T> where T: Clone + 'static);
+///
+/// fn ensure_static<T: 'static>(_: T) {}
+///
+/// let value = String::from("value");
+/// ensure_static(Bar(Cow::from(&value), 0_u8).to_static());
+/// ```
+#[macro_export]
+macro_rules! impl_into_owned {
+    (@opts [] $send:tt heap(|$value:ident| $len:expr) $($rest:tt)+) => {
+        $crate::impl_into_owned!(@opts [|$value| $len] $send $($rest)+);
+    };
+    (@opts $heap:tt [] send $($rest:tt)+) => {
+        $crate::impl_into_owned!(@opts $heap [send] $($rest)+);
+    };
+    (@opts $heap:tt $send:tt impl [$($gen:tt)*] $ty:ty => $static:ty $(where $($wc:tt)+)?) => {
+        /// `ToBoundedStatic` impl which delegates to `to_owned()`.
+        impl<$($gen)*> $crate::ToBoundedStatic for $ty $(where $($wc)+)? {
+            type Static = $static;
+
+            $crate::__to_static_heap!($heap Self::to_owned);
+        }
+        /// `IntoBoundedStatic` impl which delegates to `into_owned()`.
+        impl<$($gen)*> $crate::IntoBoundedStatic for $ty $(where $($wc)+)? {
+            type Static = $static;
+
+            fn into_static(self) -> Self::Static {
+                Self::into_owned(self)
+            }
+
+            $crate::__into_static_heap!($heap);
+        }
+        $crate::__to_bounded!(owned impl[$($gen)*] $ty => $static $(where $($wc)+)?);
+        $crate::__to_static_as!(owned impl[$($gen)*] $ty => $static $(where $($wc)+)?);
+        $crate::__to_send_static!($send owned impl[$($gen)*] $ty => $static $(where $($wc)+)?);
+    };
+    (@opts $heap:tt $send:tt $($ty:ty => $static:ty),+ $(,)?) => {
+        $(
+            $crate::impl_into_owned!(@opts $heap $send impl[] $ty => $static);
+        )+
+    };
+    (heap $($rest:tt)+) => {
+        $crate::impl_into_owned!(@opts [] [] heap $($rest)+);
+    };
+    (send $($rest:tt)+) => {
+        $crate::impl_into_owned!(@opts [] [] send $($rest)+);
+    };
+    (impl [$($gen:tt)*] $ty:ty => $static:ty $(where $($wc:tt)+)?) => {
+        $crate::impl_into_owned!(@opts [] [] impl[$($gen)*] $ty => $static $(where $($wc)+)?);
+    };
+    ($($ty:ty => $static:ty),+ $(,)?) => {
+        $crate::impl_into_owned!(@opts [] [] $($ty => $static),+);
+    };
+}
+
+/// Implement [`ToBoundedStatic`](crate::ToBoundedStatic) and no-op [`IntoBoundedStatic`](crate::IntoBoundedStatic)
+/// for one or more `'static + Clone` [`BuildHasher`](core::hash::BuildHasher) types.
+///
+/// The `HashMap` and `HashSet` impls require that the hasher `S` implements the trait being used.  This macro marks a
+/// custom hasher as identity-convertible such that `to_static()` returns a clone of it, and is equivalent to
+/// [`impl_clone`], including its `send` clause.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use std::collections::hash_map::DefaultHasher;
+/// # use std::hash::BuildHasher;
+/// # use bounded_static::{IntoBoundedStatic, ToBoundedStatic};
+/// #[derive(Clone, Default)]
+/// struct MyState;
+///
+/// impl BuildHasher for MyState {
+///     type Hasher = DefaultHasher;
+///
+///     fn build_hasher(&self) -> Self::Hasher {
+///         DefaultHasher::new()
+///     }
+/// }
+///
+/// bounded_static::impl_build_hasher!(MyState);
+///
+/// let map = HashMap::<_, _, MyState>::from_iter([("key", 0)]);
+/// let to_static: HashMap<_, _, MyState> = map.to_static();
+/// let into_static: HashMap<_, _, MyState> = map.into_static();
+/// ```
+#[macro_export]
+macro_rules! impl_build_hasher {
+    (send $($id:ty),+ $(,)?) => {
+        $crate::impl_clone!(send $($id),+);
+    };
+    ($($id:ty),+ $(,)?) => {
+        $crate::impl_clone!($($id),+);
+    };
+}
+
+/// Generate the `to_static` method of [`ToBoundedStatic`](crate::ToBoundedStatic) which converts with `$convert`
+/// and, given the `heap` clause of the public macros, the budget and cost methods which charge and report `$len`
+/// bytes, this is not part of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __to_static_heap {
+    ([] $convert:path) => {
+        fn to_static(&self) -> Self::Static {
+            $convert(self)
+        }
+    };
+    ([|$value:ident| $len:expr] $convert:path) => {
+        fn to_static(&self) -> Self::Static {
+            let $value = self;
+            $crate::__private::record_alloc($len);
+            $convert(self)
+        }
+
+        fn to_static_with_budget(
+            &self,
+            budget: &mut $crate::Budget,
+        ) -> ::core::result::Result<Self::Static, $crate::TryStaticError> {
+            let $value = self;
+            budget.charge($len)?;
+            ::core::result::Result::Ok($crate::ToBoundedStatic::to_static(self))
+        }
+
+        fn add_static_cost(&self, report: &mut $crate::CostReport) {
+            let $value = self;
+            report.add_owned($len);
+        }
+    };
+}
+
+/// Generate, given the `heap` clause of the public macros, the budget method of
+/// [`IntoBoundedStatic`](crate::IntoBoundedStatic) which charges `$len` bytes, this is not part of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __into_static_heap {
+    ([]) => {};
+    ([|$value:ident| $len:expr]) => {
+        fn into_static_with_budget(
+            self,
+            budget: &mut $crate::Budget,
+        ) -> ::core::result::Result<Self::Static, $crate::TryStaticError> {
+            let $value = &self;
+            budget.charge($len)?;
+            ::core::result::Result::Ok($crate::IntoBoundedStatic::into_static(self))
+        }
+    };
+}
+
+/// Override the hidden `to_static_fast_path` method of [`ToBoundedStatic`](crate::ToBoundedStatic) for a type whose
+/// `to_static()` is a `copy` or a `clone`, or only replaces its lifetimes with `rebind`, this is not part of the public
+/// API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __to_static_fast_path {
+    (copy) => {
+        fn to_static_fast_path<F>(f: F) -> F::Output
+        where
+            F: $crate::__private::ToStaticFastPath<Self>,
+        {
+            f.convert_copy()
+        }
+    };
+    (clone) => {
+        fn to_static_fast_path<F>(f: F) -> F::Output
+        where
+            F: $crate::__private::ToStaticFastPath<Self>,
+        {
+            f.convert_clone()
+        }
+    };
+    (rebind) => {
+        fn to_static_fast_path<F>(f: F) -> F::Output
+        where
+            F: $crate::__private::ToStaticFastPath<Self>,
+        {
+            f.convert_rebind()
+        }
+    };
+}
+
+/// Override the hidden `into_static_fast_path` method of [`IntoBoundedStatic`](crate::IntoBoundedStatic) for a type
+/// whose `into_static()` is a no-op, or only replaces its lifetimes with `rebind`, this is not part of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __into_static_fast_path {
+    () => {
+        fn into_static_fast_path<F>(f: F) -> F::Output
+        where
+            F: $crate::__private::IntoStaticFastPath<Self>,
+        {
+            f.convert_identity()
+        }
+    };
+    (rebind) => {
+        fn into_static_fast_path<F>(f: F) -> F::Output
+        where
+            F: $crate::__private::IntoStaticFastPath<Self>,
+        {
+            f.convert_rebind()
+        }
+    };
+}
+
+/// Forward the generic parameters of an impl, with a trailing comma if there are any, to the `@impl` arm of the given
+/// macro, this is not part of the public API.
+///
+/// The generic parameters are munched one token at a time to find whether they already end with a comma, so that the
+/// given macro can declare its own lifetime before them and its own type parameters after them.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_generics {
+    ($mac:ident $kind:ident [$($gen:tt)*] [] $($rest:tt)*) => {
+        $crate::$mac!(@impl $kind [$($gen)*] $($rest)*);
+    };
+    ($mac:ident $kind:ident [$($gen:tt)*] [,] $($rest:tt)*) => {
+        $crate::$mac!(@impl $kind [$($gen)*] $($rest)*);
+    };
+    ($mac:ident $kind:ident [$($gen:tt)*] [$last:tt] $($rest:tt)*) => {
+        $crate::$mac!(@impl $kind [$($gen)* ,] $($rest)*);
+    };
+    ($mac:ident $kind:ident [$($gen:tt)*] [$first:tt $($tail:tt)+] $($rest:tt)*) => {
+        $crate::__impl_generics!($mac $kind [$($gen)*] [$($tail)+] $($rest)*);
+    };
+}
+
+/// Implement [`ToBounded`](crate::ToBounded) for a type whose `to_static()` is a `copy`, a `clone` or a `to_owned()`,
+/// this is not part of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __to_bounded {
+    (@impl copy [$($gen:tt)*] $ty:ty $(where $($wc:tt)+)?) => {
+        /// No-op `ToBounded` impl for this `'static + Copy` type.
+        impl<'__b, $($gen)* __A: ?::core::marker::Sized> $crate::ToBounded<'__b, __A> for $ty $(where $($wc)+)? {
+            type Output = Self;
+
+            fn to_bounded_in(&self, _: &'__b __A) -> Self::Output {
+                *self
+            }
+        }
+    };
+    (@impl clone [$($gen:tt)*] $ty:ty $(where $($wc:tt)+)?) => {
+        /// No-op `ToBounded` impl for this `'static + Clone` type.
+        impl<'__b, $($gen)* __A: ?::core::marker::Sized> $crate::ToBounded<'__b, __A> for $ty $(where $($wc)+)? {
+            type Output = Self;
+
+            fn to_bounded_in(&self, _: &'__b __A) -> Self::Output {
+                ::core::clone::Clone::clone(self)
+            }
+        }
+    };
+    (@impl owned [$($gen:tt)*] $ty:ty => $static:ty $(where $($wc:tt)+)?) => {
+        /// `ToBounded` impl which delegates to `to_owned()`.
+        impl<'__b, $($gen)* __A: ?::core::marker::Sized> $crate::ToBounded<'__b, __A> for $ty $(where $($wc)+)? {
+            type Output = $static;
+
+            fn to_bounded_in(&self, _: &'__b __A) -> Self::Output {
+                Self::to_owned(self)
+            }
+        }
+    };
+    ($kind:ident impl [$($gen:tt)*] $($rest:tt)*) => {
+        $crate::__impl_generics!(__to_bounded $kind [$($gen)*] [$($gen)*] $($rest)*);
+    };
+}
+
+/// Implement [`ToStaticAs`](crate::ToStaticAs) for a type whose `to_static()` is a `copy`, a `clone` or a
+/// `to_owned()`, this is not part of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __to_static_as {
+    (@impl copy [$($gen:tt)*] $ty:ty $(where $($wc:tt)+)?) => {
+        /// No-op `ToStaticAs` impl for this `'static + Copy` type.
+        impl<$($gen)* __R: $crate::StaticRepr> $crate::ToStaticAs<__R> for $ty $(where $($wc)+)? {
+            type Static = Self;
+
+            fn to_static_as(&self, _: __R) -> Self::Static {
+                *self
+            }
+        }
+    };
+    (@impl clone [$($gen:tt)*] $ty:ty $(where $($wc:tt)+)?) => {
+        /// No-op `ToStaticAs` impl for this `'static + Clone` type.
+        impl<$($gen)* __R: $crate::StaticRepr> $crate::ToStaticAs<__R> for $ty $(where $($wc)+)? {
+            type Static = Self;
+
+            fn to_static_as(&self, _: __R) -> Self::Static {
+                ::core::clone::Clone::clone(self)
+            }
+        }
+    };
+    (@impl owned [$($gen:tt)*] $ty:ty => $static:ty $(where $($wc:tt)+)?) => {
+        /// `ToStaticAs` impl which delegates to `to_owned()`.
+        impl<$($gen)* __R: $crate::StaticRepr> $crate::ToStaticAs<__R> for $ty $(where $($wc)+)? {
+            type Static = $static;
+
+            fn to_static_as(&self, _: __R) -> Self::Static {
+                Self::to_owned(self)
+            }
+        }
+    };
+    ($kind:ident impl [$($gen:tt)*] $($rest:tt)*) => {
+        $crate::__impl_generics!(__to_static_as $kind [$($gen)*] [$($gen)*] $($rest)*);
+    };
+}
+
+/// Implement [`ToSendStatic`](crate::ToSendStatic) for a type whose `to_static()` is a `copy`, a `clone` or a
+/// `to_owned()`, given the `send` clause of the public macros, this is not part of the public API.
+///
+/// The target type is required to be `Send + Sync` in the `where` clause of the impl, which for a type without generic
+/// parameters is checked where the macro is used.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __to_send_static {
+    ([] $($rest:tt)*) => {};
+    ([send] copy impl [$($gen:tt)*] $ty:ty $(where $($wc:tt)+)?) => {
+        /// No-op `ToSendStatic` impl for this `'static + Copy` type.
+        impl<$($gen)*> $crate::ToSendStatic for $ty
+        where
+            $ty: ::core::marker::Send + ::core::marker::Sync,
+            $($($wc)+)?
+        {
+            type Static = Self;
+
+            fn to_send_static(&self) -> Self::Static {
+                *self
+            }
+        }
+    };
+    ([send] clone impl [$($gen:tt)*] $ty:ty $(where $($wc:tt)+)?) => {
+        /// No-op `ToSendStatic` impl for this `'static + Clone` type.
+        impl<$($gen)*> $crate::ToSendStatic for $ty
+        where
+            $ty: ::core::marker::Send + ::core::marker::Sync,
+            $($($wc)+)?
+        {
+            type Static = Self;
+
+            fn to_send_static(&self) -> Self::Static {
+                ::core::clone::Clone::clone(self)
+            }
+        }
+    };
+    ([send] owned impl [$($gen:tt)*] $ty:ty => $static:ty $(where $($wc:tt)+)?) => {
+        /// `ToSendStatic` impl which delegates to `to_owned()`.
+        impl<$($gen)*> $crate::ToSendStatic for $ty
+        where
+            $static: ::core::marker::Send + ::core::marker::Sync,
+            $($($wc)+)?
+        {
+            type Static = $static;
+
+            fn to_send_static(&self) -> Self::Static {
+                Self::to_owned(self)
+            }
+        }
+    };
+}
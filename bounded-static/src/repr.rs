@@ -0,0 +1,202 @@
+//! Conversions to `'static` with a configurable representation of strings and sequences.
+//!
+//! The [`ToBoundedStatic`](crate::ToBoundedStatic) trait converts a `Cow<str>` to a `Cow::Owned(String)` and a
+//! `Vec<T>` to a `Vec<T>`.  For values which are kept for a long time, such as the entries of a cache, a different
+//! representation may be preferable, such as a compact `Box<str>` which has no spare capacity, a cheaply clonable
+//! `Arc<str>` or an inline `SmolStr`.
+//!
+//! The [`ToStaticAs`] trait converts a value to `'static` with a [`StaticRepr`] policy which chooses the
+//! representation of the string and sequence leaves of the value:
+//!
+//! | Policy      | Strings    | Sequences  | Features               |
+//! |-------------|------------|------------|------------------------|
+//! | [`Owned`]   | `String`   | `Vec<T>`   | `alloc`                |
+//! | [`Compact`] | `Box<str>` | `Box<[T]>` | `alloc`                |
+//! | [`Shared`]  | `Arc<str>` | `Arc<[T]>` | `alloc`                |
+//! | `Inline`    | `SmolStr`  | `Box<[T]>` | `alloc` and `smol_str` |
+//!
+//! The strings and sequences produced by every policy are allocated with the exact length required.
+//!
+//! [`ToStaticAs`] is implemented for `String`, `Cow<str>`, `StaticCow<str>`, `SmolStr` and `SmartString`, which are
+//! converted to [`StaticRepr::Str`], for `Vec<T>` and `Cow<[T]>`, which are converted to [`StaticRepr::Slice`], and
+//! for the other types supported by [`ToBoundedStatic`](crate::ToBoundedStatic).  Containers and wrappers, such as
+//! `Option`, `Box`, `RefCell`, `Mutex`, tuples, arrays and maps, convert the values they hold with the policy, and
+//! types which hold no strings or sequences, such as those given to the [`impl_copy`](crate::impl_copy) and
+//! [`impl_clone`](crate::impl_clone) macros, are converted unchanged.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use std::sync::Arc;
+//! # use bounded_static::repr::{Compact, Shared, ToStaticAs};
+//! let input = String::from("value");
+//! let value = (Cow::from(input.as_str()), vec![1_u8, 2, 3]);
+//! let compact: (Box<str>, Box<[u8]>) = value.to_static_as(Compact);
+//! let shared: (Arc<str>, Arc<[u8]>) = value.to_static_as(Shared);
+//! assert_eq!("value", compact.0.as_ref());
+//! assert_eq!([1, 2, 3], shared.1.as_ref());
+//! ```
+//!
+//! The `ToStaticAs` derive macro implements [`ToStaticAs`] for a `struct` or `enum`.  As the representation of a
+//! string changes its type, the fields which hold strings or sequences must be generic over their representation:
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use bounded_static::{repr::Compact, ToStaticAs};
+//! #[derive(ToStaticAs)]
+//! struct Entry<S> {
+//!     key: S,
+//!     hits: u64,
+//! }
+//!
+//! let input = String::from("key");
+//! let entry = Entry { key: Cow::from(input.as_str()), hits: 1 };
+//! let cached: Entry<Box<str>> = entry.to_static_as(Compact);
+//! assert_eq!("key", cached.key.as_ref());
+//! ```
+
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::hash::Hash;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+
+/// A policy which chooses how the string and sequence leaves of a value are represented when converted with
+/// [`ToStaticAs`].
+///
+/// A policy is a zero sized value which is passed to [`to_static_as`](ToStaticAs::to_static_as).
+pub trait StaticRepr: Copy {
+    /// The representation of a string.
+    type Str: Borrow<str> + Clone + Debug + Eq + Ord + Hash + 'static;
+
+    /// The representation of a sequence of `T`.
+    type Slice<T: 'static>: Borrow<[T]> + 'static;
+
+    /// Copy a string to the representation of this policy.
+    fn str(self, value: &str) -> Self::Str;
+
+    /// Collect a sequence of values to the representation of this policy.
+    fn slice<T, I>(self, values: I) -> Self::Slice<T>
+    where
+        T: 'static,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator;
+}
+
+/// A trait for converting `&T` to an owned `T` such that `T: 'static`, with the representation of strings and
+/// sequences chosen by a [`StaticRepr`] policy `R`.
+///
+/// See the `repr` module for details.  It may be derived with the `ToStaticAs` derive macro when the `derive`
+/// feature is enabled.
+pub trait ToStaticAs<R: StaticRepr> {
+    /// The target type is bounded by the `'static` lifetime.
+    type Static: 'static;
+
+    /// Convert an `&T` to an owned `T` such that `T: 'static`, with strings and sequences represented as chosen by
+    /// `repr`.
+    #[must_use]
+    fn to_static_as(&self, repr: R) -> Self::Static;
+}
+
+/// A [`StaticRepr`] which represents strings as `String` and sequences as `Vec<T>`, as
+/// [`to_static`](crate::ToBoundedStatic::to_static) does.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Owned;
+
+#[cfg(feature = "alloc")]
+impl StaticRepr for Owned {
+    type Str = String;
+    type Slice<T: 'static> = Vec<T>;
+
+    fn str(self, value: &str) -> Self::Str {
+        String::from(value)
+    }
+
+    fn slice<T, I>(self, values: I) -> Self::Slice<T>
+    where
+        T: 'static,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        values.into_iter().collect()
+    }
+}
+
+/// A [`StaticRepr`] which represents strings as `Box<str>` and sequences as `Box<[T]>`, which have no spare capacity.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Compact;
+
+#[cfg(feature = "alloc")]
+impl StaticRepr for Compact {
+    type Str = Box<str>;
+    type Slice<T: 'static> = Box<[T]>;
+
+    fn str(self, value: &str) -> Self::Str {
+        Box::from(value)
+    }
+
+    fn slice<T, I>(self, values: I) -> Self::Slice<T>
+    where
+        T: 'static,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        values.into_iter().collect()
+    }
+}
+
+/// A [`StaticRepr`] which represents strings as `Arc<str>` and sequences as `Arc<[T]>`, which are cheap to clone.
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Shared;
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl StaticRepr for Shared {
+    type Str = Arc<str>;
+    type Slice<T: 'static> = Arc<[T]>;
+
+    fn str(self, value: &str) -> Self::Str {
+        Arc::from(value)
+    }
+
+    fn slice<T, I>(self, values: I) -> Self::Slice<T>
+    where
+        T: 'static,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        values.into_iter().collect()
+    }
+}
+
+/// A [`StaticRepr`] which represents strings as `smol_str::SmolStr`, which stores short strings inline, and sequences
+/// as `Box<[T]>`.
+#[cfg(all(feature = "alloc", feature = "smol_str"))]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Inline;
+
+#[cfg(all(feature = "alloc", feature = "smol_str"))]
+impl StaticRepr for Inline {
+    type Str = smol_str::SmolStr;
+    type Slice<T: 'static> = Box<[T]>;
+
+    fn str(self, value: &str) -> Self::Str {
+        smol_str::SmolStr::new(value)
+    }
+
+    fn slice<T, I>(self, values: I) -> Self::Slice<T>
+    where
+        T: 'static,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        values.into_iter().collect()
+    }
+}
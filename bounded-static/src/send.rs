@@ -0,0 +1,69 @@
+//! Conversions to a form that is bounded by `'static` and may be sent and shared between threads.
+//!
+//! A type which is bounded by `'static` is not necessarily `Send` or `Sync`, for example the
+//! [`to_static`](crate::ToBoundedStatic::to_static) conversion of an `Rc<T>` is an `Rc<T>`, which cannot be sent to
+//! another thread.  The [`ToSendStatic`] trait instead converts a value to a detached snapshot which is
+//! `Send + Sync + 'static`, replacing the single threaded types it contains with their thread safe counterparts:
+//!
+//! | Type                           | Snapshot                        | Features |
+//! |--------------------------------|---------------------------------|----------|
+//! | `Rc<T>`, `Arc<T>`              | `Arc<T>`                        | `alloc`  |
+//! | `Rc<str>`, `Arc<str>`          | `Arc<str>`                      | `alloc`  |
+//! | `RefCell<T>`                   | `Mutex<T>`                      | `std`    |
+//! | `OnceCell<T>`                  | `OnceLock<T>`                   | `std`    |
+//! | `Cell<bool>`, `Cell<u32>`, ... | `AtomicBool`, `AtomicU32`, ...  |          |
+//! | `Box<dyn Error>`               | `Box<dyn Error + Send + Sync>`  | `std`    |
+//!
+//! Borrowed data is copied as with [`to_static`](crate::ToBoundedStatic::to_static), and [`ToSendStatic`] is also
+//! implemented for the containers and wrappers which hold these types, such as `Option`, `Vec`, `Mutex`, `Reverse`,
+//! ranges, tuples and maps, for the other types supported by [`ToBoundedStatic`](crate::ToBoundedStatic) which are
+//! `Send + Sync`, and for the types given to the [`impl_copy`](crate::impl_copy), [`impl_clone`](crate::impl_clone)
+//! and [`impl_into_owned`](crate::impl_into_owned) macros with a `send` clause.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use std::cell::RefCell;
+//! # use std::rc::Rc;
+//! # use bounded_static::ToSendStatic;
+//! let state = Rc::new(RefCell::new(vec![String::from("token")]));
+//! let snapshot = state.to_send_static();
+//! std::thread::spawn(move || {
+//!     assert_eq!(["token"], snapshot.lock().unwrap().as_slice());
+//! })
+//! .join()
+//! .unwrap();
+//! ```
+//!
+//! The `ToSendStatic` derive macro implements [`ToSendStatic`] for a `struct` or `enum`.  As a snapshot of an `Rc`
+//! field has a different type, the fields which hold single threaded types must be generic over them:
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use std::rc::Rc;
+//! # use std::sync::Arc;
+//! # use bounded_static::ToSendStatic;
+//! #[derive(ToSendStatic)]
+//! struct Node<'a, P> {
+//!     name: Cow<'a, str>,
+//!     parent: Option<P>,
+//! }
+//!
+//! let input = String::from("child");
+//! let node = Node { name: Cow::from(input.as_str()), parent: Some(Rc::new(1)) };
+//! let snapshot: Node<'static, Arc<i32>> = node.to_send_static();
+//! std::thread::spawn(move || assert_eq!("child", snapshot.name)).join().unwrap();
+//! ```
+
+/// A trait for converting `&T` to an owned `T` such that `T: Send + Sync + 'static`.
+///
+/// See the `send` module for details.  It may be derived with the `ToSendStatic` derive macro when the `derive`
+/// feature is enabled.
+pub trait ToSendStatic {
+    /// The target type is bounded by the `'static` lifetime and is `Send` and `Sync`.
+    type Static: Send + Sync + 'static;
+
+    /// Convert an `&T` to an owned `T` such that `T: Send + Sync + 'static`.
+    #[must_use]
+    fn to_send_static(&self) -> Self::Static;
+}
@@ -0,0 +1,284 @@
+//! A clone-on-write smart pointer which distinguishes `'static` borrows.
+
+use alloc::borrow::{Cow, ToOwned};
+use alloc::string::String;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+/// A clone-on-write smart pointer which, unlike [`Cow`], records whether borrowed data is borrowed for `'static`.
+///
+/// Converting a [`Cow::Borrowed`] with [`to_static`](crate::ToBoundedStatic::to_static) always copies the borrowed
+/// data, even if it was borrowed from a string literal or another `'static` source, because the lifetime of the borrow
+/// is not known at runtime.  A `StaticCow` has a separate [`Static`](StaticCow::Static) state for such borrows, which
+/// is kept without copying when converted, so that only the data in the [`Borrowed`](StaticCow::Borrowed) state is
+/// copied.
+///
+/// # Examples
+///
+/// ```rust
+/// # use bounded_static::{StaticCow, ToBoundedStatic};
+/// fn ensure_static<T: 'static>(_: T) {}
+///
+/// let input = String::from("borrowed");
+/// let values = [StaticCow::Static("static"), StaticCow::Borrowed(input.as_str())];
+/// let to_static = values.to_static();
+/// assert!(to_static[0].is_static());
+/// assert!(to_static[1].is_owned());
+/// ensure_static(to_static);
+/// ```
+pub enum StaticCow<'a, B>
+where
+    B: 'static + ToOwned + ?Sized,
+{
+    /// Data borrowed for the `'static` lifetime.
+    Static(&'static B),
+    /// Data borrowed for the `'a` lifetime.
+    Borrowed(&'a B),
+    /// Owned data.
+    Owned(<B as ToOwned>::Owned),
+}
+
+impl<'a, B> StaticCow<'a, B>
+where
+    B: 'static + ToOwned + ?Sized,
+{
+    /// Returns true if the data is borrowed for the `'static` lifetime.
+    #[must_use]
+    pub const fn is_static(&self) -> bool {
+        matches!(self, Self::Static(_))
+    }
+
+    /// Returns true if the data is borrowed for the `'a` lifetime.
+    #[must_use]
+    pub const fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+
+    /// Returns true if the data is owned.
+    #[must_use]
+    pub const fn is_owned(&self) -> bool {
+        matches!(self, Self::Owned(_))
+    }
+
+    /// Acquire a mutable reference to the owned form of the data, cloning the data if it is not already owned.
+    pub fn to_mut(&mut self) -> &mut <B as ToOwned>::Owned {
+        if !self.is_owned() {
+            *self = Self::Owned((**self).to_owned());
+        }
+        match self {
+            Self::Owned(value) => value,
+            Self::Static(_) | Self::Borrowed(_) => unreachable!(),
+        }
+    }
+
+    /// Extract the owned data, cloning the data if it is not already owned.
+    #[must_use]
+    pub fn into_owned(self) -> <B as ToOwned>::Owned {
+        match self {
+            Self::Owned(value) => value,
+            borrowed => (*borrowed).to_owned(),
+        }
+    }
+
+    /// Convert into a [`Cow`], which forgets whether the data is borrowed for the `'static` lifetime.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    pub fn into_cow(self) -> Cow<'a, B> {
+        match self {
+            Self::Static(value) => Cow::Borrowed(value),
+            Self::Borrowed(value) => Cow::Borrowed(value),
+            Self::Owned(value) => Cow::Owned(value),
+        }
+    }
+}
+
+impl<B> Deref for StaticCow<'_, B>
+where
+    B: 'static + ToOwned + ?Sized,
+{
+    type Target = B;
+
+    #[allow(clippy::match_same_arms)]
+    fn deref(&self) -> &B {
+        match self {
+            Self::Static(value) => value,
+            Self::Borrowed(value) => value,
+            Self::Owned(value) => value.borrow(),
+        }
+    }
+}
+
+impl<B> AsRef<B> for StaticCow<'_, B>
+where
+    B: 'static + ToOwned + ?Sized,
+{
+    fn as_ref(&self) -> &B {
+        self
+    }
+}
+
+impl<B> Borrow<B> for StaticCow<'_, B>
+where
+    B: 'static + ToOwned + ?Sized,
+{
+    fn borrow(&self) -> &B {
+        self
+    }
+}
+
+impl<B> Clone for StaticCow<'_, B>
+where
+    B: 'static + ToOwned + ?Sized,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Static(value) => Self::Static(value),
+            Self::Borrowed(value) => Self::Borrowed(value),
+            Self::Owned(value) => Self::Owned(value.borrow().to_owned()),
+        }
+    }
+}
+
+impl<B> Default for StaticCow<'_, B>
+where
+    B: 'static + ToOwned + ?Sized,
+    <B as ToOwned>::Owned: Default,
+{
+    fn default() -> Self {
+        Self::Owned(<B as ToOwned>::Owned::default())
+    }
+}
+
+impl<B> Debug for StaticCow<'_, B>
+where
+    B: 'static + Debug + ToOwned + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<B> Display for StaticCow<'_, B>
+where
+    B: 'static + Display + ToOwned + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
+
+impl<'b, B, C> PartialEq<StaticCow<'b, C>> for StaticCow<'_, B>
+where
+    B: 'static + PartialEq<C> + ToOwned + ?Sized,
+    C: 'static + ToOwned + ?Sized,
+{
+    fn eq(&self, other: &StaticCow<'b, C>) -> bool {
+        **self == **other
+    }
+}
+
+macro_rules! impl_str_eq {
+    ($($ty:ty),+) => {
+        $(
+            impl PartialEq<$ty> for StaticCow<'_, str> {
+                fn eq(&self, other: &$ty) -> bool {
+                    **self == other[..]
+                }
+            }
+
+            impl PartialEq<StaticCow<'_, str>> for $ty {
+                fn eq(&self, other: &StaticCow<'_, str>) -> bool {
+                    self[..] == **other
+                }
+            }
+        )+
+    };
+}
+
+impl_str_eq!(str, &str, String);
+
+impl<B> Eq for StaticCow<'_, B> where B: 'static + Eq + ToOwned + ?Sized {}
+
+impl<B> PartialOrd for StaticCow<'_, B>
+where
+    B: 'static + PartialOrd + ToOwned + ?Sized,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<B> Ord for StaticCow<'_, B>
+where
+    B: 'static + Ord + ToOwned + ?Sized,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<B> Hash for StaticCow<'_, B>
+where
+    B: 'static + Hash + ToOwned + ?Sized,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&**self, state);
+    }
+}
+
+impl<'a, B> From<Cow<'a, B>> for StaticCow<'a, B>
+where
+    B: 'static + ToOwned + ?Sized,
+{
+    fn from(value: Cow<'a, B>) -> Self {
+        match value {
+            Cow::Borrowed(value) => Self::Borrowed(value),
+            Cow::Owned(value) => Self::Owned(value),
+        }
+    }
+}
+
+impl<'a, B> From<StaticCow<'a, B>> for Cow<'a, B>
+where
+    B: 'static + ToOwned + ?Sized,
+{
+    fn from(value: StaticCow<'a, B>) -> Self {
+        value.into_cow()
+    }
+}
+
+impl<'a> From<&'a str> for StaticCow<'a, str> {
+    fn from(value: &'a str) -> Self {
+        Self::Borrowed(value)
+    }
+}
+
+impl From<String> for StaticCow<'_, str> {
+    fn from(value: String) -> Self {
+        Self::Owned(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B> serde::Serialize for StaticCow<'_, B>
+where
+    B: 'static + serde::Serialize + ToOwned + ?Sized,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (**self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, B> serde::Deserialize<'de> for StaticCow<'_, B>
+where
+    B: 'static + ToOwned + ?Sized,
+    <B as ToOwned>::Owned: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <B as ToOwned>::Owned::deserialize(deserializer).map(Self::Owned)
+    }
+}
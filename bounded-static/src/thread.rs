@@ -0,0 +1,111 @@
+//! Spawn threads which are given a `'static` copy of borrowed data.
+//!
+//! The closure given to [`std::thread::spawn`] must be `'static` and so may not capture borrowed data, such as the
+//! `Cow<'a, str>` fields of a request, which must first be converted with [`to_static`](ToBoundedStatic::to_static)
+//! and moved into the closure.  The [`spawn_static`] function performs this conversion and passes the `'static` value
+//! to the closure, such that the `'static` requirement of `spawn` is met by construction.
+//!
+//! Any type which implements [`ToBoundedStatic`] may be passed, including tuples of borrowed values.  The [`Builder`]
+//! type configures the name and stack size of the thread, as [`std::thread::Builder`] does.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use bounded_static::thread::spawn_static;
+//! let path = String::from("/index.html");
+//! let method = String::from("GET");
+//! let request = (Cow::from(method.as_str()), Cow::from(path.as_str()));
+//! let handle = spawn_static(&request, |(method, path)| format!("{method} {path}"));
+//! drop(request);
+//! drop(path);
+//! assert_eq!("GET /index.html", handle.join().unwrap());
+//! ```
+
+use crate::ToBoundedStatic;
+use alloc::string::String;
+use std::io;
+use std::thread::JoinHandle;
+
+/// Convert `data` with [`to_static`](ToBoundedStatic::to_static) and spawn a new thread which calls `f` with it,
+/// returning a [`JoinHandle`] for it.
+///
+/// See the `thread` module for details.
+///
+/// # Panics
+///
+/// Panics if the OS fails to create a thread, as [`std::thread::spawn`] does.  Use [`Builder::spawn_static`] to
+/// handle the error.
+pub fn spawn_static<T, F, R>(data: &T, f: F) -> JoinHandle<R>
+where
+    T: ToBoundedStatic + ?Sized,
+    T::Static: Send,
+    F: FnOnce(T::Static) -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let owned = data.to_static();
+    std::thread::spawn(move || f(owned))
+}
+
+/// A thread factory which spawns threads that are given a `'static` copy of borrowed data.
+///
+/// This wraps a [`std::thread::Builder`], from which it may also be created.
+#[derive(Debug)]
+pub struct Builder {
+    builder: std::thread::Builder,
+}
+
+impl Builder {
+    /// Create a new `Builder` with the default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            builder: std::thread::Builder::new(),
+        }
+    }
+
+    /// Name the thread-to-be.
+    #[must_use]
+    pub fn name(self, name: String) -> Self {
+        Self {
+            builder: self.builder.name(name),
+        }
+    }
+
+    /// Set the size of the stack, in bytes, of the thread-to-be.
+    #[must_use]
+    pub fn stack_size(self, size: usize) -> Self {
+        Self {
+            builder: self.builder.stack_size(size),
+        }
+    }
+
+    /// Convert `data` with [`to_static`](ToBoundedStatic::to_static) and spawn a new thread which calls `f` with it,
+    /// returning a [`JoinHandle`] for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the OS fails to create a thread, as [`std::thread::Builder::spawn`] does.
+    pub fn spawn_static<T, F, R>(self, data: &T, f: F) -> io::Result<JoinHandle<R>>
+    where
+        T: ToBoundedStatic + ?Sized,
+        T::Static: Send,
+        F: FnOnce(T::Static) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let owned = data.to_static();
+        self.builder.spawn(move || f(owned))
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<std::thread::Builder> for Builder {
+    fn from(builder: std::thread::Builder) -> Self {
+        Self { builder }
+    }
+}